@@ -0,0 +1,37 @@
+extern crate statrs;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use statrs::distribution::{Binomial, Discrete, Poisson};
+
+fn bench_ln_pmf_slice(c: &mut Criterion) {
+    let ks: Vec<u64> = (0..100).collect();
+    let mut out = vec![0.0; ks.len()];
+    let mut group = c.benchmark_group("ln pmf slice");
+
+    let binomial = Binomial::new(black_box(0.3), black_box(100)).unwrap();
+    group.bench_function("binomial: ln_pmf", |b| {
+        b.iter(|| {
+            for (o, &k) in out.iter_mut().zip(ks.iter()) {
+                *o = binomial.ln_pmf(k);
+            }
+        })
+    });
+    group.bench_function("binomial: ln_pmf_slice", |b| {
+        b.iter(|| binomial.ln_pmf_slice(&ks, &mut out))
+    });
+
+    let poisson = Poisson::new(black_box(12.5)).unwrap();
+    group.bench_function("poisson: ln_pmf", |b| {
+        b.iter(|| {
+            for (o, &k) in out.iter_mut().zip(ks.iter()) {
+                *o = poisson.ln_pmf(k);
+            }
+        })
+    });
+    group.bench_function("poisson: ln_pmf_slice", |b| {
+        b.iter(|| poisson.ln_pmf_slice(&ks, &mut out))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_ln_pmf_slice);
+criterion_main!(benches);
@@ -1,7 +1,9 @@
-use crate::distribution::{Binomial, Discrete, DiscreteCDF};
+use crate::distribution::{ApproxEq, Binomial, Discrete, DiscreteCDF};
+use crate::function::{entropy, erf};
 use crate::statistics::*;
 use crate::Result;
 use rand::Rng;
+use std::f64;
 
 /// Implements the
 /// [Bernoulli](https://en.wikipedia.org/wiki/Bernoulli_distribution)
@@ -78,6 +80,213 @@ impl Bernoulli {
     pub fn n(&self) -> u64 {
         1
     }
+
+    /// Returns the entropy of the bernoulli distribution in bits, i.e. the
+    /// binary entropy function evaluated at `p`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Bernoulli;
+    /// use statrs::statistics::Distribution;
+    /// use statrs::prec;
+    ///
+    /// let n = Bernoulli::new(0.5).unwrap();
+    /// assert_eq!(n.entropy_bits(), 1.0);
+    ///
+    /// let n = Bernoulli::new(0.2).unwrap();
+    /// let nats = n.entropy().unwrap();
+    /// assert!(prec::almost_eq(n.entropy_bits(), nats / 2f64.ln(), 1e-15));
+    /// ```
+    pub fn entropy_bits(&self) -> f64 {
+        entropy::binary_entropy(self.p())
+    }
+
+    /// Returns the relative risk of `self` with respect to `other`, the
+    /// ratio of their success probabilities
+    ///
+    /// # Panics
+    ///
+    /// If either success probability is `0.0` or `1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// p / q
+    /// ```
+    ///
+    /// where `p` and `q` are the success probabilities of `self` and
+    /// `other`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Bernoulli;
+    ///
+    /// let exposed = Bernoulli::new(0.2).unwrap();
+    /// let unexposed = Bernoulli::new(0.1).unwrap();
+    /// assert_eq!(exposed.relative_risk(&unexposed), 2.0);
+    /// ```
+    pub fn relative_risk(&self, other: &Bernoulli) -> f64 {
+        check_non_degenerate(self.p(), other.p());
+        self.p() / other.p()
+    }
+
+    /// Returns the odds ratio of `self` with respect to `other`, the ratio
+    /// of the odds of success of the two distributions
+    ///
+    /// # Panics
+    ///
+    /// If either success probability is `0.0` or `1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (p / (1 - p)) / (q / (1 - q))
+    /// ```
+    ///
+    /// where `p` and `q` are the success probabilities of `self` and
+    /// `other`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Bernoulli;
+    /// use statrs::prec;
+    ///
+    /// let exposed = Bernoulli::new(0.2).unwrap();
+    /// let unexposed = Bernoulli::new(0.1).unwrap();
+    /// assert!(prec::almost_eq(exposed.odds_ratio(&unexposed), 2.25, 1e-15));
+    /// ```
+    pub fn odds_ratio(&self, other: &Bernoulli) -> f64 {
+        check_non_degenerate(self.p(), other.p());
+        let (p, q) = (self.p(), other.p());
+        p * (1.0 - q) / (q * (1.0 - p))
+    }
+
+    /// Returns the approximate two-sided confidence interval for the
+    /// relative risk at the confidence level `confidence`, estimated from
+    /// `events` successes out of `trials` in the first group and
+    /// `other_events` successes out of `other_trials` in the second, using
+    /// the normal approximation of the log relative risk (Katz method)
+    ///
+    /// # Panics
+    ///
+    /// If `confidence` is not in `(0, 1)`, if either count of successes is
+    /// `0` or if it exceeds the corresponding number of trials
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// exp(ln(RR) ± z * sqrt(1 / a - 1 / n1 + 1 / c - 1 / n2))
+    /// ```
+    ///
+    /// where `RR = (a / n1) / (c / n2)` is the estimated relative risk,
+    /// `a` and `c` are the counts of successes, `n1` and `n2` the numbers of
+    /// trials and `z` is the `1 - α/2` quantile of the standard normal
+    /// distribution with `α = 1 - confidence`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Bernoulli;
+    ///
+    /// let (lower, upper) = Bernoulli::relative_risk_confidence_interval(20, 100, 10, 100, 0.95);
+    /// assert!(lower < 2.0 && 2.0 < upper);
+    /// ```
+    pub fn relative_risk_confidence_interval(
+        events: u64,
+        trials: u64,
+        other_events: u64,
+        other_trials: u64,
+        confidence: f64,
+    ) -> (f64, f64) {
+        check_counts(events, trials);
+        check_counts(other_events, other_trials);
+        let (a, n1) = (events as f64, trials as f64);
+        let (c, n2) = (other_events as f64, other_trials as f64);
+        let ln_rr = (a / n1).ln() - (c / n2).ln();
+        let se = (1.0 / a - 1.0 / n1 + 1.0 / c - 1.0 / n2).sqrt();
+        log_normal_interval(ln_rr, se, confidence)
+    }
+
+    /// Returns the approximate two-sided confidence interval for the odds
+    /// ratio at the confidence level `confidence`, estimated from `events`
+    /// successes out of `trials` in the first group and `other_events`
+    /// successes out of `other_trials` in the second, using the normal
+    /// approximation of the log odds ratio (Woolf method)
+    ///
+    /// # Panics
+    ///
+    /// If `confidence` is not in `(0, 1)` or if any cell of the 2×2 table
+    /// is empty, i.e. if either count of successes is `0` or equal to the
+    /// corresponding number of trials, or if it exceeds it
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// exp(ln(OR) ± z * sqrt(1 / a + 1 / b + 1 / c + 1 / d))
+    /// ```
+    ///
+    /// where `OR = (a * d) / (b * c)` is the estimated odds ratio, `a` and
+    /// `c` are the counts of successes, `b` and `d` the counts of failures
+    /// and `z` is the `1 - α/2` quantile of the standard normal distribution
+    /// with `α = 1 - confidence`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Bernoulli;
+    ///
+    /// let (lower, upper) = Bernoulli::odds_ratio_confidence_interval(20, 100, 10, 100, 0.95);
+    /// assert!(lower < 2.25 && 2.25 < upper);
+    /// ```
+    pub fn odds_ratio_confidence_interval(
+        events: u64,
+        trials: u64,
+        other_events: u64,
+        other_trials: u64,
+        confidence: f64,
+    ) -> (f64, f64) {
+        check_counts(events, trials);
+        check_counts(other_events, other_trials);
+        if events == trials || other_events == other_trials {
+            panic!("counts of failures must be positive");
+        }
+        let (a, b) = (events as f64, (trials - events) as f64);
+        let (c, d) = (other_events as f64, (other_trials - other_events) as f64);
+        let ln_or = a.ln() + d.ln() - b.ln() - c.ln();
+        let se = (1.0 / a + 1.0 / b + 1.0 / c + 1.0 / d).sqrt();
+        log_normal_interval(ln_or, se, confidence)
+    }
+}
+
+fn check_non_degenerate(p: f64, q: f64) {
+    if p == 0.0 || p == 1.0 || q == 0.0 || q == 1.0 {
+        panic!("probabilities must be in (0, 1)");
+    }
+}
+
+fn check_counts(events: u64, trials: u64) {
+    if events == 0 || events > trials {
+        panic!("counts of successes must be in [1, trials]");
+    }
+}
+
+// the interval exp(estimate ± z * se) for a log-scale estimate that is
+// approximately normal with standard error se
+fn log_normal_interval(estimate: f64, se: f64, confidence: f64) -> (f64, f64) {
+    if !(confidence > 0.0 && confidence < 1.0) {
+        panic!("confidence must be in (0, 1)");
+    }
+    let z = f64::consts::SQRT_2 * erf::erfc_inv(1.0 - confidence);
+    ((estimate - z * se).exp(), (estimate + z * se).exp())
+}
+
+impl ApproxEq for Bernoulli {
+    fn approx_eq(&self, other: &Bernoulli, tol: f64) -> bool {
+        self.b.approx_eq(&other.b, tol)
+    }
 }
 
 impl ::rand::distributions::Distribution<f64> for Bernoulli {
@@ -193,6 +402,18 @@ impl Distribution<f64> for Bernoulli {
     fn skewness(&self) -> Option<f64> {
         self.b.skewness()
     }
+    /// Returns the excess kurtosis of the bernoulli
+    /// distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// q = (1 - p)
+    /// (1 - 6pq) / (pq)
+    /// ```
+    fn excess_kurtosis(&self) -> Option<f64> {
+        self.b.excess_kurtosis()
+    }
 }
 
 impl Median<f64> for Bernoulli {
@@ -342,4 +563,75 @@ mod testing {
         test_almost(0.3, 0.3, 1e-15, sf(0));
         test_almost(0.7, 0.7, 1e-15, sf(0));
     }
+
+    #[test]
+    fn test_excess_kurtosis() {
+        use crate::statistics::Distribution;
+        let excess_kurtosis = |x: Bernoulli| x.excess_kurtosis().unwrap();
+        test_case(0.5, -2.0, excess_kurtosis);
+        test_almost(0.3, -1.2380952380952380952, 1e-14, excess_kurtosis);
+        test_case(0.0, f64::INFINITY, excess_kurtosis);
+    }
+
+    #[test]
+    fn test_relative_risk() {
+        assert_eq!(try_create(0.2).relative_risk(&try_create(0.1)), 2.0);
+        assert_eq!(try_create(0.1).relative_risk(&try_create(0.4)), 0.25);
+        assert_eq!(try_create(0.3).relative_risk(&try_create(0.3)), 1.0);
+    }
+
+    #[test]
+    fn test_odds_ratio() {
+        assert_almost_eq!(try_create(0.2).odds_ratio(&try_create(0.1)), 2.25, 1e-15);
+        assert_almost_eq!(try_create(0.5).odds_ratio(&try_create(0.2)), 4.0, 1e-15);
+        assert_eq!(try_create(0.3).odds_ratio(&try_create(0.3)), 1.0);
+        // the odds ratio is close to the relative risk for rare outcomes
+        let or = try_create(0.002).odds_ratio(&try_create(0.001));
+        assert_almost_eq!(or, 2.0, 3e-3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_relative_risk_degenerate() {
+        try_create(0.2).relative_risk(&try_create(0.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_odds_ratio_degenerate() {
+        try_create(1.0).odds_ratio(&try_create(0.5));
+    }
+
+    #[test]
+    fn test_confidence_intervals() {
+        // 20 of 100 exposed and 10 of 100 unexposed subjects fell ill
+        let (lower, upper) = Bernoulli::relative_risk_confidence_interval(20, 100, 10, 100, 0.95);
+        assert_almost_eq!(lower, 0.986563206731782, 1e-12);
+        assert_almost_eq!(upper, 4.05447919880463, 1e-12);
+        let (lower, upper) = Bernoulli::odds_ratio_confidence_interval(20, 100, 10, 100, 0.95);
+        assert_almost_eq!(lower, 0.994294892281506, 1e-12);
+        assert_almost_eq!(upper, 5.09154782881726, 1e-12);
+        // the point estimate is the geometric mean of the bounds
+        assert_almost_eq!((lower * upper).sqrt(), 2.25, 1e-12);
+        let (narrow_lower, narrow_upper) = Bernoulli::odds_ratio_confidence_interval(20, 100, 10, 100, 0.5);
+        assert!(lower < narrow_lower && narrow_upper < upper);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_relative_risk_confidence_interval_no_events() {
+        Bernoulli::relative_risk_confidence_interval(0, 100, 10, 100, 0.95);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_odds_ratio_confidence_interval_no_failures() {
+        Bernoulli::odds_ratio_confidence_interval(20, 20, 10, 100, 0.95);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_confidence_interval_bad_confidence() {
+        Bernoulli::odds_ratio_confidence_interval(20, 100, 10, 100, 1.0);
+    }
 }
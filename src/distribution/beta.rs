@@ -1,4 +1,4 @@
-use crate::distribution::{Continuous, ContinuousCDF};
+use crate::distribution::{ApproxEq, Continuous, ContinuousCDF, Dirichlet, MomentMatch};
 use crate::function::{beta, gamma};
 use crate::is_zero;
 use crate::statistics::*;
@@ -85,6 +85,72 @@ impl Beta {
     pub fn shape_b(&self) -> f64 {
         self.shape_b
     }
+
+    /// Constructs the beta distribution of the first component of a
+    /// two-dimensional dirichlet distribution, whose shapes are the two
+    /// concentration parameters
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `dirichlet` does not have exactly two
+    /// concentration parameters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Beta, Dirichlet};
+    ///
+    /// let n = Dirichlet::new(vec![2.0, 3.0]).unwrap();
+    /// let beta = Beta::from_dirichlet_pair(&n).unwrap();
+    /// assert_eq!((beta.shape_a(), beta.shape_b()), (2.0, 3.0));
+    /// ```
+    pub fn from_dirichlet_pair(dirichlet: &Dirichlet) -> Result<Beta> {
+        match dirichlet.alpha().as_slice() {
+            &[shape_a, shape_b] => Beta::new(shape_a, shape_b),
+            _ => Err(StatsError::BadParams),
+        }
+    }
+}
+
+impl MomentMatch for Beta {
+    /// Constructs the beta distribution on `[0, 1]` with a mean of `mean`
+    /// and a variance of `variance`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `mean` or `variance` are `NaN`, if `mean` is not
+    /// in `(0, 1)` or if `variance` is not in `(0, mean * (1 - mean))`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ν = μ * (1 - μ) / σ^2 - 1, α = μ * ν, β = (1 - μ) * ν
+    /// ```
+    ///
+    /// where `μ` is the mean and `σ^2` is the variance
+    fn from_mean_variance(mean: f64, variance: f64) -> Result<Beta> {
+        if mean.is_nan()
+            || variance.is_nan()
+            || mean <= 0.0
+            || mean >= 1.0
+            || variance <= 0.0
+            || variance >= mean * (1.0 - mean)
+        {
+            return Err(StatsError::BadParams);
+        }
+        let nu = mean * (1.0 - mean) / variance - 1.0;
+        Beta::new(mean * nu, (1.0 - mean) * nu)
+    }
+}
+
+impl ApproxEq for Beta {
+    fn approx_eq(&self, other: &Beta, tol: f64) -> bool {
+        super::internal::params_approx_eq(
+            &[self.shape_a, self.shape_b],
+            &[other.shape_a, other.shape_b],
+            tol,
+        )
+    }
 }
 
 impl ::rand::distributions::Distribution<f64> for Beta {
@@ -272,6 +338,26 @@ impl Distribution<f64> for Beta {
         };
         Some(skew)
     }
+    /// Returns the excess kurtosis of the Beta distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 6((α - β)^2 * (α + β + 1) - αβ(α + β + 2)) / (αβ(α + β + 2)(α + β + 3))
+    /// ```
+    ///
+    /// where `α` is shapeA and `β` is shapeB
+    fn excess_kurtosis(&self) -> Option<f64> {
+        if self.shape_a.is_infinite() {
+            Some(6.0 / self.shape_b)
+        } else if self.shape_b.is_infinite() {
+            Some(6.0 / self.shape_a)
+        } else {
+            let (a, b) = (self.shape_a, self.shape_b);
+            let num = 6.0 * ((a - b) * (a - b) * (a + b + 1.0) - a * b * (a + b + 2.0));
+            Some(num / (a * b * (a + b + 2.0) * (a + b + 3.0)))
+        }
+    }
 }
 
 impl Mode<Option<f64>> for Beta {
@@ -495,6 +581,17 @@ mod tests {
         test_case((INF, 1.0), -2.0, skewness);
     }
 
+    #[test]
+    fn test_excess_kurtosis() {
+        let excess_kurtosis = |x: Beta| x.excess_kurtosis().unwrap();
+        test_case((1.0, 1.0), -1.2, excess_kurtosis);
+        test_case_special((9.0, 1.0), 2.5470085470085470085, 1e-14, excess_kurtosis);
+        test_case_special((5.0, 100.0), 0.93785046728971962617, 1e-14, excess_kurtosis);
+        test_case_special((0.5, 2.0), 0.81818181818181818182, 1e-14, excess_kurtosis);
+        test_case((1.0, f64::INFINITY), 6.0, excess_kurtosis);
+        test_case((f64::INFINITY, 2.0), 3.0, excess_kurtosis);
+    }
+
     #[test]
     fn test_mode() {
         let mode = |x: Beta| x.mode().unwrap();
@@ -674,9 +771,35 @@ mod tests {
         test_case((1.0, 1.0), 0.0, sf(2.0));
     }
 
+    #[test]
+    fn test_from_mean_variance() {
+        for &(mean, variance) in [(0.5, 0.05), (0.2, 0.01), (0.9, 0.08)].iter() {
+            let n = Beta::from_mean_variance(mean, variance).unwrap();
+            assert_relative_eq!(n.mean().unwrap(), mean, max_relative = 1e-14);
+            assert_relative_eq!(n.variance().unwrap(), variance, max_relative = 1e-14);
+        }
+        assert_eq!(Beta::from_mean_variance(0.5, 1.0 / 12.0).unwrap(), Beta::new(1.0, 1.0).unwrap());
+        assert!(Beta::from_mean_variance(0.0, 0.01).is_err());
+        assert!(Beta::from_mean_variance(1.0, 0.01).is_err());
+        assert!(Beta::from_mean_variance(0.5, 0.0).is_err());
+        // the variance of a beta distribution is below mean * (1 - mean)
+        assert!(Beta::from_mean_variance(0.5, 0.25).is_err());
+        assert!(Beta::from_mean_variance(f64::NAN, 0.01).is_err());
+    }
+
     #[test]
     fn test_continuous() {
         test::check_continuous_distribution(&try_create((1.2, 3.4)), 0.0, 1.0);
         test::check_continuous_distribution(&try_create((4.5, 6.7)), 0.0, 1.0);
     }
+
+    #[test]
+    fn test_from_dirichlet_pair() {
+        use crate::distribution::Dirichlet;
+
+        let pair = Dirichlet::new(vec![1.2, 3.4]).unwrap();
+        assert_eq!(Beta::from_dirichlet_pair(&pair).unwrap(), try_create((1.2, 3.4)));
+        let triple = Dirichlet::new(vec![1.0, 2.0, 3.0]).unwrap();
+        assert!(Beta::from_dirichlet_pair(&triple).is_err());
+    }
 }
@@ -1,4 +1,4 @@
-use crate::distribution::{Discrete, DiscreteCDF};
+use crate::distribution::{ApproxEq, Discrete, DiscreteCDF};
 use crate::function::{beta, factorial};
 use crate::is_zero;
 use crate::statistics::*;
@@ -85,6 +85,59 @@ impl Binomial {
     pub fn n(&self) -> u64 {
         self.n
     }
+
+    /// Evaluates the log probability mass function at each of `ks`, writing
+    /// the results into `out`. This is equivalent to calling `ln_pmf` on
+    /// every element but only computes the terms depending on `n` and `p`
+    /// once, which pays off when the same support is evaluated for many
+    /// parameter values.
+    ///
+    /// # Panics
+    ///
+    /// If `ks` and `out` do not have the same length
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Binomial, Discrete};
+    ///
+    /// let n = Binomial::new(0.3, 10).unwrap();
+    /// let ks = [0, 3, 10, 11];
+    /// let mut out = [0.0; 4];
+    /// n.ln_pmf_slice(&ks, &mut out);
+    /// for (&k, &x) in ks.iter().zip(out.iter()) {
+    ///     assert_eq!(x, n.ln_pmf(k));
+    /// }
+    /// ```
+    pub fn ln_pmf_slice(&self, ks: &[u64], out: &mut [f64]) {
+        if ks.len() != out.len() {
+            panic!("ks and out must have the same length");
+        }
+        if is_zero(self.p) || ulps_eq!(self.p, 1.0) {
+            for (o, &k) in out.iter_mut().zip(ks) {
+                *o = self.ln_pmf(k);
+            }
+            return;
+        }
+        let ln_n_factorial = factorial::ln_factorial(self.n);
+        let ln_p = self.p.ln();
+        let ln_q = (1.0 - self.p).ln();
+        for (o, &k) in out.iter_mut().zip(ks) {
+            *o = if k > self.n {
+                f64::NEG_INFINITY
+            } else {
+                ln_n_factorial - factorial::ln_factorial(k) - factorial::ln_factorial(self.n - k)
+                    + k as f64 * ln_p
+                    + (self.n - k) as f64 * ln_q
+            };
+        }
+    }
+}
+
+impl ApproxEq for Binomial {
+    fn approx_eq(&self, other: &Binomial, tol: f64) -> bool {
+        self.n == other.n && super::internal::params_approx_eq(&[self.p], &[other.p], tol)
+    }
 }
 
 impl ::rand::distributions::Distribution<f64> for Binomial {
@@ -219,6 +272,17 @@ impl Distribution<f64> for Binomial {
     fn skewness(&self) -> Option<f64> {
         Some((1.0 - 2.0 * self.p) / (self.n as f64 * self.p * (1.0 - self.p)).sqrt())
     }
+    /// Returns the excess kurtosis of the binomial distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 - 6p(1 - p)) / (n * p * (1 - p))
+    /// ```
+    fn excess_kurtosis(&self) -> Option<f64> {
+        let pq = self.p * (1.0 - self.p);
+        Some((1.0 - 6.0 * pq) / (self.n as f64 * pq))
+    }
 }
 
 impl Median<f64> for Binomial {
@@ -413,6 +477,15 @@ mod tests {
         test_case(1.0, 2, f64::NEG_INFINITY, skewness);
     }
 
+    #[test]
+    fn test_excess_kurtosis() {
+        let excess_kurtosis = |x: Binomial| x.excess_kurtosis().unwrap();
+        test_almost(0.3, 3, -0.41269841269841266485, 1e-14, excess_kurtosis);
+        test_almost(0.5, 10, -0.2, 1e-15, excess_kurtosis);
+        test_almost(0.9, 20, 0.25555555555555566521, 1e-14, excess_kurtosis);
+        test_case(0.0, 4, f64::INFINITY, excess_kurtosis);
+    }
+
     #[test]
     fn test_median() {
         let median = |x: Binomial| x.median();
@@ -429,6 +502,25 @@ mod tests {
         test_case(1.0, 2, 2, mode);
     }
 
+    #[test]
+    fn test_information_content() {
+        let n = try_create(0.5, 4);
+        assert_almost_eq!(n.information_content(0), 16f64.ln(), 1e-14);
+        assert_almost_eq!(n.information_content(2), (16.0f64 / 6.0).ln(), 1e-14);
+        assert_eq!(try_create(0.0, 4).information_content(0), 0.0);
+        assert_eq!(try_create(0.0, 4).information_content(1), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_expected_information() {
+        for &(p, n) in &[(0.5, 4), (0.3, 10), (0.9, 25)] {
+            let b = try_create(p, n);
+            let expected: f64 = (0..=n).map(|x| b.pmf(x) * b.information_content(x)).sum();
+            assert_almost_eq!(b.expected_information().unwrap(), expected, 1e-14);
+        }
+        assert_eq!(try_create(1.0, 4).expected_information(), Some(0.0));
+    }
+
     #[test]
     fn test_min_max() {
         let min = |x: Binomial| x.min();
@@ -570,4 +662,42 @@ mod tests {
         test::check_discrete_distribution(&try_create(0.3, 5), 5);
         test::check_discrete_distribution(&try_create(0.7, 10), 10);
     }
+
+    #[test]
+    fn test_quantile_residual() {
+        use ::rand::distributions::Distribution;
+        use ::rand::rngs::StdRng;
+        use ::rand::SeedableRng;
+        use crate::distribution::{ContinuousCDF, Normal};
+
+        let n = try_create(0.3, 20);
+        let std_normal = Normal::new(0.0, 1.0).unwrap();
+        let mut r: StdRng = SeedableRng::seed_from_u64(13);
+        let residuals: Vec<f64> = (0..10_000)
+            .map(|_| {
+                let x = n.sample(&mut r) as u64;
+                n.quantile_residual(x, &mut r)
+            })
+            .collect();
+        test::check_ks(&residuals, |x| std_normal.cdf(x));
+    }
+
+    #[test]
+    fn test_ln_pmf_slice() {
+        let ks: Vec<u64> = (0..=25).collect();
+        let mut out = vec![0.0; ks.len()];
+        for &(p, n) in &[(0.0, 20), (0.3, 20), (0.5, 1), (0.9, 7), (1.0, 20)] {
+            let dist = try_create(p, n);
+            dist.ln_pmf_slice(&ks, &mut out);
+            for (&k, &x) in ks.iter().zip(out.iter()) {
+                assert_eq!(x, dist.ln_pmf(k));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ln_pmf_slice_length_mismatch() {
+        try_create(0.3, 20).ln_pmf_slice(&[1, 2, 3], &mut [0.0; 2]);
+    }
 }
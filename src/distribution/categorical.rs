@@ -1,6 +1,7 @@
-use crate::distribution::{Discrete, DiscreteCDF};
+use crate::distribution::{ApproxEq, Discrete, DiscreteCDF};
 use crate::statistics::*;
 use crate::{Result, StatsError};
+use rand::distributions::Open01;
 use rand::Rng;
 use std::f64;
 
@@ -75,6 +76,96 @@ impl Categorical {
     fn cdf_max(&self) -> f64 {
         *self.cdf.last().unwrap()
     }
+
+    /// Draws a sample from the categorical distribution using the
+    /// Gumbel-max trick, i.e. by adding independent standard Gumbel noise
+    /// to the log probabilities and returning the index of the largest
+    /// perturbed value. The result is distributed identically to regular
+    /// categorical sampling.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Categorical;
+    ///
+    /// let n = Categorical::new(&[0.0, 1.0, 2.0]).unwrap();
+    /// let x = n.gumbel_max_sample(&mut rand::thread_rng());
+    /// assert!(x == 1 || x == 2);
+    /// ```
+    pub fn gumbel_max_sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        self.gumbel_perturbed_ln_pmf(rng)
+            .iter()
+            .enumerate()
+            .fold((0, f64::NEG_INFINITY), |(best, max), (i, &x)| {
+                if x > max {
+                    (i, x)
+                } else {
+                    (best, max)
+                }
+            })
+            .0 as u64
+    }
+
+    /// Draws a sample from the Gumbel-softmax (concrete) relaxation of the
+    /// categorical distribution with the given `temperature`, returning a
+    /// probability vector over the categories. As the temperature approaches
+    /// `0` the result approaches the one-hot encoding of a Gumbel-max sample.
+    ///
+    /// # Panics
+    ///
+    /// If `temperature` is `NaN` or `temperature <= 0.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// softmax((ln(p_j) + g_j) / τ)
+    /// ```
+    ///
+    /// where `p_j` is the `j`th probability mass, `g_j` are independent
+    /// standard Gumbel samples and `τ` is the temperature
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Categorical;
+    /// use statrs::prec;
+    ///
+    /// let n = Categorical::new(&[1.0, 1.0, 2.0]).unwrap();
+    /// let y = n.gumbel_softmax(0.5, &mut rand::thread_rng());
+    /// assert!(prec::almost_eq(y.iter().sum::<f64>(), 1.0, 1e-12));
+    /// ```
+    pub fn gumbel_softmax<R: Rng + ?Sized>(&self, temperature: f64, rng: &mut R) -> Vec<f64> {
+        if temperature.is_nan() || temperature <= 0.0 {
+            panic!("temperature must be positive");
+        }
+        let logits: Vec<f64> = self
+            .gumbel_perturbed_ln_pmf(rng)
+            .iter()
+            .map(|x| x / temperature)
+            .collect();
+        let max = logits.iter().fold(f64::NEG_INFINITY, |m, &x| m.max(x));
+        let exp: Vec<f64> = logits.iter().map(|x| (x - max).exp()).collect();
+        let sum: f64 = exp.iter().sum();
+        exp.iter().map(|x| x / sum).collect()
+    }
+
+    // Returns the log probability masses perturbed by independent standard
+    // Gumbel noise
+    fn gumbel_perturbed_ln_pmf<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<f64> {
+        self.norm_pmf
+            .iter()
+            .map(|p| {
+                let u: f64 = rng.sample(Open01);
+                p.ln() - (-u.ln()).ln()
+            })
+            .collect()
+    }
+}
+
+impl ApproxEq for Categorical {
+    fn approx_eq(&self, other: &Categorical, tol: f64) -> bool {
+        super::internal::params_approx_eq(&self.norm_pmf, &other.norm_pmf, tol)
+    }
 }
 
 impl ::rand::distributions::Distribution<f64> for Categorical {
@@ -544,4 +635,49 @@ mod tests {
         test::check_discrete_distribution(&try_create(&[1.0, 2.0, 3.0, 4.0]), 4);
         test::check_discrete_distribution(&try_create(&[0.0, 1.0, 2.0, 3.0, 4.0]), 5);
     }
+
+    #[test]
+    fn test_gumbel_max_sample() {
+        use ::rand::rngs::StdRng;
+        use ::rand::SeedableRng;
+
+        let prob_mass = [0.0, 1.0, 2.0, 5.0, 2.0];
+        let n = try_create(&prob_mass);
+        let mut r: StdRng = SeedableRng::seed_from_u64(5);
+        let trials = 50_000;
+        let mut counts = [0; 5];
+        for _ in 0..trials {
+            counts[n.gumbel_max_sample(&mut r) as usize] += 1;
+        }
+        assert_eq!(counts[0], 0);
+        for (i, &c) in counts.iter().enumerate() {
+            assert_almost_eq!(c as f64 / trials as f64, n.pmf(i as u64), 0.01);
+        }
+    }
+
+    #[test]
+    fn test_gumbel_softmax() {
+        use ::rand::rngs::StdRng;
+        use ::rand::SeedableRng;
+
+        let n = try_create(&[0.0, 1.0, 2.0, 5.0, 2.0]);
+        let mut r: StdRng = SeedableRng::seed_from_u64(5);
+        for &temperature in &[0.1, 1.0, 10.0] {
+            let y = n.gumbel_softmax(temperature, &mut r);
+            assert_eq!(y.len(), 5);
+            assert_eq!(y[0], 0.0);
+            assert!(y.iter().all(|&p| (0.0..=1.0).contains(&p)));
+            assert_almost_eq!(y.iter().sum::<f64>(), 1.0, 1e-12);
+        }
+        // a tiny temperature gives the one-hot gumbel-max sample
+        let y = n.gumbel_softmax(1e-6, &mut r);
+        assert_eq!(y.iter().filter(|&&p| p > 0.999).count(), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gumbel_softmax_bad_temperature() {
+        let n = try_create(&[1.0, 2.0]);
+        n.gumbel_softmax(0.0, &mut ::rand::thread_rng());
+    }
 }
@@ -1,4 +1,4 @@
-use crate::distribution::{Continuous, ContinuousCDF};
+use crate::distribution::{ApproxEq, Continuous, ContinuousCDF};
 use crate::statistics::*;
 use crate::{Result, StatsError};
 use rand::Rng;
@@ -79,6 +79,16 @@ impl Cauchy {
     }
 }
 
+impl ApproxEq for Cauchy {
+    fn approx_eq(&self, other: &Cauchy, tol: f64) -> bool {
+        super::internal::params_approx_eq(
+            &[self.location, self.scale],
+            &[other.location, other.scale],
+            tol,
+        )
+    }
+}
+
 impl ::rand::distributions::Distribution<f64> for Cauchy {
     fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
         self.location + self.scale * (f64::consts::PI * (r.gen::<f64>() - 0.5)).tan()
@@ -146,6 +156,16 @@ impl Max<f64> for Cauchy {
 }
 
 impl Distribution<f64> for Cauchy {
+    /// Returns `None` since the mean of the cauchy distribution is
+    /// undefined
+    fn mean(&self) -> Option<f64> {
+        None
+    }
+    /// Returns `None` since the variance of the cauchy distribution is
+    /// undefined
+    fn variance(&self) -> Option<f64> {
+        None
+    }
     /// Returns the entropy of the cauchy distribution
     ///
     /// # Formula
@@ -158,6 +178,19 @@ impl Distribution<f64> for Cauchy {
     fn entropy(&self) -> Option<f64> {
         Some((4.0 * f64::consts::PI * self.scale).ln())
     }
+    /// Returns the center of symmetry of the cauchy distribution, i.e.
+    /// the location
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// x_0
+    /// ```
+    ///
+    /// where `x_0` is the location
+    fn symmetry_center(&self) -> Option<f64> {
+        Some(self.location)
+    }
 }
 
 impl Median<f64> for Cauchy {
@@ -284,6 +317,15 @@ mod tests {
         bad_create_case(1.0, 0.0);
     }
 
+    #[test]
+    fn test_undefined_moments() {
+        let n = try_create(1.0, 2.0);
+        assert_eq!(n.mean(), None);
+        assert_eq!(n.variance(), None);
+        assert_eq!(n.std_dev(), None);
+        assert_eq!(n.probability_beyond_sigma(1.0), None);
+    }
+
     #[test]
     fn test_entropy() {
         let entropy = |x: Cauchy| x.entropy().unwrap();
@@ -293,6 +335,15 @@ mod tests {
         test_case(10.0, 11.0, 4.92891951976766133704, entropy);
     }
 
+    #[test]
+    fn test_symmetry_center() {
+        let center = |x: Cauchy| x.symmetry_center().unwrap();
+        test_case(0.0, 2.0, 0.0, center);
+        test_case(-3.0, 0.5, -3.0, center);
+        test_case(10.0, 11.0, 10.0, center);
+        assert!(try_create(1.0, 1.0).is_symmetric());
+    }
+
     #[test]
     fn test_mode() {
         let mode = |x: Cauchy| x.mode().unwrap();
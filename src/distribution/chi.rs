@@ -1,4 +1,4 @@
-use crate::distribution::{Continuous, ContinuousCDF};
+use crate::distribution::{ApproxEq, Continuous, ContinuousCDF};
 use crate::function::gamma;
 use crate::statistics::*;
 use crate::{Result, StatsError};
@@ -68,6 +68,12 @@ impl Chi {
     }
 }
 
+impl ApproxEq for Chi {
+    fn approx_eq(&self, other: &Chi, tol: f64) -> bool {
+        super::internal::params_approx_eq(&[self.freedom], &[other.freedom], tol)
+    }
+}
+
 impl ::rand::distributions::Distribution<f64> for Chi {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
         (0..self.freedom as i64)
@@ -246,6 +252,26 @@ impl Distribution<f64> for Chi {
         let skew = self.mean()? * (1.0 - 2.0 * sigma * sigma) / (sigma * sigma * sigma);
         Some(skew)
     }
+    /// Returns the excess kurtosis of the chi distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `NaN` if `freedom` is `INF`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (2 / σ^2) * (1 - μσγ - σ^2)
+    /// ```
+    ///
+    /// where `μ` is the mean, `σ` the standard deviation and `γ` the skewness
+    /// of the distribution
+    fn excess_kurtosis(&self) -> Option<f64> {
+        let mu = self.mean()?;
+        let sigma = self.std_dev()?;
+        let skew = self.skewness()?;
+        Some(2.0 / (sigma * sigma) * (1.0 - mu * sigma * skew - sigma * sigma))
+    }
 }
 
 impl Mode<Option<f64>> for Chi {
@@ -451,6 +477,15 @@ mod tests {
         get_value(f64::INFINITY, skewness);
     }
 
+    #[test]
+    fn test_excess_kurtosis() {
+        let excess_kurtosis = |x: Chi| x.excess_kurtosis().unwrap();
+        test_almost(1.0, 0.86917730360597411666, 1e-12, excess_kurtosis);
+        test_almost(2.0, 0.24508930068763806285, 1e-12, excess_kurtosis);
+        test_almost(2.5, 0.15709166214579089199, 1e-12, excess_kurtosis);
+        test_almost(3.0, 0.10816384281629414695, 1e-12, excess_kurtosis);
+    }
+
     #[test]
     fn test_mode() {
         let mode = |x: Chi| x.mode().unwrap();
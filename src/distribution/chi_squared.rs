@@ -1,4 +1,4 @@
-use crate::distribution::{Continuous, ContinuousCDF, Gamma};
+use crate::distribution::{ApproxEq, Continuous, ContinuousCDF, Gamma};
 use crate::statistics::*;
 use crate::Result;
 use rand::Rng;
@@ -94,6 +94,45 @@ impl ChiSquared {
     pub fn rate(&self) -> f64 {
         self.g.rate()
     }
+
+    /// Approximates the cumulative distribution function at `x` with the
+    /// Wilson–Hilferty transformation, under which the cube root of
+    /// `x / k` is approximately normally distributed. This is cheaper than
+    /// the exact `cdf`.
+    ///
+    /// # Remarks
+    ///
+    /// The absolute error decreases roughly in proportion to `1 / k`. It is
+    /// about `1e-3` for `k = 10`, a few parts in `10^4` for `k >= 30` and
+    /// about `1e-4` for `k = 100`, while for `k <= 2` it exceeds `1e-2` and
+    /// the exact `cdf` should be preferred.
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Φ(((x / k)^(1/3) - (1 - 2 / (9k))) / sqrt(2 / (9k)))
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom and `Φ` is the cdf of the
+    /// standard normal distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ChiSquared, ContinuousCDF};
+    ///
+    /// let n = ChiSquared::new(50.0).unwrap();
+    /// assert!((n.wilson_hilferty_cdf(60.0) - n.cdf(60.0)).abs() < 5e-4);
+    /// ```
+    pub fn wilson_hilferty_cdf(&self, x: f64) -> f64 {
+        self.g.wilson_hilferty_cdf(x)
+    }
+}
+
+impl ApproxEq for ChiSquared {
+    fn approx_eq(&self, other: &ChiSquared, tol: f64) -> bool {
+        super::internal::params_approx_eq(&[self.freedom], &[other.freedom], tol)
+    }
 }
 
 impl ::rand::distributions::Distribution<f64> for ChiSquared {
@@ -214,6 +253,18 @@ impl Distribution<f64> for ChiSquared {
     fn skewness(&self) -> Option<f64> {
         self.g.skewness()
     }
+    /// Returns the excess kurtosis of the chi-squared distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 12 / k
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom
+    fn excess_kurtosis(&self) -> Option<f64> {
+        self.g.excess_kurtosis()
+    }
 }
 
 impl Median<f64> for ChiSquared {
@@ -309,6 +360,37 @@ mod tests {
         assert_almost_eq!(expected, x, acc);
     }
 
+    #[test]
+    fn test_wilson_hilferty_cdf() {
+        use crate::distribution::ContinuousCDF;
+
+        let max_error = |freedom: f64| {
+            let n = try_create(freedom);
+            (1..80)
+                .map(|i| freedom * i as f64 / 20.0)
+                .map(|x| (n.wilson_hilferty_cdf(x) - n.cdf(x)).abs())
+                .fold(0.0, f64::max)
+        };
+        // a few parts in 10^4 for moderate degrees of freedom
+        assert!(max_error(30.0) < 5e-4);
+        assert!(max_error(50.0) < 3e-4);
+        assert!(max_error(100.0) < 1.5e-4);
+        assert!(max_error(10.0) < 1.5e-3);
+        // but it degrades for small degrees of freedom
+        assert!(max_error(1.0) > 1e-2);
+        assert!(max_error(1.0) > max_error(5.0));
+        assert_eq!(try_create(3.0).wilson_hilferty_cdf(0.0), 0.0);
+    }
+
+    #[test]
+    fn test_excess_kurtosis() {
+        use crate::statistics::Distribution;
+        let excess_kurtosis = |x: ChiSquared| x.excess_kurtosis().unwrap();
+        test_case(1.0, 12.0, excess_kurtosis);
+        test_case(4.0, 3.0, excess_kurtosis);
+        test_almost(7.0, 12.0 / 7.0, 1e-15, excess_kurtosis);
+    }
+
     #[test]
     fn test_median() {
         let median = |x: ChiSquared| x.median();
@@ -0,0 +1,136 @@
+use rand::Rng;
+
+/// Implements a two-level
+/// [compound](https://en.wikipedia.org/wiki/Compound_probability_distribution)
+/// distribution, where a parameter drawn from an outer distribution is used
+/// to build the inner distribution that the sample is drawn from
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Compound, Gamma, Poisson};
+/// use rand::distributions::Distribution;
+///
+/// // a gamma mixture of Poisson distributions is negative binomial
+/// let c = Compound::new(Gamma::new(4.0, 1.0).unwrap(), |lambda| {
+///     Poisson::new(lambda).unwrap()
+/// });
+/// let x = c.sample(&mut rand::thread_rng());
+/// assert!(x >= 0.0 && x.fract() == 0.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Compound<O, F> {
+    outer: O,
+    build: F,
+}
+
+impl<O, F> Compound<O, F> {
+    /// Constructs a new compound distribution, where the parameter drawn
+    /// from `outer` is passed to `build` to construct the inner
+    /// distribution
+    ///
+    /// # Remarks
+    ///
+    /// `build` is called once per sample and may panic if the drawn
+    /// parameter is not valid for the inner distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Compound, Normal};
+    ///
+    /// let c = Compound::new(Normal::new(0.0, 1.0).unwrap(), |mu| {
+    ///     Normal::new(mu, 1.0).unwrap()
+    /// });
+    /// ```
+    pub fn new(outer: O, build: F) -> Compound<O, F> {
+        Compound { outer, build }
+    }
+
+    /// Returns the outer distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Compound, Normal};
+    ///
+    /// let outer = Normal::new(0.0, 1.0).unwrap();
+    /// let c = Compound::new(outer, |mu| Normal::new(mu, 1.0).unwrap());
+    /// assert_eq!(c.outer(), &outer);
+    /// ```
+    pub fn outer(&self) -> &O {
+        &self.outer
+    }
+}
+
+impl<O, F, I> Compound<O, F>
+where
+    O: ::rand::distributions::Distribution<f64>,
+    F: Fn(f64) -> I,
+    I: ::rand::distributions::Distribution<f64>,
+{
+    /// Draws a sample from the compound distribution and returns it together
+    /// with the parameter drawn from the outer distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Compound, Uniform};
+    ///
+    /// let c = Compound::new(Uniform::new(0.0, 1.0).unwrap(), |a| {
+    ///     Uniform::new(a, a + 1.0).unwrap()
+    /// });
+    /// let (a, x) = c.sample_with_parameter(&mut rand::thread_rng());
+    /// assert!(a <= x && x <= a + 1.0);
+    /// ```
+    pub fn sample_with_parameter<R: Rng + ?Sized>(&self, rng: &mut R) -> (f64, f64) {
+        let theta = self.outer.sample(rng);
+        (theta, (self.build)(theta).sample(rng))
+    }
+}
+
+impl<O, F, I> ::rand::distributions::Distribution<f64> for Compound<O, F>
+where
+    O: ::rand::distributions::Distribution<f64>,
+    F: Fn(f64) -> I,
+    I: ::rand::distributions::Distribution<f64>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        self.sample_with_parameter(rng).1
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(all(test, feature = "nightly"))]
+mod tests {
+    use crate::statistics::*;
+    use crate::distribution::{Compound, Gamma, NegativeBinomial, Normal, Poisson};
+    use ::rand::distributions::Distribution as RandDistribution;
+    use ::rand::rngs::StdRng;
+    use ::rand::SeedableRng;
+
+    #[test]
+    fn test_gamma_poisson_is_negative_binomial() {
+        let (r, p) = (3.0, 0.4);
+        let nb = NegativeBinomial::new(r, p).unwrap();
+        let c = Compound::new(Gamma::new(r, p / (1.0 - p)).unwrap(), |lambda| Poisson::new(lambda).unwrap());
+        let mut rng: StdRng = SeedableRng::seed_from_u64(550);
+        let samples: Vec<f64> = (0..200_000).map(|_| c.sample(&mut rng)).collect();
+        let (mean, variance) = (nb.mean().unwrap(), nb.variance().unwrap());
+        assert_almost_eq!(samples.iter().mean(), mean, 0.05);
+        assert_almost_eq!(samples.iter().variance(), variance, 0.3);
+        // the frequency of zeros matches the negative binomial pmf p^r
+        let zeros = samples.iter().filter(|&&x| x == 0.0).count() as f64 / samples.len() as f64;
+        assert_almost_eq!(zeros, p.powf(r), 0.005);
+    }
+
+    #[test]
+    fn test_sample_with_parameter() {
+        let c = Compound::new(Normal::new(5.0, 2.0).unwrap(), |mu| Normal::new(mu, 1e-9).unwrap());
+        let mut rng: StdRng = SeedableRng::seed_from_u64(1);
+        for _ in 0..100 {
+            let (mu, x) = c.sample_with_parameter(&mut rng);
+            assert_almost_eq!(mu, x, 1e-6);
+        }
+    }
+}
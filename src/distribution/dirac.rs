@@ -1,4 +1,4 @@
-use crate::distribution::{Continuous, ContinuousCDF};
+use crate::distribution::{ApproxEq, Continuous, ContinuousCDF};
 use crate::statistics::*;
 use crate::{Result, StatsError};
 use rand::Rng;
@@ -45,6 +45,12 @@ impl Dirac {
     }
 }
 
+impl ApproxEq for Dirac {
+    fn approx_eq(&self, other: &Dirac, tol: f64) -> bool {
+        super::internal::params_approx_eq(&[self.0], &[other.0], tol)
+    }
+}
+
 impl ::rand::distributions::Distribution<f64> for Dirac {
     fn sample<R: Rng + ?Sized>(&self, _: &mut R) -> f64 {
         self.0
@@ -151,6 +157,16 @@ impl Distribution<f64> for Dirac {
     fn skewness(&self) -> Option<f64> {
         Some(0.0)
     }
+    /// Returns the excess kurtosis of the dirac distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn excess_kurtosis(&self) -> Option<f64> {
+        Some(0.0)
+    }
 }
 
 impl Median<f64> for Dirac {
@@ -251,6 +267,13 @@ mod tests {
         test_case(f64::INFINITY, 0.0, skewness);
     }
 
+    #[test]
+    fn test_excess_kurtosis() {
+        let excess_kurtosis = |x: Dirac| x.excess_kurtosis().unwrap();
+        test_case(0.0, 0.0, excess_kurtosis);
+        test_case(4.0, 0.0, excess_kurtosis);
+    }
+
     #[test]
     fn test_mode() {
         let mode = |x: Dirac| x.mode().unwrap();
@@ -1,4 +1,4 @@
-use crate::distribution::Continuous;
+use crate::distribution::{ApproxEq, Continuous};
 use crate::function::gamma;
 use crate::statistics::*;
 use crate::{prec, Result, StatsError};
@@ -88,6 +88,82 @@ impl Dirichlet {
         Self::new(vec![alpha; n])
     }
 
+    /// Fits a dirichlet distribution to `samples` by maximum likelihood,
+    /// using the fixed-point iteration
+    /// `α_k = ψ^-1(ψ(Σ α_i) + mean(ln(x_k)))` of "Estimating a Dirichlet
+    /// distribution", Thomas P. Minka, 2000, started from the method of
+    /// moments estimate.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are fewer than 2 samples, if the samples
+    /// have different lengths or fewer than 2 components, if any component
+    /// is not in `(0, 1)`, if any sample does not sum to 1, or if the
+    /// iteration does not converge, e.g. because all samples are equal
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Dirichlet;
+    ///
+    /// let samples = vec![
+    ///     vec![0.2, 0.3, 0.5],
+    ///     vec![0.1, 0.6, 0.3],
+    ///     vec![0.3, 0.3, 0.4],
+    ///     vec![0.25, 0.45, 0.3],
+    /// ];
+    /// let n = Dirichlet::from_data(&samples).unwrap();
+    /// assert_eq!(n.alpha().len(), 3);
+    /// ```
+    pub fn from_data(samples: &[Vec<f64>]) -> Result<Dirichlet> {
+        if samples.len() < 2 {
+            return Err(StatsError::BadParams);
+        }
+        let k = samples[0].len();
+        let is_valid = |x: &Vec<f64>| {
+            x.len() == k
+                && x.iter().all(|&v| v > 0.0 && v < 1.0)
+                && prec::almost_eq(x.iter().sum::<f64>(), 1.0, 1e-4)
+        };
+        if k < 2 || !samples.iter().all(is_valid) {
+            return Err(StatsError::BadParams);
+        }
+
+        let n = samples.len() as f64;
+        let mean_ln: Vec<f64> = (0..k)
+            .map(|i| samples.iter().map(|x| x[i].ln()).sum::<f64>() / n)
+            .collect();
+        let mean: Vec<f64> = (0..k)
+            .map(|i| samples.iter().map(|x| x[i]).sum::<f64>() / n)
+            .collect();
+        // the method of moments estimate of the precision from the first
+        // component
+        let second_moment = samples.iter().map(|x| x[0] * x[0]).sum::<f64>() / n;
+        let precision = (mean[0] - second_moment) / (second_moment - mean[0] * mean[0]);
+        let mut alpha: Vec<f64> = if precision.is_finite() && precision > 0.0 {
+            mean.iter().map(|m| m * precision).collect()
+        } else {
+            vec![1.0; k]
+        };
+
+        for _ in 0..10_000 {
+            let digamma_sum = gamma::digamma(alpha.iter().sum());
+            let mut converged = true;
+            for (a, l) in alpha.iter_mut().zip(mean_ln.iter()) {
+                let next = gamma::inv_digamma(digamma_sum + l);
+                converged &= (next - *a).abs() <= 1e-12 * next;
+                *a = next;
+            }
+            if !alpha.iter().all(|a| a.is_finite() && *a > 0.0) {
+                return Err(StatsError::BadParams);
+            }
+            if converged {
+                return Dirichlet::new(alpha);
+            }
+        }
+        Err(StatsError::BadParams)
+    }
+
     /// Returns the concentration parameters of
     /// the dirichlet distribution as a slice
     ///
@@ -104,6 +180,52 @@ impl Dirichlet {
         &self.alpha
     }
 
+    /// Returns the dirichlet distribution of the sums of the components in
+    /// each of `groups`, whose concentration parameters are the sums of
+    /// the concentration parameters of the grouped components. Each group
+    /// lists the indices of the components it merges.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `groups` do not partition the indices of the
+    /// concentration parameters, i.e. if any index is out of range, missing
+    /// or listed more than once, if any group is empty, or if there are
+    /// fewer than 2 groups
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Dirichlet;
+    /// use nalgebra::DVector;
+    ///
+    /// let n = Dirichlet::new(vec![1.0, 2.0, 3.0, 4.0]).unwrap();
+    /// let aggregated = n.aggregate(&[vec![0, 2], vec![1], vec![3]]).unwrap();
+    /// assert_eq!(aggregated.alpha(), &DVector::from_vec(vec![4.0, 2.0, 4.0]));
+    /// assert!(n.aggregate(&[vec![0, 1], vec![1, 2, 3]]).is_err());
+    /// ```
+    pub fn aggregate(&self, groups: &[Vec<usize>]) -> Result<Dirichlet> {
+        let mut seen = vec![false; self.alpha.len()];
+        let mut alpha = Vec::with_capacity(groups.len());
+        for group in groups {
+            if group.is_empty() {
+                return Err(StatsError::BadParams);
+            }
+            let mut sum = 0.0;
+            for &i in group {
+                match seen.get_mut(i) {
+                    Some(s) if !*s => *s = true,
+                    _ => return Err(StatsError::BadParams),
+                }
+                sum += self.alpha[i];
+            }
+            alpha.push(sum);
+        }
+        if seen.iter().any(|&s| !s) {
+            return Err(StatsError::BadParams);
+        }
+        Dirichlet::new(alpha)
+    }
+
     fn alpha_sum(&self) -> f64 {
         self.alpha.fold(0.0, |acc, x| acc + x)
     }
@@ -136,6 +258,12 @@ impl Dirichlet {
     }
 }
 
+impl ApproxEq for Dirichlet {
+    fn approx_eq(&self, other: &Dirichlet, tol: f64) -> bool {
+        super::internal::params_approx_eq(self.alpha.as_slice(), other.alpha.as_slice(), tol)
+    }
+}
+
 impl ::rand::distributions::Distribution<DVector<f64>> for Dirichlet {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> DVector<f64> {
         let mut sum = 0.0;
@@ -452,4 +580,69 @@ mod tests {
         let n = try_create(&[0.1, 0.3, 0.5, 0.8]);
         n.ln_pdf(&dvec![0.5, 0.25, 0.8, 0.9]);
     }
+
+    #[test]
+    fn test_aggregate() {
+        let n = try_create(&[1.0, 2.0, 3.0, 4.0]);
+        let aggregated = n.aggregate(&[vec![3], vec![0, 2], vec![1]]).unwrap();
+        assert_eq!(aggregated.alpha(), &dvec![4.0, 4.0, 2.0]);
+        let aggregated = n.aggregate(&[vec![0, 1, 2, 3]]);
+        assert!(aggregated.is_err());
+        let identity = n.aggregate(&[vec![0], vec![1], vec![2], vec![3]]).unwrap();
+        assert_eq!(identity, n);
+    }
+
+    #[test]
+    fn test_aggregate_beta_marginal() {
+        use crate::distribution::Beta;
+
+        // X_0 + X_1 of a dirichlet is beta distributed with the summed
+        // concentrations against the remaining ones
+        let n = try_create(&[0.5, 1.5, 2.0, 3.0]);
+        let pair = n.aggregate(&[vec![0, 1], vec![2, 3]]).unwrap();
+        let beta = Beta::from_dirichlet_pair(&pair).unwrap();
+        assert_eq!(beta, Beta::new(2.0, 5.0).unwrap());
+        for &x in &[0.1, 0.25, 0.5, 0.9] {
+            assert_almost_eq!(pair.pdf(&dvec![x, 1.0 - x]), beta.pdf(x), 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_aggregate_bad_groups() {
+        let n = try_create(&[1.0, 2.0, 3.0]);
+        assert!(n.aggregate(&[vec![0, 1], vec![1, 2]]).is_err());
+        assert!(n.aggregate(&[vec![0], vec![1]]).is_err());
+        assert!(n.aggregate(&[vec![0], vec![1, 2, 3]]).is_err());
+        assert!(n.aggregate(&[vec![0, 1, 2], vec![]]).is_err());
+    }
+
+    #[test]
+    fn test_from_data() {
+        use ::rand::distributions::Distribution;
+        use ::rand::rngs::StdRng;
+        use ::rand::SeedableRng;
+
+        let alpha = [2.0, 5.0, 3.0];
+        let n = try_create(&alpha);
+        let mut r: StdRng = SeedableRng::seed_from_u64(31);
+        let samples: Vec<Vec<f64>> = (0..20_000)
+            .map(|_| n.sample(&mut r).iter().cloned().collect())
+            .collect();
+        let fitted = Dirichlet::from_data(&samples).unwrap();
+        for (&a, &b) in fitted.alpha().iter().zip(alpha.iter()) {
+            assert_almost_eq!(a / b, 1.0, 0.05);
+        }
+    }
+
+    #[test]
+    fn test_from_data_bad_samples() {
+        let good = vec![0.2, 0.3, 0.5];
+        assert!(Dirichlet::from_data(std::slice::from_ref(&good)).is_err());
+        assert!(Dirichlet::from_data(&[good.clone(), vec![0.5, 0.5]]).is_err());
+        assert!(Dirichlet::from_data(&[good.clone(), vec![0.0, 0.5, 0.5]]).is_err());
+        assert!(Dirichlet::from_data(&[good.clone(), vec![0.2, 0.2, 0.2]]).is_err());
+        assert!(Dirichlet::from_data(&[vec![1.0], vec![1.0]]).is_err());
+        // identical samples have no finite maximum likelihood estimate
+        assert!(Dirichlet::from_data(&[good.clone(), good]).is_err());
+    }
 }
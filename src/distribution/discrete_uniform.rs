@@ -1,4 +1,4 @@
-use crate::distribution::{Discrete, DiscreteCDF};
+use crate::distribution::{ApproxEq, Discrete, DiscreteCDF};
 use crate::statistics::*;
 use crate::{Result, StatsError};
 use rand::Rng;
@@ -51,6 +51,12 @@ impl DiscreteUniform {
     }
 }
 
+impl ApproxEq for DiscreteUniform {
+    fn approx_eq(&self, other: &DiscreteUniform, _tol: f64) -> bool {
+        self.min == other.min && self.max == other.max
+    }
+}
+
 impl ::rand::distributions::Distribution<f64> for DiscreteUniform {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
         rng.gen_range(self.min..=self.max) as f64
@@ -169,6 +175,24 @@ impl Distribution<f64> for DiscreteUniform {
     fn skewness(&self) -> Option<f64> {
         Some(0.0)
     }
+    /// Returns the excess kurtosis of the discrete uniform distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -6(n^2 + 1) / (5(n^2 - 1))
+    /// ```
+    ///
+    /// where `n = max - min + 1` is the number of values, the excess kurtosis is
+    /// `0` for a single value
+    fn excess_kurtosis(&self) -> Option<f64> {
+        let n = (self.max - self.min) as f64 + 1.0;
+        if n == 1.0 {
+            Some(0.0)
+        } else {
+            Some(-6.0 * (n * n + 1.0) / (5.0 * (n * n - 1.0)))
+        }
+    }
 }
 
 impl Median<f64> for DiscreteUniform {
@@ -335,6 +359,15 @@ mod tests {
         test_case(20, 20, 0.0, skewness);
     }
 
+    #[test]
+    fn test_excess_kurtosis() {
+        let excess_kurtosis = |x: DiscreteUniform| x.excess_kurtosis().unwrap();
+        test_case(-10, 10, -1.2054545454545454545, excess_kurtosis);
+        test_case(0, 4, -1.3, excess_kurtosis);
+        test_case(0, 1, -2.0, excess_kurtosis);
+        test_case(20, 20, 0.0, excess_kurtosis);
+    }
+
     #[test]
     fn test_median() {
         let median = |x: DiscreteUniform| x.median();
@@ -1,4 +1,4 @@
-use crate::distribution::{Continuous, ContinuousCDF, Gamma};
+use crate::distribution::{ApproxEq, Continuous, ContinuousCDF, Gamma};
 use crate::statistics::*;
 use crate::Result;
 use rand::Rng;
@@ -78,6 +78,12 @@ impl Erlang {
     }
 }
 
+impl ApproxEq for Erlang {
+    fn approx_eq(&self, other: &Erlang, tol: f64) -> bool {
+        self.g.approx_eq(&other.g, tol)
+    }
+}
+
 impl ::rand::distributions::Distribution<f64> for Erlang {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
         ::rand::distributions::Distribution::sample(&self.g, rng)
@@ -203,6 +209,18 @@ impl Distribution<f64> for Erlang {
     fn skewness(&self) -> Option<f64> {
         self.g.skewness()
     }
+    /// Returns the excess kurtosis of the erlang distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 6 / k
+    /// ```
+    ///
+    /// where `k` is the shape
+    fn excess_kurtosis(&self) -> Option<f64> {
+        self.g.excess_kurtosis()
+    }
 }
 
 impl Mode<Option<f64>> for Erlang {
@@ -313,4 +331,11 @@ mod tests {
         test::check_continuous_distribution(&try_create(2, 1.5), 0.0, 20.0);
         test::check_continuous_distribution(&try_create(3, 0.5), 0.0, 20.0);
     }
+
+    #[test]
+    fn test_excess_kurtosis() {
+        use crate::statistics::Distribution;
+        assert_eq!(try_create(1, 0.5).excess_kurtosis().unwrap(), 6.0);
+        assert_eq!(try_create(4, 3.0).excess_kurtosis().unwrap(), 1.5);
+    }
 }
@@ -1,4 +1,4 @@
-use crate::distribution::{ziggurat, Continuous, ContinuousCDF};
+use crate::distribution::{ziggurat, ApproxEq, Continuous, ContinuousCDF, Lomax};
 use crate::statistics::*;
 use crate::{Result, StatsError};
 use rand::Rng;
@@ -52,6 +52,51 @@ impl Exp {
         }
     }
 
+    /// Constructs a new exponential distribution with a rate (λ) of `rate`,
+    /// where the type of `rate` rules out passing a scale by mistake. Only
+    /// available with the `typed_params` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error under the same conditions as `new`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Exp, Rate};
+    ///
+    /// let n = Exp::new_typed(Rate(2.0)).unwrap();
+    /// assert_eq!(n, Exp::new(2.0).unwrap());
+    /// ```
+    #[cfg(feature = "typed_params")]
+    pub fn new_typed(rate: crate::distribution::Rate) -> Result<Exp> {
+        Exp::new(rate.0)
+    }
+
+    /// Constructs a new exponential distribution with a scale (mean) of
+    /// `scale`, i.e. a rate of `1 / scale`. Only available with the
+    /// `typed_params` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `scale` is `NaN`, inf or `scale <= 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Exp, Scale};
+    ///
+    /// let n = Exp::from_scale(Scale(0.5)).unwrap();
+    /// assert_eq!(n, Exp::new(2.0).unwrap());
+    /// ```
+    #[cfg(feature = "typed_params")]
+    pub fn from_scale(scale: crate::distribution::Scale) -> Result<Exp> {
+        if !scale.0.is_finite() || scale.0 <= 0.0 {
+            return Err(StatsError::BadParams);
+        }
+        Exp::new(scale.0.recip())
+    }
+
     /// Returns the rate of the exponential distribution
     ///
     /// # Examples
@@ -65,6 +110,94 @@ impl Exp {
     pub fn rate(&self) -> f64 {
         self.rate
     }
+
+    /// Returns the distribution of the minimum of `n` independent samples
+    /// of the exponential distribution, which is exponential with a rate
+    /// of `n * λ`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `n == 0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Exp;
+    ///
+    /// let n = Exp::new(0.5).unwrap();
+    /// assert_eq!(n.min_of_n(4).unwrap().rate(), 2.0);
+    /// assert!(n.min_of_n(0).is_err());
+    /// ```
+    pub fn min_of_n(&self, n: u64) -> Result<Exp> {
+        if n == 0 {
+            Err(StatsError::BadParams)
+        } else {
+            Exp::new(n as f64 * self.rate)
+        }
+    }
+
+    /// Returns the accelerated-failure-time reparameterization of the
+    /// distribution, i.e. the distribution of `X / factor`, which is
+    /// exponential with a rate of `factor * λ`
+    ///
+    /// # Panics
+    ///
+    /// If `factor` is not finite or `factor <= 0.0`, or if the new rate
+    /// underflows to `0.0`. A new rate that overflows is infinite
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Exp;
+    ///
+    /// let n = Exp::new(0.5).unwrap();
+    /// assert_eq!(n.accelerate(2.0), Exp::new(1.0).unwrap());
+    /// ```
+    pub fn accelerate(&self, factor: f64) -> Exp {
+        if !factor.is_finite() || factor <= 0.0 {
+            panic!("factor must be finite and positive");
+        }
+        Exp::new(factor * self.rate).expect("accelerated rate underflows to zero")
+    }
+
+    /// Returns the compound distribution of an exponential distribution
+    /// whose rate is itself gamma distributed with the given `shape` and
+    /// `rate`, i.e. the marginal distribution of `X` where `λ ~ Gamma(α, β)`
+    /// and `X | λ ~ Exp(λ)`. This is the Lomax distribution with a shape of
+    /// `α` and a scale of `β`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `shape` or `rate` are not finite or are less
+    /// than or equal to `0.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// P(X > x) = E[exp(-λx)] = (β / (β + x))^α
+    /// ```
+    ///
+    /// where `α` is the shape and `β` is the rate of the gamma distribution,
+    /// which is the survival function of `Lomax(α, β)`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Exp, Lomax};
+    ///
+    /// let n = Exp::compound_gamma_rate(3.0, 2.0).unwrap();
+    /// assert_eq!(n, Lomax::new(3.0, 2.0).unwrap());
+    /// assert!(Exp::compound_gamma_rate(3.0, f64::INFINITY).is_err());
+    /// ```
+    pub fn compound_gamma_rate(shape: f64, rate: f64) -> Result<Lomax> {
+        Lomax::new(shape, rate)
+    }
+}
+
+impl ApproxEq for Exp {
+    fn approx_eq(&self, other: &Exp, tol: f64) -> bool {
+        super::internal::params_approx_eq(&[self.rate], &[other.rate], tol)
+    }
 }
 
 impl ::rand::distributions::Distribution<f64> for Exp {
@@ -186,6 +319,16 @@ impl Distribution<f64> for Exp {
     fn skewness(&self) -> Option<f64> {
         Some(2.0)
     }
+    /// Returns the excess kurtosis of the exponential distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 6
+    /// ```
+    fn excess_kurtosis(&self) -> Option<f64> {
+        Some(6.0)
+    }
 }
 
 impl Median<f64> for Exp {
@@ -354,6 +497,21 @@ mod tests {
         test_case(10.0, 2.0, skewness);
     }
 
+    #[test]
+    fn test_excess_kurtosis() {
+        let excess_kurtosis = |x: Exp| x.excess_kurtosis().unwrap();
+        test_case(0.1, 6.0, excess_kurtosis);
+        test_case(1.0, 6.0, excess_kurtosis);
+        test_case(10.0, 6.0, excess_kurtosis);
+    }
+
+    #[test]
+    fn test_symmetry_center() {
+        let n = try_create(1.0);
+        assert_eq!(n.symmetry_center(), None);
+        assert!(!n.is_symmetric());
+    }
+
     #[test]
     fn test_median() {
         let median = |x: Exp| x.median();
@@ -488,4 +646,144 @@ mod tests {
         test::check_continuous_distribution(&try_create(1.5), 0.0, 20.0);
         test::check_continuous_distribution(&try_create(2.5), 0.0, 50.0);
     }
+
+    #[test]
+    fn test_inverse_cdf_round_trip() {
+        // the default binary search limits the accuracy of the inverse cdf
+        let probs = [1e-4, 0.01, 0.1, 0.25, 0.5, 0.75, 0.9, 0.99, 0.9999];
+        test::check_inverse_cdf(&try_create(0.5), &probs, 1e-3);
+        test::check_inverse_cdf(&try_create(1.5), &probs, 1e-3);
+        test::check_inverse_cdf(&try_create(2.5), &probs, 1e-3);
+    }
+
+    #[test]
+    fn test_min_of_n() {
+        let n = try_create(1.5);
+        assert_eq!(n.min_of_n(1).unwrap(), n);
+        assert_eq!(n.min_of_n(3).unwrap().rate(), 4.5);
+        assert!(n.min_of_n(0).is_err());
+        for &x in &[0.1, 0.5, 1.0, 2.0] {
+            assert_almost_eq!(n.min_of_n(3).unwrap().cdf(x), n.order_statistic_cdf(1, 3, x), 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_accelerate() {
+        let n = try_create(1.5);
+        let m = n.accelerate(2.0);
+        assert_eq!(m, try_create(3.0));
+        assert_almost_eq!(m.mean().unwrap(), n.mean().unwrap() / 2.0, 1e-15);
+        assert_eq!(n.accelerate(1.0), n);
+        for &x in &[0.1, 0.5, 1.0, 2.0] {
+            assert_almost_eq!(m.cdf(x), n.cdf(2.0 * x), 1e-15);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_accelerate_zero() {
+        try_create(1.5).accelerate(0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_accelerate_underflow() {
+        try_create(1e-300).accelerate(1e-300);
+    }
+
+    #[test]
+    fn test_accelerate_overflow() {
+        assert_eq!(try_create(1e300).accelerate(1e300).rate(), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_compound_gamma_rate() {
+        let n = Exp::compound_gamma_rate(2.5, 4.0).unwrap();
+        assert_eq!(n.shape(), 2.5);
+        assert_eq!(n.scale(), 4.0);
+        assert!(Exp::compound_gamma_rate(0.0, 1.0).is_err());
+        assert!(Exp::compound_gamma_rate(1.0, -1.0).is_err());
+        assert!(Exp::compound_gamma_rate(f64::NAN, 1.0).is_err());
+    }
+
+    #[test]
+    fn test_compound_gamma_rate_hierarchical() {
+        use crate::distribution::Gamma;
+        use ::rand::distributions::Distribution;
+        use ::rand::rngs::StdRng;
+        use ::rand::SeedableRng;
+
+        let (shape, rate) = (2.5, 4.0);
+        let compound = Exp::compound_gamma_rate(shape, rate).unwrap();
+        let mixing = Gamma::new(shape, rate).unwrap();
+        let mut r: StdRng = SeedableRng::seed_from_u64(43);
+        let samples: Vec<f64> = (0..10_000)
+            .map(|_| try_create(mixing.sample(&mut r)).sample(&mut r))
+            .collect();
+        test::check_ks(&samples, |x| compound.cdf(x));
+    }
+
+    #[test]
+    fn test_order_statistic_cdf() {
+        let n = try_create(2.0);
+        for &x in &[0.1, 0.5, 1.0, 2.0] {
+            assert_almost_eq!(n.cdf(x).powi(4), n.order_statistic_cdf(4, 4, x), 1e-14);
+        }
+        test_case(2.0, 0.0, |n: Exp| n.order_statistic_cdf(2, 5, 0.0));
+        test_case(2.0, 1.0, |n: Exp| n.order_statistic_cdf(2, 5, f64::INFINITY));
+    }
+
+    #[test]
+    fn test_order_statistic_cdf_monte_carlo() {
+        use ::rand::distributions::Distribution;
+        use ::rand::rngs::StdRng;
+        use ::rand::SeedableRng;
+
+        let n = try_create(1.0);
+        let mut r: StdRng = SeedableRng::seed_from_u64(7);
+        let trials = 20_000;
+        let thirds: Vec<f64> = (0..trials)
+            .map(|_| {
+                let mut s: Vec<f64> = (0..5).map(|_| n.sample(&mut r)).collect();
+                s.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                s[2]
+            })
+            .collect();
+        for &x in &[0.25, 0.5, 0.75, 1.0, 1.5] {
+            let empirical = thirds.iter().filter(|&&s| s <= x).count() as f64 / trials as f64;
+            assert_almost_eq!(empirical, n.order_statistic_cdf(3, 5, x), 0.01);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_order_statistic_cdf_bad_k() {
+        try_create(1.0).order_statistic_cdf(4, 3, 1.0);
+    }
+
+    #[test]
+    fn test_sample_truncated() {
+        use ::rand::rngs::StdRng;
+        use ::rand::SeedableRng;
+
+        // uses the default binary-search inverse cdf
+        let n = try_create(1.0);
+        let mut r: StdRng = SeedableRng::seed_from_u64(3);
+        for _ in 0..1_000 {
+            let x = n.sample_truncated(&mut r, 0.5, 0.75).unwrap();
+            assert!((0.5..=0.75).contains(&x));
+        }
+    }
+
+    #[test]
+    fn test_conditional_value_at_risk() {
+        // ((1 - α) * ln(1 - α) + α) / (λ * α)
+        let expected = |rate: f64, alpha: f64| ((1.0 - alpha) * (1.0 - alpha).ln() + alpha) / (rate * alpha);
+        for &rate in &[0.5, 1.0, 3.0] {
+            let n = try_create(rate);
+            for &alpha in &[0.05, 0.5, 0.95] {
+                assert_almost_eq!(n.conditional_value_at_risk(alpha), expected(rate, alpha), 1e-6);
+            }
+        }
+    }
 }
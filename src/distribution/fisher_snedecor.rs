@@ -1,4 +1,4 @@
-use crate::distribution::{Continuous, ContinuousCDF};
+use crate::distribution::{ApproxEq, Continuous, ContinuousCDF};
 use crate::function::beta;
 use crate::statistics::*;
 use crate::{Result, StatsError};
@@ -87,6 +87,38 @@ impl FisherSnedecor {
     pub fn freedom_2(&self) -> f64 {
         self.freedom_2
     }
+
+    /// Constructs the fisher-snedecor distribution of the ratio
+    /// `(X1 / d1) / (X2 / d2)` where `X1` and `X2` are independent
+    /// chi-squared variables with `d1` and `d2` degrees of freedom
+    /// respectively.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `d1` or `d2` are `NaN` or infinite, or if
+    /// `d1 <= 0.0` or `d2 <= 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::FisherSnedecor;
+    ///
+    /// let n = FisherSnedecor::from_chi_squareds(2.0, 3.0).unwrap();
+    /// assert_eq!(n, FisherSnedecor::new(2.0, 3.0).unwrap());
+    /// ```
+    pub fn from_chi_squareds(d1: f64, d2: f64) -> Result<FisherSnedecor> {
+        FisherSnedecor::new(d1, d2)
+    }
+}
+
+impl ApproxEq for FisherSnedecor {
+    fn approx_eq(&self, other: &FisherSnedecor, tol: f64) -> bool {
+        super::internal::params_approx_eq(
+            &[self.freedom_1, self.freedom_2],
+            &[other.freedom_1, other.freedom_2],
+            tol,
+        )
+    }
 }
 
 impl ::rand::distributions::Distribution<f64> for FisherSnedecor {
@@ -267,6 +299,30 @@ impl Distribution<f64> for FisherSnedecor {
             Some(val)
         }
     }
+    /// Returns the excess kurtosis of the fisher-snedecor distribution
+    ///
+    /// # None
+    ///
+    /// If `d2 <= 8.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 12(d1(5d2 - 22)(d1 + d2 - 2) + (d2 - 4)(d2 - 2)^2) / (d1(d2 - 6)(d2 - 8)(d1 + d2 - 2))
+    /// ```
+    ///
+    /// where `d1` is the first degree of freedom and `d2` is
+    /// the second degree of freedom
+    fn excess_kurtosis(&self) -> Option<f64> {
+        if self.freedom_2 <= 8.0 {
+            None
+        } else {
+            let (d1, d2) = (self.freedom_1, self.freedom_2);
+            let num =
+                d1 * (5.0 * d2 - 22.0) * (d1 + d2 - 2.0) + (d2 - 4.0) * (d2 - 2.0) * (d2 - 2.0);
+            Some(12.0 * num / (d1 * (d2 - 6.0) * (d2 - 8.0) * (d1 + d2 - 2.0)))
+        }
+    }
 }
 
 impl Mode<Option<f64>> for FisherSnedecor {
@@ -476,6 +532,15 @@ mod tests {
         get_value(0.1, 0.1, skewness);
     }
 
+    #[test]
+    fn test_excess_kurtosis() {
+        let excess_kurtosis = |x: FisherSnedecor| x.excess_kurtosis().unwrap();
+        test_almost(1.0, 10.0, 106.0, 1e-12, excess_kurtosis);
+        test_almost(10.0, 10.0, 45.2, 1e-12, excess_kurtosis);
+        test_almost(0.1, 20.0, 210.14917127071822063, 1e-11, excess_kurtosis);
+        assert!(try_create(1.0, 8.0).excess_kurtosis().is_none());
+    }
+
     #[test]
     fn test_mode() {
         let mode = |x: FisherSnedecor| x.mode().unwrap();
@@ -589,4 +654,28 @@ mod tests {
     fn test_continuous() {
         test::check_continuous_distribution(&try_create(10.0, 10.0), 0.0, 10.0);
     }
+
+    #[test]
+    fn test_from_chi_squareds() {
+        use ::rand::distributions::Distribution;
+        use ::rand::rngs::StdRng;
+        use ::rand::SeedableRng;
+        use crate::distribution::ChiSquared;
+
+        let n = FisherSnedecor::from_chi_squareds(4.0, 9.5).unwrap();
+        assert_eq!(n.freedom_1(), 4.0);
+        assert_eq!(n.freedom_2(), 9.5);
+        assert!(FisherSnedecor::from_chi_squareds(f64::INFINITY, 9.5).is_err());
+        assert!(FisherSnedecor::from_chi_squareds(4.0, f64::INFINITY).is_err());
+        assert!(FisherSnedecor::from_chi_squareds(0.0, 9.5).is_err());
+
+        // the scaled ratio of independent chi-squared samples follows n
+        let x1 = ChiSquared::new(4.0).unwrap();
+        let x2 = ChiSquared::new(9.5).unwrap();
+        let mut r: StdRng = SeedableRng::seed_from_u64(5);
+        let samples: Vec<f64> = (0..10_000)
+            .map(|_| (x1.sample(&mut r) / 4.0) / (x2.sample(&mut r) / 9.5))
+            .collect();
+        test::check_ks(&samples, |x| n.cdf(x));
+    }
 }
@@ -1,4 +1,4 @@
-use crate::distribution::{Continuous, ContinuousCDF};
+use crate::distribution::{ApproxEq, Continuous, ContinuousCDF, MomentMatch};
 use crate::function::gamma;
 use crate::statistics::*;
 use crate::{Result, StatsError};
@@ -57,6 +57,72 @@ impl Gamma {
         Ok(Gamma { shape, rate })
     }
 
+    /// Constructs a new gamma distribution with a shape (α) of `shape` and
+    /// a scale (θ) of `scale`, i.e. a rate of `1 / θ`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `scale` is `NaN`, inf or `scale <= 0.0`, or
+    /// under the same conditions as `new`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Gamma;
+    ///
+    /// let n = Gamma::new_with_scale(3.0, 0.5).unwrap();
+    /// assert_eq!(n, Gamma::new(3.0, 2.0).unwrap());
+    /// ```
+    pub fn new_with_scale(shape: f64, scale: f64) -> Result<Gamma> {
+        if !scale.is_finite() || scale <= 0.0 {
+            return Err(StatsError::BadParams);
+        }
+        Gamma::new(shape, scale.recip())
+    }
+
+    /// Constructs a new gamma distribution with a shape (α) of `shape` and
+    /// a rate (β) of `rate`, where the type of `rate` rules out passing a
+    /// scale by mistake. Only available with the `typed_params` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error under the same conditions as `new`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Gamma, Rate};
+    ///
+    /// let n = Gamma::new_typed(3.0, Rate(2.0)).unwrap();
+    /// assert_eq!(n, Gamma::new(3.0, 2.0).unwrap());
+    /// ```
+    #[cfg(feature = "typed_params")]
+    pub fn new_typed(shape: f64, rate: crate::distribution::Rate) -> Result<Gamma> {
+        Gamma::new(shape, rate.0)
+    }
+
+    /// Constructs a new gamma distribution with a shape (α) of `shape` and
+    /// a scale (θ) of `scale`, i.e. a rate of `1 / θ`. Only available with
+    /// the `typed_params` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `scale` is `NaN`, inf or `scale <= 0.0`, or
+    /// under the same conditions as `new`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Gamma, Scale};
+    ///
+    /// let n = Gamma::from_scale(3.0, Scale(0.5)).unwrap();
+    /// assert_eq!(n, Gamma::new(3.0, 2.0).unwrap());
+    /// ```
+    #[cfg(feature = "typed_params")]
+    pub fn from_scale(shape: f64, scale: crate::distribution::Scale) -> Result<Gamma> {
+        Gamma::new_with_scale(shape, scale.0)
+    }
+
     /// Returns the shape (α) of the gamma distribution
     ///
     /// # Examples
@@ -84,6 +150,276 @@ impl Gamma {
     pub fn rate(&self) -> f64 {
         self.rate
     }
+
+    /// Returns the profile log-likelihood of `shape` for the samples in
+    /// `data`, i.e. the total log-likelihood of a gamma distribution with
+    /// the given `shape` and the rate that maximizes the likelihood for that
+    /// shape. Maximizing the profile over `shape` yields the maximum
+    /// likelihood estimate of the shape.
+    ///
+    /// # Panics
+    ///
+    /// If `data` is empty or `shape` is `NaN`, infinite or `shape <= 0.0`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `NEG_INFINITY` if the mean of `data` is not positive
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ ln(f(x_i; α, α / mean(x)))
+    /// ```
+    ///
+    /// where `α` is the shape and `f(x; α, β)` is the pdf of the gamma
+    /// distribution with shape `α` and rate `β`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Gamma;
+    ///
+    /// let data = [0.5, 1.2, 2.0, 3.1, 4.4];
+    /// let profile = |shape| Gamma::profile_loglik_shape(&data, shape);
+    /// assert!(profile(2.0) > profile(0.5));
+    /// assert!(profile(2.0) > profile(20.0));
+    /// ```
+    pub fn profile_loglik_shape(data: &[f64], shape: f64) -> f64 {
+        if data.is_empty() {
+            panic!("data must not be empty");
+        }
+        if shape.is_nan() || shape.is_infinite() || shape <= 0.0 {
+            panic!("shape must be finite and positive");
+        }
+        let mean = data.iter().sum::<f64>() / data.len() as f64;
+        match Gamma::new(shape, shape / mean) {
+            Ok(dist) => data.iter().map(|&x| dist.ln_pdf(x)).sum(),
+            Err(_) => f64::NEG_INFINITY,
+        }
+    }
+
+    /// Transforms the uniform base sample `u` into a sample of the gamma
+    /// distribution and returns it together with its derivative with respect
+    /// to the shape, for use in differentiable (reparameterized) sampling.
+    ///
+    /// # Panics
+    ///
+    /// If `u` is not in `(0, 1)` or the rate is infinite
+    ///
+    /// # Remarks
+    ///
+    /// The gamma distribution has no explicit reparameterization in terms
+    /// of a parameter free base distribution, so this uses implicit
+    /// reparameterization instead: the sample `x = F^-1(u; α, β)` is
+    /// obtained by numerically inverting the cdf, and differentiating
+    /// `F(x; α, β) = u` at constant `u` gives the derivative of the sample
+    /// without differentiating through the inversion. The partial
+    /// derivative of the cdf with respect to the shape is approximated by a
+    /// central difference.
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// dx/dα = -(∂F/∂α)(x; α, β) / f(x; α, β)
+    /// ```
+    ///
+    /// where `α` is the shape, `β` is the rate, `F` is the cdf and `f` is
+    /// the pdf
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Gamma};
+    /// use statrs::prec;
+    ///
+    /// let n = Gamma::new(2.0, 1.0).unwrap();
+    /// let (x, grad) = n.rsample_with_grad(0.5);
+    /// assert!(prec::almost_eq(n.cdf(x), 0.5, 1e-12));
+    /// // increasing the shape moves the median to the right
+    /// assert!(grad > 0.0);
+    /// ```
+    pub fn rsample_with_grad(&self, u: f64) -> (f64, f64) {
+        if !(u > 0.0 && u < 1.0) {
+            panic!("u must be in (0, 1)");
+        }
+        if self.rate.is_infinite() {
+            panic!("rate must be finite");
+        }
+        // work with the standard gamma distribution Y = β * X
+        let y = gamma_lr_inv(self.shape, u);
+        let h = 1e-6 * self.shape;
+        let d_cdf =
+            (gamma::gamma_lr(self.shape + h, y) - gamma::gamma_lr(self.shape - h, y)) / (2.0 * h);
+        let density = Gamma::new(self.shape, 1.0).unwrap().pdf(y);
+        (y / self.rate, -d_cdf / density / self.rate)
+    }
+
+    /// Returns the gradient of the `p`-quantile of the gamma distribution
+    /// with respect to its parameters, as `(∂Q/∂α, ∂Q/∂β)`.
+    ///
+    /// # Panics
+    ///
+    /// If `p` is not in `(0, 1)` or the rate is infinite
+    ///
+    /// # Remarks
+    ///
+    /// The gradient is obtained by implicit differentiation of
+    /// `F(Q; α, β) = p`, see `rsample_with_grad`. It is what the delta method
+    /// needs to propagate the uncertainty of estimated parameters to a
+    /// quantile: if the estimates of `(α, β)` have covariance matrix `Σ`,
+    /// the variance of the estimated quantile is approximately `gᵀ Σ g`
+    /// where `g` is the gradient.
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ∂Q/∂α = -(∂F/∂α)(Q; α, β) / f(Q; α, β)
+    /// ∂Q/∂β = -Q / β
+    /// ```
+    ///
+    /// where `α` is the shape, `β` is the rate, `F` is the cdf and `f` is
+    /// the pdf
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Gamma;
+    ///
+    /// let n = Gamma::new(2.0, 1.0).unwrap();
+    /// let (d_shape, d_rate) = n.quantile_grad(0.9);
+    /// // larger shapes move the quantile up, larger rates move it down
+    /// assert!(d_shape > 0.0);
+    /// assert!(d_rate < 0.0);
+    /// ```
+    pub fn quantile_grad(&self, p: f64) -> (f64, f64) {
+        let (q, d_shape) = self.rsample_with_grad(p);
+        (d_shape, -q / self.rate)
+    }
+
+    /// Approximates the cumulative distribution function at `x` with the
+    /// Wilson–Hilferty transformation, under which the cube root of a gamma
+    /// variate is approximately normally distributed. This is cheaper than
+    /// the exact `cdf`.
+    ///
+    /// # Remarks
+    ///
+    /// The absolute error decreases roughly in proportion to `1 / α`. It is
+    /// about `1e-3` for `α = 5`, a few parts in `10^4` for `α >= 15` and
+    /// about `1e-4` for `α = 50`, while for `α <= 1` it exceeds `5e-3` and the
+    /// exact `cdf` should be preferred.
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Φ(((βx / α)^(1/3) - (1 - 1 / (9α))) / sqrt(1 / (9α)))
+    /// ```
+    ///
+    /// where `α` is the shape, `β` is the rate and `Φ` is the cdf of the
+    /// standard normal distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Gamma};
+    ///
+    /// let n = Gamma::new(25.0, 2.0).unwrap();
+    /// assert!((n.wilson_hilferty_cdf(13.0) - n.cdf(13.0)).abs() < 5e-4);
+    /// ```
+    pub fn wilson_hilferty_cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        let v = 1.0 / (9.0 * self.shape);
+        let z = ((self.rate * x / self.shape).cbrt() - (1.0 - v)) / v.sqrt();
+        super::normal::cdf_unchecked(z, 0.0, 1.0)
+    }
+
+    /// Returns the distribution of `cX`, where `X` follows this
+    /// distribution, i.e. the gamma distribution with the same shape and a
+    /// rate of `β / c`, e.g. for converting the units of a gamma variate
+    ///
+    /// # Panics
+    ///
+    /// If `c` is `NaN`, inf or `c <= 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Gamma;
+    ///
+    /// // waiting times in minutes converted to seconds
+    /// let minutes = Gamma::new(3.0, 0.5).unwrap();
+    /// let seconds = minutes.scale_variable(60.0);
+    /// assert_eq!(seconds, Gamma::new(3.0, 0.5 / 60.0).unwrap());
+    /// ```
+    pub fn scale_variable(&self, c: f64) -> Gamma {
+        if !c.is_finite() || c <= 0.0 {
+            panic!("c must be finite and positive");
+        }
+        Gamma::new(self.shape, self.rate / c).unwrap()
+    }
+}
+
+/// Solves `P(shape, y) = u` for `y`, where `P` is the regularized lower
+/// incomplete gamma function, by Newton's method safeguarded with bisection
+pub(crate) fn gamma_lr_inv(shape: f64, u: f64) -> f64 {
+    let density = Gamma::new(shape, 1.0).unwrap();
+    let mut low = 0.0;
+    let mut high = shape.max(1.0);
+    while gamma::gamma_lr(shape, high) < u {
+        low = high;
+        high *= 2.0;
+    }
+    let mut y = (low + high) / 2.0;
+    for _ in 0..100 {
+        let f = gamma::gamma_lr(shape, y) - u;
+        if f < 0.0 {
+            low = y;
+        } else {
+            high = y;
+        }
+        let newton = y - f / density.pdf(y);
+        let next = if newton > low && newton < high {
+            newton
+        } else {
+            (low + high) / 2.0
+        };
+        if (next - y).abs() <= 1e-15 * y {
+            return next;
+        }
+        y = next;
+    }
+    y
+}
+
+impl MomentMatch for Gamma {
+    /// Constructs the gamma distribution with a mean of `mean` and a
+    /// variance of `variance`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `mean` or `variance` are `NaN` or inf, or if
+    /// `mean <= 0.0` or `variance <= 0.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// α = μ^2 / σ^2, β = μ / σ^2
+    /// ```
+    ///
+    /// where `μ` is the mean and `σ^2` is the variance
+    fn from_mean_variance(mean: f64, variance: f64) -> Result<Gamma> {
+        if !mean.is_finite() || !variance.is_finite() || mean <= 0.0 || variance <= 0.0 {
+            return Err(StatsError::BadParams);
+        }
+        Gamma::new(mean * mean / variance, mean / variance)
+    }
+}
+
+impl ApproxEq for Gamma {
+    fn approx_eq(&self, other: &Gamma, tol: f64) -> bool {
+        super::internal::params_approx_eq(&[self.shape, self.rate], &[other.shape, other.rate], tol)
+    }
 }
 
 impl ::rand::distributions::Distribution<f64> for Gamma {
@@ -147,6 +483,35 @@ impl ContinuousCDF<f64, f64> for Gamma {
             gamma::gamma_ur(self.shape, x * self.rate)
         }
     }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// gamma distribution at `p`, by solving `P(α, y) = p` for `y` with
+    /// Newton's method safeguarded with bisection
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// P^-1(α, p) / β
+    /// ```
+    ///
+    /// where `α` is the shape, `β` is the rate and `P^-1` is the inverse of
+    /// the regularized lower incomplete gamma function in its second argument
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            panic!("p must be in [0, 1]");
+        }
+        if p == 0.0 || self.rate.is_infinite() {
+            0.0
+        } else if p == 1.0 || self.shape.is_infinite() {
+            f64::INFINITY
+        } else {
+            gamma_lr_inv(self.shape, p) / self.rate
+        }
+    }
 }
 
 impl Min<f64> for Gamma {
@@ -232,6 +597,18 @@ impl Distribution<f64> for Gamma {
     fn skewness(&self) -> Option<f64> {
         Some(2.0 / self.shape.sqrt())
     }
+    /// Returns the excess kurtosis of the gamma distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 6 / α
+    /// ```
+    ///
+    /// where `α` is the shape
+    fn excess_kurtosis(&self) -> Option<f64> {
+        Some(6.0 / self.shape)
+    }
 }
 
 impl Mode<Option<f64>> for Gamma {
@@ -435,6 +812,24 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cumulants() {
+        // κ_n = α (n - 1)! / β^n
+        let params = [(1.0, 1.0), (3.0, 2.0), (0.5, 0.25), (10.0, 7.0)];
+        for &(a, b) in params.iter() {
+            let k = try_create((a, b)).cumulants().unwrap();
+            let expected = [
+                a / b,
+                a / b.powi(2),
+                2.0 * a / b.powi(3),
+                6.0 * a / b.powi(4),
+            ];
+            for (&x, &e) in k.iter().zip(expected.iter()) {
+                assert_almost_eq!(x, e, 1e-14 * e);
+            }
+        }
+    }
+
     #[test]
     fn test_skewness() {
         let f = |x: Gamma| x.skewness().unwrap();
@@ -450,6 +845,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_excess_kurtosis() {
+        let f = |x: Gamma| x.excess_kurtosis().unwrap();
+        test_case((1.0, 0.1), 6.0, f);
+        test_case((3.0, 1.0), 2.0, f);
+        test_case((12.0, 10.0), 0.5, f);
+    }
+
+    #[test]
+    fn test_symmetry_center() {
+        for &arg in [(1.0, 0.1), (10.0, 1.0), (100.0, 3.0)].iter() {
+            let n = try_create(arg);
+            assert_eq!(n.symmetry_center(), None);
+            assert!(!n.is_symmetric());
+        }
+    }
+
     #[test]
     fn test_mode() {
         let f = |x: Gamma| x.mode().unwrap();
@@ -463,6 +875,81 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_mode_density() {
+        for &arg in [(1.0, 0.1), (2.0, 1.0), (10.0, 10.0), (10.0, 1.0)].iter() {
+            let n = try_create(arg);
+            assert_eq!(n.mode_density(), Some(n.pdf(n.mode().unwrap())));
+        }
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        let n = try_create((3.0, 1.0));
+        assert!(n.approx_eq(&Gamma::new_with_scale(3.0, 1.0).unwrap(), 0.0));
+        let m = Gamma::new_with_scale(3.0, 3.0).unwrap();
+        assert!(n.approx_eq(&Gamma::new(3.0, m.rate() * 3.0).unwrap(), 1e-15));
+        assert!(n.approx_eq(&try_create((3.0 + 1e-10, 1.0)), 1e-9));
+        assert!(!n.approx_eq(&try_create((3.0, 1.5)), 1e-9));
+        assert!(!n.approx_eq(&try_create((3.5, 1.0)), 0.1));
+        let inf = try_create((3.0, f64::INFINITY));
+        assert!(inf.approx_eq(&inf, 1e-9));
+        assert!(!inf.approx_eq(&n, 1e-9));
+    }
+
+    #[test]
+    fn test_cdf_numeric() {
+        let params = [(1.0, 1.0), (3.0, 2.0), (7.5, 0.5), (20.0, 4.0)];
+        for &(a, b) in params.iter() {
+            let n = try_create((a, b));
+            let mut prev = 0.0;
+            for i in 0..=60 {
+                let x = i as f64 * 0.5;
+                let p = n.cdf_numeric(x);
+                assert_almost_eq!(p, n.cdf(x), 1e-8);
+                // monotone up to the quadrature tolerance
+                assert!(p >= prev - 1e-12);
+                prev = p;
+            }
+            assert_eq!(n.cdf_numeric(-1.0), 0.0);
+            assert_almost_eq!(n.cdf_numeric(f64::INFINITY), 1.0, 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_cdf_numeric_concentrated() {
+        let n = try_create((1e4, 1e4));
+        for &x in &[0.97, 0.99, 1.0, 1.01, 1.03, 2.0] {
+            assert_almost_eq!(n.cdf_numeric(x), n.cdf(x), 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_cdf_numeric_small_shape() {
+        // the density is unbounded at zero
+        let n = try_create((0.1, 1.0));
+        for &x in &[1e-5, 0.1, 1.0, 5.0] {
+            assert_almost_eq!(n.cdf_numeric(x), n.cdf(x), 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_mode_numeric() {
+        let params = [
+            (2.0, 1.0),
+            (3.0, 2.0),
+            (10.0, 10.0),
+            (10.0, 1.0),
+            (1.5, 0.1),
+        ];
+        for &(shape, rate) in params.iter() {
+            let n = try_create((shape, rate));
+            let mode = n.mode().unwrap();
+            let found = n.mode_numeric(0.0, mode + 20.0 * n.std_dev().unwrap());
+            assert_almost_eq!(found, (shape - 1.0) / rate, 1e-6 * mode.max(1.0));
+        }
+    }
+
     #[test]
     fn test_min_max() {
         let f = |x: Gamma| x.min();
@@ -588,9 +1075,317 @@ mod tests {
         test_case((1.0, 0.1), 1.0, |x| x.sf(0.0));
     }
 
+    #[test]
+    fn test_from_mean_variance() {
+        for &(mean, variance) in [(1.0, 1.0), (6.0, 12.0), (0.1, 5.0), (30.0, 0.2)].iter() {
+            let n = Gamma::from_mean_variance(mean, variance).unwrap();
+            assert_relative_eq!(n.mean().unwrap(), mean, max_relative = 1e-14);
+            assert_relative_eq!(n.variance().unwrap(), variance, max_relative = 1e-14);
+        }
+        assert!(Gamma::from_mean_variance(0.0, 1.0).is_err());
+        assert!(Gamma::from_mean_variance(-1.0, 1.0).is_err());
+        assert!(Gamma::from_mean_variance(1.0, 0.0).is_err());
+        assert!(Gamma::from_mean_variance(f64::NAN, 1.0).is_err());
+        assert!(Gamma::from_mean_variance(1.0, f64::INFINITY).is_err());
+    }
+
     #[test]
     fn test_continuous() {
         test::check_continuous_distribution(&try_create((1.0, 0.5)), 0.0, 20.0);
         test::check_continuous_distribution(&try_create((9.0, 2.0)), 0.0, 20.0);
     }
+
+    #[test]
+    fn test_rsample_with_grad() {
+        for &arg in [(0.5, 1.0), (1.0, 2.0), (2.5, 0.5), (10.0, 3.0)].iter() {
+            let n = try_create(arg);
+            for &u in [1e-6, 0.01, 0.3, 0.5, 0.9, 0.999].iter() {
+                let (x, grad) = n.rsample_with_grad(u);
+                assert_relative_eq!(n.cdf(x), u, max_relative = 1e-12);
+                // finite differences of the quantile with respect to the shape
+                let h = 1e-5 * arg.0;
+                let (x_up, _) = try_create((arg.0 + h, arg.1)).rsample_with_grad(u);
+                let (x_down, _) = try_create((arg.0 - h, arg.1)).rsample_with_grad(u);
+                let expected = (x_up - x_down) / (2.0 * h);
+                assert_relative_eq!(grad, expected, max_relative = 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rsample_with_grad_rate_scaling() {
+        let (x, grad) = try_create((3.0, 1.0)).rsample_with_grad(0.7);
+        let (x_scaled, grad_scaled) = try_create((3.0, 4.0)).rsample_with_grad(0.7);
+        assert_relative_eq!(x_scaled, x / 4.0, max_relative = 1e-14);
+        assert_relative_eq!(grad_scaled, grad / 4.0, max_relative = 1e-14);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rsample_with_grad_bad_u() {
+        try_create((3.0, 1.0)).rsample_with_grad(1.0);
+    }
+
+    #[test]
+    fn test_clamp_to_support() {
+        let n = try_create((2.0, 1.0));
+        assert_eq!(n.clamp_to_support(-1e-12), 0.0);
+        assert_eq!(n.clamp_to_support(-5.0), 0.0);
+        assert_eq!(n.clamp_to_support(3.5), 3.5);
+        assert_eq!(n.clamp_to_support(f64::INFINITY), f64::INFINITY);
+        assert!(n.clamp_to_support(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_invariants() {
+        for &arg in [(0.5, 1.0), (1.0, 0.5), (9.0, 2.0), (100.0, 10.0)].iter() {
+            let n = try_create(arg);
+            test::check_cdf_limits(&n);
+            test::check_cdf_monotone(&n, 0.0, 50.0, 5000);
+            test::check_pdf_nonnegative(&n, 0.0, 50.0, 5000);
+        }
+    }
+
+    #[test]
+    fn test_inverse_cdf_round_trip() {
+        let probs = [1e-4, 0.01, 0.1, 0.25, 0.5, 0.75, 0.9, 0.99, 0.9999];
+        test::check_inverse_cdf(&try_create((1.0, 0.5)), &probs, 1e-9);
+        test::check_inverse_cdf(&try_create((9.0, 2.0)), &probs, 1e-9);
+    }
+
+    #[test]
+    fn test_quantile_residual() {
+        use crate::distribution::Normal;
+        use ::rand::distributions::Distribution;
+        use ::rand::rngs::StdRng;
+        use ::rand::SeedableRng;
+
+        let n = try_create((2.5, 1.5));
+        let std_normal = Normal::new(0.0, 1.0).unwrap();
+        let mut r: StdRng = SeedableRng::seed_from_u64(13);
+        let residuals: Vec<f64> = (0..10_000)
+            .map(|_| {
+                let x = n.sample(&mut r);
+                n.quantile_residual(x, &mut r)
+            })
+            .collect();
+        test::check_ks(&residuals, |x| std_normal.cdf(x));
+    }
+
+    #[test]
+    fn test_sample_stratified() {
+        use ::rand::rngs::StdRng;
+        use ::rand::SeedableRng;
+
+        let n = try_create((2.5, 1.5));
+        let mut r: StdRng = SeedableRng::seed_from_u64(17);
+        let samples = n.sample_stratified(&mut r, 50);
+        assert_eq!(samples.len(), 50);
+        for (i, &x) in samples.iter().enumerate() {
+            assert!(n.inverse_cdf(i as f64 / 50.0) <= x);
+            assert!(x <= n.inverse_cdf((i + 1) as f64 / 50.0));
+        }
+        let mean = samples.iter().sum::<f64>() / samples.len() as f64;
+        assert_almost_eq!(mean, 2.5 / 1.5, 0.05);
+    }
+
+    #[test]
+    fn test_quantile_grad() {
+        let quantile = |shape: f64, rate: f64, p: f64| try_create((shape, rate)).inverse_cdf(p);
+        for &(shape, rate) in [(0.5, 1.0), (2.0, 1.0), (3.0, 0.5), (10.0, 4.0)].iter() {
+            for &p in [0.01, 0.25, 0.5, 0.9, 0.999].iter() {
+                let (d_shape, d_rate) = try_create((shape, rate)).quantile_grad(p);
+                let h = 1e-5 * shape;
+                let expected_shape =
+                    (quantile(shape + h, rate, p) - quantile(shape - h, rate, p)) / (2.0 * h);
+                let h = 1e-5 * rate;
+                let expected_rate =
+                    (quantile(shape, rate + h, p) - quantile(shape, rate - h, p)) / (2.0 * h);
+                let tol = expected_shape.abs().max(1e-3);
+                assert_almost_eq!(d_shape, expected_shape, 1e-5 * tol);
+                let tol = expected_rate.abs().max(1e-3);
+                assert_almost_eq!(d_rate, expected_rate, 1e-7 * tol);
+            }
+        }
+    }
+
+    #[test]
+    fn test_quantile_grad_delta_method() {
+        // the gradient predicts the change of the quantile for small
+        // parameter changes
+        let n = try_create((3.0, 2.0));
+        let (d_shape, d_rate) = n.quantile_grad(0.95);
+        let (ds, dr) = (1e-3, -2e-3);
+        let predicted = n.inverse_cdf(0.95) + d_shape * ds + d_rate * dr;
+        let actual = try_create((3.0 + ds, 2.0 + dr)).inverse_cdf(0.95);
+        assert_almost_eq!(predicted, actual, 1e-5);
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        for &(shape, rate) in [(0.5, 1.0), (2.0, 1.0), (3.0, 0.5), (100.0, 4.0)].iter() {
+            let n = try_create((shape, rate));
+            for &p in [1e-6, 0.01, 0.25, 0.5, 0.9, 0.999].iter() {
+                assert_almost_eq!(n.cdf(n.inverse_cdf(p)), p, 1e-12 * p.max(1e-3));
+            }
+            assert_eq!(n.inverse_cdf(0.0), 0.0);
+            assert_eq!(n.inverse_cdf(1.0), f64::INFINITY);
+        }
+        // the quantiles agree with the reparameterized samples
+        let n = try_create((2.5, 3.0));
+        assert_eq!(n.inverse_cdf(0.3), n.rsample_with_grad(0.3).0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_inverse_cdf_bad_p() {
+        try_create((2.0, 1.0)).inverse_cdf(1.5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_quantile_grad_bad_p() {
+        try_create((2.0, 1.0)).quantile_grad(1.0);
+    }
+
+    #[test]
+    fn test_scale_variable() {
+        let n = try_create((3.0, 2.0));
+        for &c in [0.1, 1.0, 2.5, 60.0].iter() {
+            let m = n.scale_variable(c);
+            assert_eq!(m.shape(), n.shape());
+            assert_almost_eq!(m.mean().unwrap(), c * n.mean().unwrap(), 1e-14);
+            assert_almost_eq!(m.variance().unwrap(), c * c * n.variance().unwrap(), 1e-12);
+            // the density transforms with the jacobian 1 / c
+            for &x in [0.1, 0.5, 1.0, 2.0, 5.0].iter() {
+                assert_almost_eq!(m.pdf(c * x), n.pdf(x) / c, 1e-14);
+                assert_almost_eq!(m.cdf(c * x), n.cdf(x), 1e-14);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_scale_variable_zero() {
+        try_create((3.0, 2.0)).scale_variable(0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_scale_variable_infinite() {
+        try_create((3.0, 2.0)).scale_variable(f64::INFINITY);
+    }
+
+    #[test]
+    fn test_wilson_hilferty_cdf() {
+        // the approximation error shrinks roughly like 1 / shape
+        let cases = [(5.0, 1.0, 1.5e-3), (25.0, 0.5, 3e-4), (50.0, 3.0, 1.5e-4)];
+        for &(shape, rate, tol) in cases.iter() {
+            let n = try_create((shape, rate));
+            for i in 1..80 {
+                let x = shape / rate * i as f64 / 20.0;
+                assert_almost_eq!(n.wilson_hilferty_cdf(x), n.cdf(x), tol);
+            }
+        }
+        let n = try_create((2.0, 1.0));
+        assert_eq!(n.wilson_hilferty_cdf(0.0), 0.0);
+        assert_eq!(n.wilson_hilferty_cdf(-1.0), 0.0);
+        assert_eq!(n.wilson_hilferty_cdf(f64::INFINITY), 1.0);
+    }
+
+    #[test]
+    fn test_sample_into() {
+        use ::rand::rngs::StdRng;
+        use ::rand::SeedableRng;
+
+        let n = try_create((2.5, 1.5));
+        let mut r: StdRng = SeedableRng::seed_from_u64(19);
+        let mut out = vec![f64::NAN; 20_000];
+        n.sample_into(&mut r, &mut out);
+        assert!(out.iter().all(|&x| x > 0.0 && x.is_finite()));
+        let mean = out.iter().sum::<f64>() / out.len() as f64;
+        // within about five standard errors of the analytic mean
+        let se = (n.variance().unwrap() / out.len() as f64).sqrt();
+        assert!((mean - n.mean().unwrap()).abs() < 5.0 * se);
+
+        // only the given slice is filled
+        let mut out = [f64::NAN; 8];
+        n.sample_into(&mut r, &mut out[2..5]);
+        assert!(out[..2].iter().chain(out[5..].iter()).all(|x| x.is_nan()));
+        assert!(out[2..5].iter().all(|&x| x > 0.0));
+        n.sample_into(&mut r, &mut []);
+    }
+
+    #[test]
+    fn test_sample_seeded() {
+        let n = try_create((2.5, 1.5));
+        let a = n.sample_seeded(7, 100);
+        assert_eq!(a.len(), 100);
+        assert_eq!(a, n.sample_seeded(7, 100));
+        assert_ne!(a, n.sample_seeded(8, 100));
+        // a longer draw with the same seed extends the shorter one
+        assert_eq!(&n.sample_seeded(7, 200)[..100], &a[..]);
+        assert!(n.sample_seeded(7, 0).is_empty());
+    }
+
+    #[test]
+    fn test_profile_loglik_shape() {
+        use crate::function::gamma::digamma;
+        use ::rand::distributions::Distribution;
+        use ::rand::rngs::StdRng;
+        use ::rand::SeedableRng;
+
+        let mut r: StdRng = SeedableRng::seed_from_u64(5);
+        let data: Vec<f64> = (0..1_000)
+            .map(|_| try_create((2.5, 1.5)).sample(&mut r))
+            .collect();
+
+        // the maximum likelihood shape solves ln(α) - ψ(α) = ln(mean) - mean(ln(x))
+        let n = data.len() as f64;
+        let s = (data.iter().sum::<f64>() / n).ln() - data.iter().map(|x| x.ln()).sum::<f64>() / n;
+        let (mut lo, mut hi): (f64, f64) = (1e-3, 1e3);
+        for _ in 0..200 {
+            let mid = (lo + hi) / 2.0;
+            if mid.ln() - digamma(mid) > s {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        let mle = (lo + hi) / 2.0;
+
+        // golden-section search for the maximizer of the profile
+        let profile = |shape| Gamma::profile_loglik_shape(&data, shape);
+        let ratio = (5f64.sqrt() - 1.0) / 2.0;
+        let (mut a, mut b) = (0.1, 10.0);
+        while b - a > 1e-9 {
+            let c = b - ratio * (b - a);
+            let d = a + ratio * (b - a);
+            if profile(c) > profile(d) {
+                b = d;
+            } else {
+                a = c;
+            }
+        }
+        assert_almost_eq!((a + b) / 2.0, mle, 1e-5);
+        assert!(profile(mle) > profile(mle * 0.99));
+        assert!(profile(mle) > profile(mle * 1.01));
+    }
+
+    #[test]
+    fn test_profile_loglik_shape_matches_ln_pdf() {
+        let data = [0.5, 1.2, 2.0, 3.1, 4.4];
+        let mean = data.iter().sum::<f64>() / data.len() as f64;
+        let dist = try_create((3.0, 3.0 / mean));
+        let expected: f64 = data.iter().map(|&x| dist.ln_pdf(x)).sum();
+        assert_eq!(Gamma::profile_loglik_shape(&data, 3.0), expected);
+        let negative_mean = Gamma::profile_loglik_shape(&[-1.0, 0.5], 3.0);
+        assert_eq!(negative_mean, f64::NEG_INFINITY);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_profile_loglik_shape_empty() {
+        Gamma::profile_loglik_shape(&[], 1.0);
+    }
 }
@@ -1,4 +1,4 @@
-use crate::distribution::{Discrete, DiscreteCDF};
+use crate::distribution::{ApproxEq, Discrete, DiscreteCDF};
 use crate::statistics::*;
 use crate::{Result, StatsError};
 use rand::distributions::OpenClosed01;
@@ -9,6 +9,11 @@ use std::{f64, u64};
 /// [Geometric](https://en.wikipedia.org/wiki/Geometric_distribution)
 /// distribution
 ///
+/// The distribution counts the number of Bernoulli trials up to and including
+/// the first success, so its support starts at `1`. The number of failures
+/// before the first success (support starting at `0`) is modeled by a
+/// [`NegativeBinomial`](./struct.NegativeBinomial.html) with `r = 1`.
+///
 /// # Examples
 ///
 /// ```
@@ -68,6 +73,12 @@ impl Geometric {
     }
 }
 
+impl ApproxEq for Geometric {
+    fn approx_eq(&self, other: &Geometric, tol: f64) -> bool {
+        super::internal::params_approx_eq(&[self.p], &[other.p], tol)
+    }
+}
+
 impl ::rand::distributions::Distribution<f64> for Geometric {
     fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
         if ulps_eq!(self.p, 1.0) {
@@ -159,7 +170,7 @@ impl Distribution<f64> for Geometric {
     fn mean(&self) -> Option<f64> {
         Some(1.0 / self.p)
     }
-    /// Returns the standard deviation of the geometric distribution
+    /// Returns the variance of the geometric distribution
     ///
     /// # Formula
     ///
@@ -193,6 +204,16 @@ impl Distribution<f64> for Geometric {
         };
         Some((2.0 - self.p) / (1.0 - self.p).sqrt())
     }
+    /// Returns the excess kurtosis of the geometric distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 6 + p^2 / (1 - p)
+    /// ```
+    fn excess_kurtosis(&self) -> Option<f64> {
+        Some(6.0 + self.p * self.p / (1.0 - self.p))
+    }
 }
 
 impl Mode<Option<u64>> for Geometric {
@@ -358,6 +379,14 @@ mod tests {
         test_case(1.0, f64::INFINITY, skewness);
     }
 
+    #[test]
+    fn test_excess_kurtosis() {
+        let excess_kurtosis = |x: Geometric| x.excess_kurtosis().unwrap();
+        test_almost(0.3, 6.1285714285714285599, 1e-14, excess_kurtosis);
+        test_case(0.5, 6.5, excess_kurtosis);
+        test_case(1.0, f64::INFINITY, excess_kurtosis);
+    }
+
     #[test]
     fn test_median() {
         let median = |x: Geometric| x.median();
@@ -506,6 +535,15 @@ mod tests {
         test_case(0.3, 0.0, cdf(0));
     }
 
+    #[test]
+    fn test_quantile_residual_below_support() {
+        let mut r = ::rand::thread_rng();
+        let n = try_create(0.3);
+        assert_eq!(n.quantile_residual(0, &mut r), f64::NEG_INFINITY);
+        let r1 = n.quantile_residual(1, &mut r);
+        assert!(r1.is_finite() && r1 <= -0.5244005127080407);
+    }
+
     #[test]
     fn test_sf_lower_bound() {
         let sf = |arg: u64| move |x: Geometric| x.sf(arg);
@@ -518,4 +556,39 @@ mod tests {
         test::check_discrete_distribution(&try_create(0.6), 100);
         test::check_discrete_distribution(&try_create(1.0), 1);
     }
+
+    #[test]
+    fn test_support_starts_at_one() {
+        use crate::distribution::NegativeBinomial;
+
+        // counts trials up to and including the first success, so it is the
+        // failure-counting negative binomial with r = 1 shifted by one
+        for &p in &[0.1, 0.3, 0.75] {
+            let g = try_create(p);
+            let nb = NegativeBinomial::new(1.0, p).unwrap();
+            assert_eq!(g.min(), 1);
+            assert_eq!(g.pmf(0), 0.0);
+            assert_eq!(g.cdf(0), 0.0);
+            assert_eq!(g.mode(), Some(1));
+            assert_almost_eq!(g.mean().unwrap(), nb.mean().unwrap() + 1.0, 1e-14);
+            assert_almost_eq!(g.variance().unwrap(), nb.variance().unwrap(), 1e-14);
+            for x in 0..10 {
+                assert_almost_eq!(g.pmf(x + 1), nb.pmf(x), 1e-14);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sample_support() {
+        use ::rand::distributions::Distribution;
+        use ::rand::rngs::StdRng;
+        use ::rand::SeedableRng;
+
+        let n = try_create(0.25);
+        let mut r: StdRng = SeedableRng::seed_from_u64(1);
+        let samples: Vec<f64> = (0..10_000).map(|_| n.sample(&mut r)).collect();
+        assert!(samples.iter().all(|&x| x >= 1.0));
+        let mean = samples.iter().sum::<f64>() / samples.len() as f64;
+        assert_almost_eq!(mean, 4.0, 0.15);
+    }
 }
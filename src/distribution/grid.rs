@@ -0,0 +1,482 @@
+use crate::distribution::{Continuous, ContinuousCDF};
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// Implements a continuous distribution given by a table of density values,
+/// e.g. an empirical or externally computed density. The density is
+/// interpolated linearly between the grid points and is zero outside of
+/// them.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Continuous, ContinuousCDF, GridDistribution};
+///
+/// // a triangular density on [0, 2] with its peak at 1
+/// let n = GridDistribution::new(&[0.0, 1.0, 2.0], &[0.0, 1.0, 0.0]).unwrap();
+/// assert_eq!(n.pdf(0.5), 0.5);
+/// assert_eq!(n.cdf(1.0), 0.5);
+/// assert_eq!(n.inverse_cdf(0.125), 0.5);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct GridDistribution {
+    xs: Vec<f64>,
+    densities: Vec<f64>,
+    cdf: Vec<f64>,
+}
+
+impl GridDistribution {
+    /// Constructs a new grid distribution from the grid points `xs` and the
+    /// density values `densities` at those points. The densities are
+    /// normalized so that the piecewise linear density integrates to one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `xs` and `densities` have different lengths, if
+    /// there are fewer than two grid points, if `xs` is not strictly
+    /// increasing, if any value is `NaN` or infinite, if any density is
+    /// negative, or if all densities are zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::GridDistribution;
+    ///
+    /// let mut result = GridDistribution::new(&[0.0, 1.0, 2.0], &[1.0, 2.0, 1.0]);
+    /// assert!(result.is_ok());
+    ///
+    /// result = GridDistribution::new(&[0.0, 2.0, 1.0], &[1.0, 2.0, 1.0]);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(xs: &[f64], densities: &[f64]) -> Result<GridDistribution> {
+        if xs.len() != densities.len()
+            || xs.len() < 2
+            || xs.iter().any(|x| !x.is_finite())
+            || xs.windows(2).any(|w| w[0] >= w[1])
+            || densities.iter().any(|d| !d.is_finite() || *d < 0.0)
+        {
+            return Err(StatsError::BadParams);
+        }
+        // cumulative trapezoidal integral of the unnormalized density
+        let mut cdf = Vec::with_capacity(xs.len());
+        cdf.push(0.0);
+        for i in 1..xs.len() {
+            let area = (xs[i] - xs[i - 1]) * (densities[i] + densities[i - 1]) / 2.0;
+            cdf.push(cdf[i - 1] + area);
+        }
+        let total = cdf[cdf.len() - 1];
+        if total <= 0.0 || !total.is_finite() {
+            return Err(StatsError::BadParams);
+        }
+        Ok(GridDistribution {
+            xs: xs.to_vec(),
+            densities: densities.iter().map(|d| d / total).collect(),
+            cdf: cdf.iter().map(|c| c / total).collect(),
+        })
+    }
+
+    /// Returns the grid points of the distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::GridDistribution;
+    ///
+    /// let n = GridDistribution::new(&[0.0, 1.0, 2.0], &[1.0, 2.0, 1.0]).unwrap();
+    /// assert_eq!(n.xs(), &[0.0, 1.0, 2.0]);
+    /// ```
+    pub fn xs(&self) -> &[f64] {
+        &self.xs
+    }
+
+    /// Returns the normalized density values at the grid points
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::GridDistribution;
+    ///
+    /// let n = GridDistribution::new(&[0.0, 1.0, 2.0], &[1.0, 2.0, 1.0]).unwrap();
+    /// assert_eq!(n.densities(), &[1.0 / 3.0, 2.0 / 3.0, 1.0 / 3.0]);
+    /// ```
+    pub fn densities(&self) -> &[f64] {
+        &self.densities
+    }
+
+    // index of the segment [xs[i], xs[i + 1]] containing x, which must lie
+    // in [min, max]
+    fn segment(&self, x: f64) -> usize {
+        let i = self.xs.partition_point(|&g| g <= x);
+        i.max(1).min(self.xs.len() - 1) - 1
+    }
+
+    // probability mass of the segment i between xs[i] and xs[i] + t
+    fn partial_mass(&self, i: usize, t: f64) -> f64 {
+        let width = self.xs[i + 1] - self.xs[i];
+        let (d0, d1) = (self.densities[i], self.densities[i + 1]);
+        d0 * t + (d1 - d0) * t * t / (2.0 * width)
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for GridDistribution {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        self.inverse_cdf(rng.gen())
+    }
+}
+
+impl ContinuousCDF<f64, f64> for GridDistribution {
+    /// Calculates the cumulative distribution function for the grid
+    /// distribution at `x`, the exact integral of the piecewise linear
+    /// density
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= self.min() {
+            0.0
+        } else if x >= self.max() {
+            1.0
+        } else {
+            let i = self.segment(x);
+            (self.cdf[i] + self.partial_mass(i, x - self.xs[i])).min(1.0)
+        }
+    }
+
+    /// Calculates the survival function for the grid distribution at `x`
+    fn sf(&self, x: f64) -> f64 {
+        if x <= self.min() {
+            1.0
+        } else if x >= self.max() {
+            0.0
+        } else {
+            let i = self.segment(x);
+            (1.0 - self.cdf[i] - self.partial_mass(i, x - self.xs[i])).max(0.0)
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the grid
+    /// distribution at `p` by solving for the quantile within the grid
+    /// segment that contains it
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            panic!("p must be in [0, 1]");
+        }
+        // first grid point whose cdf reaches p, so that the preceding
+        // segment has positive mass
+        let j = self.cdf.partition_point(|&c| c < p);
+        if j == 0 {
+            return self.min();
+        }
+        if j == self.cdf.len() {
+            return self.max();
+        }
+        let i = j - 1;
+        let width = self.xs[i + 1] - self.xs[i];
+        let (d0, d1) = (self.densities[i], self.densities[i + 1]);
+        let r = p - self.cdf[i];
+        // root of d0 * t + (d1 - d0) * t^2 / (2 * width) = r, in a form that
+        // is stable when the density is (nearly) constant
+        let disc = (d0 * d0 + 2.0 * (d1 - d0) * r / width).max(0.0);
+        let denom = d0 + disc.sqrt();
+        let t = if denom > 0.0 { 2.0 * r / denom } else { 0.0 };
+        (self.xs[i] + t).max(self.xs[i]).min(self.xs[i + 1])
+    }
+}
+
+impl Min<f64> for GridDistribution {
+    /// Returns the minimum value in the domain of the grid distribution
+    /// representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// x_0
+    /// ```
+    ///
+    /// where `x_0` is the first grid point
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::GridDistribution;
+    /// use statrs::statistics::Min;
+    ///
+    /// let n = GridDistribution::new(&[0.5, 1.0, 2.0], &[1.0, 2.0, 1.0]).unwrap();
+    /// assert_eq!(n.min(), 0.5);
+    /// ```
+    fn min(&self) -> f64 {
+        self.xs[0]
+    }
+}
+
+impl Max<f64> for GridDistribution {
+    /// Returns the maximum value in the domain of the grid distribution
+    /// representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// x_n
+    /// ```
+    ///
+    /// where `x_n` is the last grid point
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::GridDistribution;
+    /// use statrs::statistics::Max;
+    ///
+    /// let n = GridDistribution::new(&[0.5, 1.0, 2.0], &[1.0, 2.0, 1.0]).unwrap();
+    /// assert_eq!(n.max(), 2.0);
+    /// ```
+    fn max(&self) -> f64 {
+        self.xs[self.xs.len() - 1]
+    }
+}
+
+impl Distribution<f64> for GridDistribution {
+    /// Returns the mean of the grid distribution, the exact mean of the
+    /// piecewise linear density
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ (x_{i+1} - x_i) / 6 * (f_i * (2x_i + x_{i+1}) + f_{i+1} * (x_i + 2x_{i+1}))
+    /// ```
+    ///
+    /// where `x_i` are the grid points and `f_i` the normalized densities
+    fn mean(&self) -> Option<f64> {
+        let mean = (0..self.xs.len() - 1)
+            .map(|i| {
+                let (x0, x1) = (self.xs[i], self.xs[i + 1]);
+                let (d0, d1) = (self.densities[i], self.densities[i + 1]);
+                (x1 - x0) / 6.0 * (d0 * (2.0 * x0 + x1) + d1 * (x0 + 2.0 * x1))
+            })
+            .sum();
+        Some(mean)
+    }
+
+    /// Returns the variance of the grid distribution, the exact variance of
+    /// the piecewise linear density
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ (x_{i+1} - x_i) / 12 * (f_i * (3y_i^2 + 2y_i y_{i+1} + y_{i+1}^2)
+    ///     + f_{i+1} * (y_i^2 + 2y_i y_{i+1} + 3y_{i+1}^2))
+    /// ```
+    ///
+    /// where `x_i` are the grid points, `y_i = x_i - μ` the grid points
+    /// centered on the mean and `f_i` the normalized densities
+    fn variance(&self) -> Option<f64> {
+        let mean = self.mean()?;
+        let var = (0..self.xs.len() - 1)
+            .map(|i| {
+                let (y0, y1) = (self.xs[i] - mean, self.xs[i + 1] - mean);
+                let (d0, d1) = (self.densities[i], self.densities[i + 1]);
+                (y1 - y0) / 12.0
+                    * (d0 * (3.0 * y0 * y0 + 2.0 * y0 * y1 + y1 * y1)
+                        + d1 * (y0 * y0 + 2.0 * y0 * y1 + 3.0 * y1 * y1))
+            })
+            .sum();
+        Some(var)
+    }
+}
+
+impl Median<f64> for GridDistribution {
+    /// Returns the median of the grid distribution
+    fn median(&self) -> f64 {
+        self.inverse_cdf(0.5)
+    }
+}
+
+impl Mode<Option<f64>> for GridDistribution {
+    /// Returns the mode of the grid distribution, the first grid point with
+    /// the largest density
+    fn mode(&self) -> Option<f64> {
+        let mut mode = 0;
+        for (i, &d) in self.densities.iter().enumerate() {
+            if d > self.densities[mode] {
+                mode = i;
+            }
+        }
+        Some(self.xs[mode])
+    }
+}
+
+impl Continuous<f64, f64> for GridDistribution {
+    /// Calculates the probability density function for the grid
+    /// distribution at `x` by linear interpolation between the grid points
+    ///
+    /// # Remarks
+    ///
+    /// Returns `0.0` if `x` is outside of the grid
+    fn pdf(&self, x: f64) -> f64 {
+        if x.is_nan() || x < self.min() || x > self.max() {
+            return 0.0;
+        }
+        let i = self.segment(x);
+        let t = (x - self.xs[i]) / (self.xs[i + 1] - self.xs[i]);
+        self.densities[i] + t * (self.densities[i + 1] - self.densities[i])
+    }
+
+    /// Calculates the log probability density function for the grid
+    /// distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NEG_INFINITY` if `x` is outside of the grid
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.pdf(x).ln()
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(all(test, feature = "nightly"))]
+mod tests {
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, GridDistribution, Normal};
+    use crate::distribution::internal::*;
+    use ::rand::distributions::Distribution as RandDistribution;
+    use ::rand::rngs::StdRng;
+    use ::rand::SeedableRng;
+
+    fn normal_grid(mean: f64, std_dev: f64, n: usize) -> GridDistribution {
+        let d = Normal::new(mean, std_dev).unwrap();
+        let xs: Vec<f64> = (0..n)
+            .map(|i| mean - 10.0 * std_dev + 20.0 * std_dev * i as f64 / (n - 1) as f64)
+            .collect();
+        let densities: Vec<f64> = xs.iter().map(|&x| d.pdf(x)).collect();
+        GridDistribution::new(&xs, &densities).unwrap()
+    }
+
+    #[test]
+    fn test_create() {
+        let n = GridDistribution::new(&[0.0, 1.0, 3.0], &[2.0, 2.0, 0.0]).unwrap();
+        assert_eq!(n.xs(), &[0.0, 1.0, 3.0]);
+        assert_eq!(n.densities(), &[0.5, 0.5, 0.0]);
+        assert_eq!(n.min(), 0.0);
+        assert_eq!(n.max(), 3.0);
+        assert!(GridDistribution::new(&[0.0, 1.0], &[0.0, 1.0]).is_ok());
+    }
+
+    #[test]
+    fn test_bad_create() {
+        assert!(GridDistribution::new(&[0.0, 1.0], &[1.0]).is_err());
+        assert!(GridDistribution::new(&[0.0], &[1.0]).is_err());
+        assert!(GridDistribution::new(&[], &[]).is_err());
+        assert!(GridDistribution::new(&[0.0, 2.0, 1.0], &[1.0, 1.0, 1.0]).is_err());
+        assert!(GridDistribution::new(&[0.0, 1.0, 1.0], &[1.0, 1.0, 1.0]).is_err());
+        assert!(GridDistribution::new(&[0.0, 1.0], &[1.0, -1.0]).is_err());
+        assert!(GridDistribution::new(&[0.0, 1.0], &[0.0, 0.0]).is_err());
+        assert!(GridDistribution::new(&[0.0, f64::NAN], &[1.0, 1.0]).is_err());
+        assert!(GridDistribution::new(&[0.0, f64::INFINITY], &[1.0, 1.0]).is_err());
+        assert!(GridDistribution::new(&[0.0, 1.0], &[1.0, f64::NAN]).is_err());
+        assert!(GridDistribution::new(&[0.0, 1.0], &[1.0, f64::INFINITY]).is_err());
+    }
+
+    #[test]
+    fn test_triangular() {
+        let n = GridDistribution::new(&[0.0, 1.0, 2.0], &[0.0, 1.0, 0.0]).unwrap();
+        assert_eq!(n.mean().unwrap(), 1.0);
+        assert_almost_eq!(n.variance().unwrap(), 1.0 / 6.0, 1e-15);
+        assert_eq!(n.median(), 1.0);
+        assert_eq!(n.mode(), Some(1.0));
+        assert_eq!(n.pdf(1.5), 0.5);
+        assert_eq!(n.cdf(0.5), 0.125);
+        assert_eq!(n.sf(1.5), 0.125);
+        assert_eq!(n.inverse_cdf(0.875), 1.5);
+    }
+
+    #[test]
+    fn test_uniform() {
+        let n = GridDistribution::new(&[2.0, 3.0, 6.0], &[1.0, 1.0, 1.0]).unwrap();
+        assert_almost_eq!(n.mean().unwrap(), 4.0, 1e-15);
+        assert_almost_eq!(n.variance().unwrap(), 16.0 / 12.0, 1e-14);
+        assert_almost_eq!(n.cdf(5.0), 0.75, 1e-15);
+        assert_almost_eq!(n.inverse_cdf(0.75), 5.0, 1e-15);
+    }
+
+    #[test]
+    fn test_outside_grid() {
+        let n = GridDistribution::new(&[0.0, 1.0, 2.0], &[1.0, 2.0, 1.0]).unwrap();
+        assert_eq!(n.pdf(-1.0), 0.0);
+        assert_eq!(n.pdf(3.0), 0.0);
+        assert_eq!(n.ln_pdf(3.0), f64::NEG_INFINITY);
+        assert_eq!(n.cdf(-1.0), 0.0);
+        assert_eq!(n.cdf(3.0), 1.0);
+        assert_eq!(n.sf(-1.0), 1.0);
+        assert_eq!(n.sf(3.0), 0.0);
+        assert_eq!(n.inverse_cdf(0.0), 0.0);
+        assert_eq!(n.inverse_cdf(1.0), 2.0);
+    }
+
+    #[test]
+    fn test_zero_mass_segments() {
+        let n = GridDistribution::new(&[0.0, 1.0, 2.0, 3.0, 4.0], &[0.0, 0.0, 1.0, 0.0, 0.0]).unwrap();
+        assert_eq!(n.cdf(1.0), 0.0);
+        assert_eq!(n.cdf(3.0), 1.0);
+        assert_eq!(n.inverse_cdf(0.5), 2.0);
+        assert!(n.inverse_cdf(1e-12) > 1.0);
+        assert!(n.inverse_cdf(1.0 - 1e-12) < 3.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_inverse_cdf_bad_p() {
+        let n = GridDistribution::new(&[0.0, 1.0], &[1.0, 1.0]).unwrap();
+        n.inverse_cdf(1.5);
+    }
+
+    #[test]
+    fn test_normal_grid_moments() {
+        let n = normal_grid(3.0, 2.0, 2001);
+        assert_almost_eq!(n.mean().unwrap(), 3.0, 1e-10);
+        assert_almost_eq!(n.variance().unwrap(), 4.0, 1e-3);
+        assert_almost_eq!(n.median(), 3.0, 1e-10);
+        assert_eq!(n.mode(), Some(3.0));
+    }
+
+    #[test]
+    fn test_normal_grid_cdf() {
+        let n = normal_grid(0.0, 1.0, 2001);
+        let d = Normal::new(0.0, 1.0).unwrap();
+        for &x in &[-3.0, -1.5, -0.3, 0.0, 0.7, 2.2] {
+            assert_almost_eq!(n.cdf(x), d.cdf(x), 1e-4);
+            assert_almost_eq!(n.sf(x), d.sf(x), 1e-4);
+            assert_almost_eq!(n.pdf(x), d.pdf(x), 1e-4);
+        }
+        for &p in &[0.01, 0.1, 0.5, 0.9, 0.99] {
+            assert_almost_eq!(n.inverse_cdf(p), d.inverse_cdf(p), 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_inverse_cdf_round_trip() {
+        let n = normal_grid(1.0, 0.5, 501);
+        test::check_inverse_cdf(&n, &[0.001, 0.05, 0.3, 0.5, 0.77, 0.999], 1e-10);
+        let n = GridDistribution::new(&[0.0, 0.5, 2.0, 2.5], &[3.0, 0.2, 1.0, 0.0]).unwrap();
+        test::check_inverse_cdf(&n, &[0.001, 0.05, 0.3, 0.5, 0.77, 0.999], 1e-10);
+    }
+
+    #[test]
+    fn test_continuous() {
+        test::check_continuous_distribution(&normal_grid(0.0, 1.0, 401), -10.0, 10.0);
+        let n = GridDistribution::new(&[0.0, 0.5, 2.0, 2.5], &[3.0, 0.2, 1.0, 0.0]).unwrap();
+        test::check_continuous_distribution(&n, 0.0, 2.5);
+    }
+
+    #[test]
+    fn test_sample() {
+        let n = normal_grid(-2.0, 1.5, 2001);
+        let mut r: StdRng = SeedableRng::seed_from_u64(7);
+        let samples: Vec<f64> = (0..10_000).map(|_| n.sample(&mut r)).collect();
+        test::check_ks(&samples, |x| n.cdf(x));
+        let mean = samples.iter().sum::<f64>() / samples.len() as f64;
+        assert!((mean + 2.0).abs() < 0.1);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::distribution::{Discrete, DiscreteCDF};
+use crate::distribution::{ApproxEq, Discrete, DiscreteCDF};
 use crate::function::factorial;
 use crate::statistics::*;
 use crate::{Result, StatsError};
@@ -110,6 +110,14 @@ impl Hypergeometric {
     }
 }
 
+impl ApproxEq for Hypergeometric {
+    fn approx_eq(&self, other: &Hypergeometric, _tol: f64) -> bool {
+        self.population == other.population
+            && self.successes == other.successes
+            && self.draws == other.draws
+    }
+}
+
 impl ::rand::distributions::Distribution<f64> for Hypergeometric {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
         let mut population = self.population as f64;
@@ -308,6 +316,39 @@ impl Distribution<f64> for Hypergeometric {
             Some(val)
         }
     }
+    /// Returns the excess kurtosis of the hypergeometric distribution
+    ///
+    /// # None
+    ///
+    /// If `N <= 3`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ((N - 1)N^2 * (N(N + 1) - 6K(N - K) - 6n(N - n)) + 6nK(N - K)(N - n)(5N - 6)) /
+    /// (nK(N - K)(N - n)(N - 2)(N - 3))
+    /// ```
+    ///
+    /// where `N` is population, `K` is successes, and `n` is draws
+    fn excess_kurtosis(&self) -> Option<f64> {
+        if self.population <= 3 {
+            None
+        } else {
+            let (population, successes, draws) = self.values_f64();
+            let failures = population - successes;
+            let rest = population - draws;
+            let num = (population - 1.0)
+                * population
+                * population
+                * (population * (population + 1.0)
+                    - 6.0 * successes * failures
+                    - 6.0 * draws * rest)
+                + 6.0 * draws * successes * failures * rest * (5.0 * population - 6.0);
+            let denom =
+                draws * successes * failures * rest * (population - 2.0) * (population - 3.0);
+            Some(num / denom)
+        }
+    }
 }
 
 impl Mode<Option<u64>> for Hypergeometric {
@@ -477,6 +518,15 @@ mod tests {
         get_value(2, 2, 2, skewness);
     }
 
+    #[test]
+    fn test_excess_kurtosis() {
+        let excess_kurtosis = |x: Hypergeometric| x.excess_kurtosis().unwrap();
+        test_almost(10, 1, 1, 5.1111111111111111111, 1e-14, excess_kurtosis);
+        test_almost(10, 5, 3, -0.36734693877551020408, 1e-14, excess_kurtosis);
+        test_almost(50, 20, 10, -0.1316212322695035461, 1e-14, excess_kurtosis);
+        assert!(try_create(3, 2, 2).excess_kurtosis().is_none());
+    }
+
     #[test]
     fn test_mode() {
         let mode = |x: Hypergeometric| x.mode().unwrap();
@@ -12,6 +12,16 @@ pub fn is_valid_multinomial(arr: &[f64], incl_zero: bool) -> bool {
     sum != 0.0
 }
 
+/// Returns true if `a` and `b` have the same length and each pair of
+/// parameters differs by at most `tol`, where equal infinite parameters
+/// are considered equal and `NaN` is never equal
+pub fn params_approx_eq(a: &[f64], b: &[f64], tol: f64) -> bool {
+    a.len() == b.len()
+        && a.iter()
+            .zip(b)
+            .all(|(&x, &y)| x == y || (x - y).abs() <= tol)
+}
+
 #[macro_use]
 #[cfg(all(test, feature = "nightly"))]
 pub mod test {
@@ -144,6 +154,40 @@ pub mod test {
         assert!(sum <= 1.0 + 1e-10);
     }
 
+    /// cdf should be nondecreasing and within `[0, 1]` on `n + 1` evenly
+    /// spaced points from `lo` to `hi`
+    pub fn check_cdf_monotone<D: ContinuousCDF<f64, f64>>(dist: &D, lo: f64, hi: f64, n: usize) {
+        let mut prev = 0.0;
+        for i in 0..=n {
+            let x = lo + (hi - lo) * i as f64 / n as f64;
+            let cdf = dist.cdf(x);
+            assert!(
+                (0.0..=1.0).contains(&cdf),
+                "cdf({}) = {} is not in [0, 1]",
+                x,
+                cdf
+            );
+            assert!(cdf >= prev, "cdf decreases to {} at {}", cdf, x);
+            prev = cdf;
+        }
+    }
+
+    /// pdf should be nonnegative on `n + 1` evenly spaced points from `lo`
+    /// to `hi`
+    pub fn check_pdf_nonnegative<D: Continuous<f64, f64>>(dist: &D, lo: f64, hi: f64, n: usize) {
+        for i in 0..=n {
+            let x = lo + (hi - lo) * i as f64 / n as f64;
+            let density = dist.pdf(x);
+            assert!(density >= 0.0, "pdf({}) = {} is negative", x, density);
+        }
+    }
+
+    /// cdf should be 0 at the minimum and 1 at the maximum of the support
+    pub fn check_cdf_limits<D: ContinuousCDF<f64, f64>>(dist: &D) {
+        assert_almost_eq!(dist.cdf(dist.min()), 0.0, 1e-12);
+        assert_almost_eq!(dist.cdf(dist.max()), 1.0, 1e-12);
+    }
+
     /// Does a series of checks that all continuous distributions must obey.
     /// 99% of the probability mass should be between x_min and x_max.
     pub fn check_continuous_distribution<D: ContinuousCDF<f64, f64> + Continuous<f64, f64>>(
@@ -158,9 +202,64 @@ pub mod test {
         assert_eq!(dist.cdf(f64::NEG_INFINITY), 0.0);
         assert_eq!(dist.cdf(f64::INFINITY), 1.0);
 
+        check_cdf_limits(dist);
+        check_cdf_monotone(dist, x_min, x_max, 1000);
+        check_pdf_nonnegative(dist, x_min, x_max, 1000);
         check_integrate_pdf_is_cdf(dist, x_min, x_max, (x_max - x_min) / 100000.0);
     }
 
+    /// Returns the one-sample Kolmogorov-Smirnov statistic, the largest
+    /// distance between the empirical cdf of `samples` and `cdf`.
+    pub fn ks_statistic<F: Fn(f64) -> f64>(samples: &[f64], cdf: F) -> f64 {
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let n = sorted.len() as f64;
+        sorted.iter().enumerate().fold(0.0, |d: f64, (i, &x)| {
+            let f = cdf(x);
+            d.max(f - i as f64 / n).max((i + 1) as f64 / n - f)
+        })
+    }
+
+    /// Asserts that `samples` pass a Kolmogorov-Smirnov test against `cdf`
+    /// at the 0.1% significance level.
+    pub fn check_ks<F: Fn(f64) -> f64>(samples: &[f64], cdf: F) {
+        let d = ks_statistic(samples, cdf);
+        let critical = 1.95 / (samples.len() as f64).sqrt();
+        assert!(
+            d < critical,
+            "KS statistic {} exceeds critical value {}",
+            d,
+            critical
+        );
+    }
+
+    /// Asserts that `inverse_cdf` inverts `cdf` to within `tol`, checking
+    /// both `cdf(inverse_cdf(p)) ≈ p` for each of `probs` and
+    /// `inverse_cdf(cdf(x)) ≈ x` for the quantiles `x` of `probs`.
+    pub fn check_inverse_cdf<D: ContinuousCDF<f64, f64>>(dist: &D, probs: &[f64], tol: f64) {
+        for &p in probs {
+            let x = dist.inverse_cdf(p);
+            let p_round_trip = dist.cdf(x);
+            assert!(
+                (p_round_trip - p).abs() <= tol,
+                "cdf(inverse_cdf({})) = {} differs from {} by more than {}",
+                p,
+                p_round_trip,
+                p,
+                tol
+            );
+            let x_round_trip = dist.inverse_cdf(dist.cdf(x));
+            assert!(
+                (x_round_trip - x).abs() <= tol,
+                "inverse_cdf(cdf({})) = {} differs from {} by more than {}",
+                x,
+                x_round_trip,
+                x,
+                tol
+            );
+        }
+    }
+
     /// Does a series of checks that all positive discrete distributions must
     /// obey.
     /// 99% of the probability mass should be between 0 and x_max (inclusive).
@@ -1,4 +1,4 @@
-use crate::distribution::{Continuous, ContinuousCDF};
+use crate::distribution::{ApproxEq, Continuous, ContinuousCDF};
 use crate::function::gamma;
 use crate::statistics::*;
 use crate::{Result, StatsError};
@@ -87,6 +87,12 @@ impl InverseGamma {
     }
 }
 
+impl ApproxEq for InverseGamma {
+    fn approx_eq(&self, other: &InverseGamma, tol: f64) -> bool {
+        super::internal::params_approx_eq(&[self.shape, self.rate], &[other.shape, other.rate], tol)
+    }
+}
+
 impl ::rand::distributions::Distribution<f64> for InverseGamma {
     fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
         1.0 / super::gamma::sample_unchecked(r, self.shape, self.rate)
@@ -247,6 +253,26 @@ impl Distribution<f64> for InverseGamma {
             Some(4.0 * (self.shape - 2.0).sqrt() / (self.shape - 3.0))
         }
     }
+    /// Returns the excess kurtosis of the inverse gamma distribution
+    ///
+    /// # None
+    ///
+    /// If `shape <= 4`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 6(5α - 11) / ((α - 3)(α - 4))
+    /// ```
+    ///
+    /// where `α` is the shape
+    fn excess_kurtosis(&self) -> Option<f64> {
+        if self.shape <= 4.0 {
+            None
+        } else {
+            Some(6.0 * (5.0 * self.shape - 11.0) / ((self.shape - 3.0) * (self.shape - 4.0)))
+        }
+    }
 }
 
 impl Mode<Option<f64>> for InverseGamma {
@@ -420,6 +446,14 @@ mod tests {
         get_value(0.1, 0.1, skewness);
     }
 
+    #[test]
+    fn test_excess_kurtosis() {
+        let excess_kurtosis = |x: InverseGamma| x.excess_kurtosis().unwrap();
+        test_case(5.0, 1.0, 42.0, excess_kurtosis);
+        test_almost(10.5, 2.0, 5.1076923076923076923, 1e-14, excess_kurtosis);
+        assert!(try_create(4.0, 1.0).excess_kurtosis().is_none());
+    }
+
     #[test]
     fn test_mode() {
         let mode = |x: InverseGamma| x.mode().unwrap();
@@ -1,4 +1,4 @@
-use crate::distribution::{Continuous, ContinuousCDF};
+use crate::distribution::{ApproxEq, Continuous, ContinuousCDF};
 use crate::statistics::{Distribution, Max, Median, Min, Mode};
 use crate::{Result, StatsError};
 use rand::Rng;
@@ -79,6 +79,16 @@ impl Laplace {
     }
 }
 
+impl ApproxEq for Laplace {
+    fn approx_eq(&self, other: &Laplace, tol: f64) -> bool {
+        super::internal::params_approx_eq(
+            &[self.location, self.scale],
+            &[other.location, other.scale],
+            tol,
+        )
+    }
+}
+
 impl ::rand::distributions::Distribution<f64> for Laplace {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
         let x: f64 = rng.gen_range(-0.5..0.5);
@@ -227,6 +237,29 @@ impl Distribution<f64> for Laplace {
     fn skewness(&self) -> Option<f64> {
         Some(0.)
     }
+    /// Returns the excess kurtosis of the laplace distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 3
+    /// ```
+    fn excess_kurtosis(&self) -> Option<f64> {
+        Some(3.0)
+    }
+    /// Returns the center of symmetry of the laplace distribution, i.e.
+    /// the location
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ
+    /// ```
+    ///
+    /// where `μ` is the location
+    fn symmetry_center(&self) -> Option<f64> {
+        Some(self.location)
+    }
 }
 
 impl Median<f64> for Laplace {
@@ -404,6 +437,22 @@ mod tests {
         test_case(INF, INF, 0.0, skewness);
     }
 
+    #[test]
+    fn test_excess_kurtosis() {
+        let excess_kurtosis = |x: Laplace| x.excess_kurtosis().unwrap();
+        test_case(-6.0, 1.0, 3.0, excess_kurtosis);
+        test_case(5.0, 10.0, 3.0, excess_kurtosis);
+    }
+
+    #[test]
+    fn test_symmetry_center() {
+        let center = |x: Laplace| x.symmetry_center().unwrap();
+        test_case(-6.0, 1.0, -6.0, center);
+        test_case(0.0, 0.1, 0.0, center);
+        test_case(5.0, 10.0, 5.0, center);
+        assert!(try_create(1.0, 1.0).is_symmetric());
+    }
+
     #[test]
     fn test_mode() {
         let mode = |x: Laplace| x.mode().unwrap();
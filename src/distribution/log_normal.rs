@@ -1,4 +1,4 @@
-use crate::distribution::{Continuous, ContinuousCDF};
+use crate::distribution::{ApproxEq, Continuous, ContinuousCDF, MomentMatch};
 use crate::function::erf;
 use crate::statistics::*;
 use crate::{consts, Result, StatsError};
@@ -53,6 +53,83 @@ impl LogNormal {
             Ok(LogNormal { location, scale })
         }
     }
+
+    /// Returns the mean excess of the log-normal distribution over
+    /// `threshold`, i.e. the expected value of `X - t` given `X > t`
+    ///
+    /// # Panics
+    ///
+    /// If `threshold` is `NaN` or `threshold <= 0.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(μ + σ^2 / 2) * Φ((μ + σ^2 - ln(t)) / σ) / Φ((μ - ln(t)) / σ) - t
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale, `t` is the threshold
+    /// and `Φ` is the cdf of the standard normal distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::LogNormal;
+    /// use statrs::statistics::Distribution;
+    /// use statrs::prec;
+    ///
+    /// let n = LogNormal::new(0.0, 1.0).unwrap();
+    /// assert!(prec::almost_eq(n.mean_excess(1e-300), n.mean().unwrap(), 1e-12));
+    /// ```
+    pub fn mean_excess(&self, threshold: f64) -> f64 {
+        if threshold.is_nan() || threshold <= 0.0 {
+            panic!("threshold must be positive");
+        }
+        let mu = self.location;
+        let sigma = self.scale;
+        let z = (mu - threshold.ln()) / sigma;
+        let mean = (mu + sigma * sigma / 2.0).exp();
+        // both cdfs underflow for large thresholds, so take their ratio in
+        // log space
+        let ln_ratio = super::normal::ln_cdf_unchecked(z + sigma, 0.0, 1.0)
+            - super::normal::ln_cdf_unchecked(z, 0.0, 1.0);
+        mean * ln_ratio.exp() - threshold
+    }
+}
+
+impl MomentMatch for LogNormal {
+    /// Constructs the log-normal distribution with a mean of `mean` and a
+    /// variance of `variance`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `mean` or `variance` are `NaN` or inf, or if
+    /// `mean <= 0.0` or `variance <= 0.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// σ^2 = ln(1 + v / m^2), μ = ln(m) - σ^2 / 2
+    /// ```
+    ///
+    /// where `m` is the mean, `v` is the variance, `μ` is the location and
+    /// `σ` is the scale
+    fn from_mean_variance(mean: f64, variance: f64) -> Result<LogNormal> {
+        if !mean.is_finite() || !variance.is_finite() || mean <= 0.0 || variance <= 0.0 {
+            return Err(StatsError::BadParams);
+        }
+        let scale_sq = (variance / (mean * mean)).ln_1p();
+        LogNormal::new(mean.ln() - scale_sq / 2.0, scale_sq.sqrt())
+    }
+}
+
+impl ApproxEq for LogNormal {
+    fn approx_eq(&self, other: &LogNormal, tol: f64) -> bool {
+        super::internal::params_approx_eq(
+            &[self.location, self.scale],
+            &[other.location, other.scale],
+            tol,
+        )
+    }
 }
 
 impl ::rand::distributions::Distribution<f64> for LogNormal {
@@ -194,6 +271,19 @@ impl Distribution<f64> for LogNormal {
         let expsigma2 = (self.scale * self.scale).exp();
         Some((expsigma2 + 2.0) * (expsigma2 - 1.0).sqrt())
     }
+    /// Returns the excess kurtosis of the log-normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(4σ^2) + 2e^(3σ^2) + 3e^(2σ^2) - 6
+    /// ```
+    ///
+    /// where `σ` is the scale
+    fn excess_kurtosis(&self) -> Option<f64> {
+        let s2 = self.scale * self.scale;
+        Some((4.0 * s2).exp() + 2.0 * (3.0 * s2).exp() + 3.0 * (2.0 * s2).exp() - 6.0)
+    }
 }
 
 impl Median<f64> for LogNormal {
@@ -270,7 +360,7 @@ impl Continuous<f64, f64> for LogNormal {
 #[cfg(all(test, feature = "nightly"))]
 mod tests {
     use crate::statistics::*;
-    use crate::distribution::{ContinuousCDF, Continuous, LogNormal};
+    use crate::distribution::{ContinuousCDF, Continuous, LogNormal, MomentMatch};
     use crate::distribution::internal::*;
     use crate::consts::ACC;
 
@@ -440,6 +530,14 @@ mod tests {
         test_almost(5.5, 5.5, 50829064464591483629.132631635472412625371367420496, 1e4, skewness);
     }
 
+    #[test]
+    fn test_excess_kurtosis() {
+        let excess_kurtosis = |x: LogNormal| x.excess_kurtosis().unwrap();
+        test_almost(-1.0, 0.1, 0.16232386217968938674, 1e-13, excess_kurtosis);
+        test_almost(0.0, 0.5, 5.898445673784779013, 1e-13, excess_kurtosis);
+        test_almost(2.5, 1.0, 110.93639217631152524, 1e-12, excess_kurtosis);
+    }
+
     #[test]
     fn test_mode() {
         let mode = |x: LogNormal| x.mode().unwrap();
@@ -650,9 +748,75 @@ mod tests {
         test_case(0.0, 1.0, 1.0, sf(0.0));
     }
 
+    #[test]
+    fn test_from_mean_variance() {
+        for &(mean, variance) in &[(1.0, 1.0), (5.0, 0.5), (0.2, 3.0)] {
+            let n = LogNormal::from_mean_variance(mean, variance).unwrap();
+            assert_almost_eq!(n.mean().unwrap() / mean, 1.0, 1e-14);
+            assert_almost_eq!(n.variance().unwrap() / variance, 1.0, 1e-13);
+        }
+        assert!(LogNormal::from_mean_variance(0.0, 1.0).is_err());
+        assert!(LogNormal::from_mean_variance(-1.0, 1.0).is_err());
+        assert!(LogNormal::from_mean_variance(1.0, 0.0).is_err());
+        assert!(LogNormal::from_mean_variance(1.0, f64::NAN).is_err());
+    }
+
     #[test]
     fn test_continuous() {
         test::check_continuous_distribution(&try_create(0.0, 0.25), 0.0, 10.0);
         test::check_continuous_distribution(&try_create(0.0, 0.5), 0.0, 10.0);
     }
+
+    #[test]
+    fn test_mean_excess() {
+        // integral of the survival function above the threshold by the
+        // midpoint rule
+        let numeric = |n: LogNormal, t: f64| {
+            let steps = 200_000;
+            let step = 100.0 / steps as f64;
+            let tail = (0..steps)
+                .map(|i| n.sf(t + (i as f64 + 0.5) * step) * step)
+                .sum::<f64>();
+            tail / n.sf(t)
+        };
+        for &(location, scale) in &[(0.0, 0.5), (0.5, 0.25), (-0.5, 0.75)] {
+            let n = try_create(location, scale);
+            for &t in &[0.5, 1.0, 2.0, 3.0] {
+                assert_almost_eq!(numeric(n, t), n.mean_excess(t), 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn test_mean_excess_large_threshold() {
+        // both normal cdfs in the formula underflow for these thresholds
+        let cases = [
+            (0.0, 1.0, 1e20, 2.2175137013971726224e18),
+            (1.0, 0.5, 1e30, 3.6854180409889365843e27),
+            (0.0, 2.0, 1e50, 3.5971246935357067904e48),
+            (0.0, 1.0, 1e300, 1.4497409071630089912e297),
+        ];
+        for &(location, scale, t, expected) in &cases {
+            let x = try_create(location, scale).mean_excess(t);
+            assert!(((x - expected) / expected).abs() < 1e-8, "{} != {}", x, expected);
+        }
+        assert_almost_eq!(try_create(0.0, 1.0).mean_excess(100.0), 24.795008407232548086, 1e-9);
+    }
+
+    #[test]
+    fn test_mean_excess_increasing() {
+        let n = try_create(0.0, 1.0);
+        let mut prev = n.mean_excess(0.5);
+        for i in 1..20 {
+            let x = n.mean_excess(0.5 + i as f64);
+            assert!(x > prev);
+            prev = x;
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mean_excess_bad_threshold() {
+        try_create(0.0, 1.0).mean_excess(0.0);
+    }
 }
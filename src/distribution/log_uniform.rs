@@ -0,0 +1,470 @@
+use crate::distribution::{ApproxEq, Continuous, ContinuousCDF};
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::distributions::Uniform as RandUniform;
+use rand::Rng;
+use std::f64;
+
+/// Implements the
+/// [Log-uniform](https://en.wikipedia.org/wiki/Reciprocal_distribution)
+/// (reciprocal) distribution, whose logarithm is uniformly distributed
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{LogUniform, Continuous};
+/// use statrs::statistics::Median;
+/// use statrs::prec;
+///
+/// let n = LogUniform::new(1.0, 100.0).unwrap();
+/// assert!(prec::almost_eq(n.median(), 10.0, 1e-14));
+/// assert!(prec::almost_eq(n.pdf(10.0), 0.1 / 100f64.ln(), 1e-15));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct LogUniform {
+    min: f64,
+    max: f64,
+}
+
+impl LogUniform {
+    /// Constructs a new log-uniform distribution with a min of `min` and a
+    /// max of `max`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `min` or `max` are `NaN` or infinite.
+    /// Returns an error if `min <= 0.0` or `max <= min`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::LogUniform;
+    ///
+    /// let mut result = LogUniform::new(0.01, 1.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = LogUniform::new(0.0, 1.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(min: f64, max: f64) -> Result<LogUniform> {
+        if !min.is_finite() || !max.is_finite() || min <= 0.0 || max <= min {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(LogUniform { min, max })
+        }
+    }
+
+    // the logarithm of the ratio of the bounds, ln(max / min)
+    fn log_ratio(&self) -> f64 {
+        self.max.ln() - self.min.ln()
+    }
+}
+
+impl ApproxEq for LogUniform {
+    fn approx_eq(&self, other: &LogUniform, tol: f64) -> bool {
+        super::internal::params_approx_eq(&[self.min, self.max], &[other.min, other.max], tol)
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for LogUniform {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let d = RandUniform::new_inclusive(self.min.ln(), self.max.ln());
+        rng.sample(d).exp().max(self.min).min(self.max)
+    }
+}
+
+impl ContinuousCDF<f64, f64> for LogUniform {
+    /// Calculates the cumulative distribution function for the log-uniform
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(x / a) / ln(b / a)
+    /// ```
+    ///
+    /// where `a` is the min and `b` is the max
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= self.min {
+            0.0
+        } else if x >= self.max {
+            1.0
+        } else {
+            (x.ln() - self.min.ln()) / self.log_ratio()
+        }
+    }
+
+    /// Calculates the survival function for the log-uniform
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(b / x) / ln(b / a)
+    /// ```
+    ///
+    /// where `a` is the min and `b` is the max
+    fn sf(&self, x: f64) -> f64 {
+        if x <= self.min {
+            1.0
+        } else if x >= self.max {
+            0.0
+        } else {
+            (self.max.ln() - x.ln()) / self.log_ratio()
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// log-uniform distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// a * (b / a)^p
+    /// ```
+    ///
+    /// where `a` is the min and `b` is the max
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            panic!("p must be in [0, 1]");
+        }
+        if p == 1.0 {
+            return self.max;
+        }
+        (self.min.ln() + p * self.log_ratio())
+            .exp()
+            .max(self.min)
+            .min(self.max)
+    }
+}
+
+impl Min<f64> for LogUniform {
+    /// Returns the minimum value in the domain of the log-uniform
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// a
+    /// ```
+    ///
+    /// where `a` is the min
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::LogUniform;
+    /// use statrs::statistics::Min;
+    ///
+    /// let n = LogUniform::new(0.01, 1.0).unwrap();
+    /// assert_eq!(n.min(), 0.01);
+    /// ```
+    fn min(&self) -> f64 {
+        self.min
+    }
+}
+
+impl Max<f64> for LogUniform {
+    /// Returns the maximum value in the domain of the log-uniform
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// b
+    /// ```
+    ///
+    /// where `b` is the max
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::LogUniform;
+    /// use statrs::statistics::Max;
+    ///
+    /// let n = LogUniform::new(0.01, 1.0).unwrap();
+    /// assert_eq!(n.max(), 1.0);
+    /// ```
+    fn max(&self) -> f64 {
+        self.max
+    }
+}
+
+impl Distribution<f64> for LogUniform {
+    /// Returns the mean for the log-uniform distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (b - a) / ln(b / a)
+    /// ```
+    ///
+    /// where `a` is the min and `b` is the max
+    fn mean(&self) -> Option<f64> {
+        Some((self.max - self.min) / self.log_ratio())
+    }
+    /// Returns the variance for the log-uniform distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (b^2 - a^2) / (2 * ln(b / a)) - ((b - a) / ln(b / a))^2
+    /// ```
+    ///
+    /// where `a` is the min and `b` is the max
+    fn variance(&self) -> Option<f64> {
+        let l = self.log_ratio();
+        let mean = (self.max - self.min) / l;
+        Some((self.max - self.min) * (self.max + self.min) / (2.0 * l) - mean * mean)
+    }
+    /// Returns the entropy for the log-uniform distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(ln(b / a)) + (ln(a) + ln(b)) / 2
+    /// ```
+    ///
+    /// where `a` is the min and `b` is the max
+    fn entropy(&self) -> Option<f64> {
+        Some(self.log_ratio().ln() + (self.min.ln() + self.max.ln()) / 2.0)
+    }
+}
+
+impl Median<f64> for LogUniform {
+    /// Returns the median for the log-uniform distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^((ln(a) + ln(b)) / 2)
+    /// ```
+    ///
+    /// where `a` is the min and `b` is the max
+    fn median(&self) -> f64 {
+        ((self.min.ln() + self.max.ln()) / 2.0).exp()
+    }
+}
+
+impl Mode<Option<f64>> for LogUniform {
+    /// Returns the mode for the log-uniform distribution
+    ///
+    /// # Remarks
+    ///
+    /// The density is decreasing, so the mode is the lower bound
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// a
+    /// ```
+    ///
+    /// where `a` is the min
+    fn mode(&self) -> Option<f64> {
+        Some(self.min)
+    }
+}
+
+impl Continuous<f64, f64> for LogUniform {
+    /// Calculates the probability density function for the log-uniform
+    /// distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `0.0` if `x` is not in `[a, b]`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 / (x * ln(b / a))
+    /// ```
+    ///
+    /// where `a` is the min and `b` is the max
+    fn pdf(&self, x: f64) -> f64 {
+        if x < self.min || x > self.max {
+            0.0
+        } else {
+            1.0 / (x * self.log_ratio())
+        }
+    }
+
+    /// Calculates the log probability density function for the log-uniform
+    /// distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NEG_INFINITY` if `x` is not in `[a, b]`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -ln(x) - ln(ln(b / a))
+    /// ```
+    ///
+    /// where `a` is the min and `b` is the max
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x < self.min || x > self.max {
+            f64::NEG_INFINITY
+        } else {
+            -x.ln() - self.log_ratio().ln()
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(all(test, feature = "nightly"))]
+mod tests {
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, LogUniform};
+    use crate::distribution::internal::*;
+    use std::f64::consts;
+
+    fn try_create(min: f64, max: f64) -> LogUniform {
+        let n = LogUniform::new(min, max);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn create_case(min: f64, max: f64) {
+        let n = try_create(min, max);
+        assert_eq!(n.min(), min);
+        assert_eq!(n.max(), max);
+    }
+
+    fn bad_create_case(min: f64, max: f64) {
+        let n = LogUniform::new(min, max);
+        assert!(n.is_err());
+    }
+
+    fn test_almost<F>(min: f64, max: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(LogUniform) -> f64
+    {
+        let x = eval(try_create(min, max));
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        create_case(1e-5, 1.0);
+        create_case(1.0, 1.5);
+        create_case(0.1, 1e6);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(0.0, 1.0);
+        bad_create_case(-1.0, 1.0);
+        bad_create_case(1.0, 1.0);
+        bad_create_case(2.0, 1.0);
+        bad_create_case(f64::NAN, 1.0);
+        bad_create_case(1.0, f64::NAN);
+        bad_create_case(1.0, f64::INFINITY);
+    }
+
+    #[test]
+    fn test_mean() {
+        let mean = |x: LogUniform| x.mean().unwrap();
+        test_almost(1.0, 100.0, 99.0 / 100f64.ln(), 1e-13, mean);
+        test_almost(1.0, consts::E, consts::E - 1.0, 1e-15, mean);
+    }
+
+    #[test]
+    fn test_variance() {
+        let variance = |x: LogUniform| x.variance().unwrap();
+        let e = consts::E;
+        let mean = e - 1.0;
+        test_almost(1.0, e, (e * e - 1.0) / 2.0 - mean * mean, 1e-14, variance);
+    }
+
+    #[test]
+    fn test_entropy() {
+        let entropy = |x: LogUniform| x.entropy().unwrap();
+        test_almost(1.0, consts::E, 0.5, 1e-15, entropy);
+        test_almost(2.0, 8.0, 4f64.ln().ln() + 4f64.ln(), 1e-15, entropy);
+    }
+
+    #[test]
+    fn test_median_mode() {
+        test_almost(2.0, 8.0, 4.0, 1e-15, |x| x.median());
+        assert_eq!(try_create(2.0, 8.0).mode(), Some(2.0));
+        // the product of the bounds overflows
+        let median = try_create(1e200, 1e300).median();
+        assert!(((median - 1e250) / 1e250).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_pdf() {
+        let n = try_create(1.0, 100.0);
+        assert_almost_eq!(n.pdf(1.0), 1.0 / 100f64.ln(), 1e-15);
+        assert_almost_eq!(n.pdf(50.0), 1.0 / (50.0 * 100f64.ln()), 1e-15);
+        assert_almost_eq!(n.ln_pdf(50.0), n.pdf(50.0).ln(), 1e-14);
+        assert_eq!(n.pdf(0.5), 0.0);
+        assert_eq!(n.pdf(101.0), 0.0);
+        assert_eq!(n.ln_pdf(0.5), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_pdf_normalization() {
+        // midpoint rule in log-space, where the integrand x * pdf(x) is flat
+        let n = try_create(1e-3, 10.0);
+        let (lo, hi) = (n.min().ln(), n.max().ln());
+        let steps = 10_000;
+        let h = (hi - lo) / steps as f64;
+        let sum: f64 = (0..steps)
+            .map(|i| {
+                let x = (lo + (i as f64 + 0.5) * h).exp();
+                x * n.pdf(x) * h
+            })
+            .sum();
+        assert_almost_eq!(sum, 1.0, 1e-12);
+    }
+
+    #[test]
+    fn test_cdf_sf() {
+        let n = try_create(2.0, 8.0);
+        assert_eq!(n.cdf(1.0), 0.0);
+        assert_eq!(n.cdf(9.0), 1.0);
+        assert_almost_eq!(n.cdf(4.0), 0.5, 1e-15);
+        assert_almost_eq!(n.sf(4.0), 0.5, 1e-15);
+        assert_almost_eq!(n.cdf(3.0) + n.sf(3.0), 1.0, 1e-15);
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let probs = [0.0, 1e-4, 0.01, 0.1, 0.25, 0.5, 0.75, 0.9, 0.99, 1.0];
+        test::check_inverse_cdf(&try_create(2.0, 8.0), &probs, 1e-12);
+        test::check_inverse_cdf(&try_create(1e-4, 1e4), &probs, 1e-12);
+        assert_eq!(try_create(2.0, 8.0).inverse_cdf(1.0), 8.0);
+        assert_eq!(try_create(2.0, 8.0).inverse_cdf(0.0), 2.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_inverse_cdf_bad_p() {
+        try_create(2.0, 8.0).inverse_cdf(-0.5);
+    }
+
+    #[test]
+    fn test_sample_uniform_in_log_space() {
+        use ::rand::distributions::Distribution;
+        use ::rand::rngs::StdRng;
+        use ::rand::SeedableRng;
+
+        let n = try_create(1e-4, 10.0);
+        let (lo, hi) = (n.min().ln(), n.max().ln());
+        let mut r: StdRng = SeedableRng::seed_from_u64(17);
+        let logs: Vec<f64> = (0..10_000).map(|_| n.sample(&mut r).ln()).collect();
+        assert!(logs.iter().all(|&l| l >= lo && l <= hi));
+        test::check_ks(&logs, |l| ((l - lo) / (hi - lo)).clamp(0.0, 1.0));
+    }
+
+    #[test]
+    fn test_continuous() {
+        test::check_continuous_distribution(&try_create(1.0, 10.0), 1.0, 10.0);
+        test::check_continuous_distribution(&try_create(0.5, 2.0), 0.5, 2.0);
+    }
+}
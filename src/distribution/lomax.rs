@@ -0,0 +1,549 @@
+use crate::distribution::{ApproxEq, Continuous, ContinuousCDF};
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::distributions::OpenClosed01;
+use rand::Rng;
+use std::f64;
+
+/// Implements the [Lomax](https://en.wikipedia.org/wiki/Lomax_distribution)
+/// (Pareto type II) distribution, a Pareto distribution shifted to start
+/// at zero
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Lomax, Continuous};
+/// use statrs::statistics::Distribution;
+/// use statrs::prec;
+///
+/// let l = Lomax::new(3.0, 2.0).unwrap();
+/// assert_eq!(l.mean().unwrap(), 1.0);
+/// assert!(prec::almost_eq(l.pdf(2.0), 0.09375, 1e-15));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Lomax {
+    shape: f64,
+    scale: f64,
+}
+
+impl Lomax {
+    /// Constructs a new Lomax distribution with shape (α) `shape` and
+    /// scale (λ) `scale`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `shape` or `scale` are `NaN` or infinite.
+    /// Returns an error if `shape <= 0.0` or `scale <= 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Lomax;
+    ///
+    /// let mut result = Lomax::new(3.0, 2.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = Lomax::new(0.0, 2.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(shape: f64, scale: f64) -> Result<Lomax> {
+        if !shape.is_finite() || !scale.is_finite() || shape <= 0.0 || scale <= 0.0 {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(Lomax { shape, scale })
+        }
+    }
+
+    /// Returns the shape (α) of the Lomax distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Lomax;
+    ///
+    /// let n = Lomax::new(3.0, 2.0).unwrap();
+    /// assert_eq!(n.shape(), 3.0);
+    /// ```
+    pub fn shape(&self) -> f64 {
+        self.shape
+    }
+
+    /// Returns the scale (λ) of the Lomax distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Lomax;
+    ///
+    /// let n = Lomax::new(3.0, 2.0).unwrap();
+    /// assert_eq!(n.scale(), 2.0);
+    /// ```
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+}
+
+impl ApproxEq for Lomax {
+    fn approx_eq(&self, other: &Lomax, tol: f64) -> bool {
+        super::internal::params_approx_eq(
+            &[self.shape, self.scale],
+            &[other.shape, other.scale],
+            tol,
+        )
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for Lomax {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        // Inverse transform sampling
+        let u: f64 = rng.sample(OpenClosed01);
+        self.scale * (u.powf(-1.0 / self.shape) - 1.0)
+    }
+}
+
+impl ContinuousCDF<f64, f64> for Lomax {
+    /// Calculates the cumulative distribution function for the Lomax
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if x < 0 {
+    ///     0
+    /// } else {
+    ///     1 - (1 + x/λ)^(-α)
+    /// }
+    /// ```
+    ///
+    /// where `α` is the shape and `λ` is the scale
+    fn cdf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            0.0
+        } else {
+            -(-self.shape * (x / self.scale).ln_1p()).exp_m1()
+        }
+    }
+
+    /// Calculates the survival function for the Lomax
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if x < 0 {
+    ///     1
+    /// } else {
+    ///     (1 + x/λ)^(-α)
+    /// }
+    /// ```
+    ///
+    /// where `α` is the shape and `λ` is the scale
+    fn sf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            1.0
+        } else {
+            (-self.shape * (x / self.scale).ln_1p()).exp()
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// Lomax distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// λ * ((1 - p)^(-1/α) - 1)
+    /// ```
+    ///
+    /// where `α` is the shape and `λ` is the scale
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            panic!("p must be in [0, 1]");
+        }
+        self.scale * (-(-p).ln_1p() / self.shape).exp_m1()
+    }
+}
+
+impl Min<f64> for Lomax {
+    /// Returns the minimum value in the domain of the Lomax distribution
+    /// representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn min(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Max<f64> for Lomax {
+    /// Returns the maximum value in the domain of the Lomax distribution
+    /// representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for Lomax {
+    /// Returns the mean of the Lomax distribution
+    ///
+    /// # None
+    ///
+    /// If `α <= 1`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// λ / (α - 1)
+    /// ```
+    ///
+    /// where `α` is the shape and `λ` is the scale
+    fn mean(&self) -> Option<f64> {
+        if self.shape <= 1.0 {
+            None
+        } else {
+            Some(self.scale / (self.shape - 1.0))
+        }
+    }
+    /// Returns the variance of the Lomax distribution
+    ///
+    /// # None
+    ///
+    /// If `α <= 2`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// λ^2 * α / ((α - 1)^2 * (α - 2))
+    /// ```
+    ///
+    /// where `α` is the shape and `λ` is the scale
+    fn variance(&self) -> Option<f64> {
+        if self.shape <= 2.0 {
+            None
+        } else {
+            let a = self.scale / (self.shape - 1.0);
+            Some(a * a * self.shape / (self.shape - 2.0))
+        }
+    }
+    /// Returns the entropy of the Lomax distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(λ/α) + 1/α + 1
+    /// ```
+    ///
+    /// where `α` is the shape and `λ` is the scale
+    fn entropy(&self) -> Option<f64> {
+        Some((self.scale / self.shape).ln() + 1.0 / self.shape + 1.0)
+    }
+    /// Returns the skewness of the Lomax distribution
+    ///
+    /// # None
+    ///
+    /// If `α <= 3`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (2 * (α + 1) / (α - 3)) * sqrt((α - 2) / α)
+    /// ```
+    ///
+    /// where `α` is the shape
+    fn skewness(&self) -> Option<f64> {
+        if self.shape <= 3.0 {
+            None
+        } else {
+            Some(
+                (2.0 * (self.shape + 1.0) / (self.shape - 3.0))
+                    * ((self.shape - 2.0) / self.shape).sqrt(),
+            )
+        }
+    }
+    /// Returns the excess kurtosis of the Lomax distribution
+    ///
+    /// # None
+    ///
+    /// If `α <= 4`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 6(α^3 + α^2 - 6α - 2) / (α(α - 3)(α - 4))
+    /// ```
+    ///
+    /// where `α` is the shape
+    fn excess_kurtosis(&self) -> Option<f64> {
+        if self.shape <= 4.0 {
+            None
+        } else {
+            let a = self.shape;
+            Some(6.0 * (a * a * a + a * a - 6.0 * a - 2.0) / (a * (a - 3.0) * (a - 4.0)))
+        }
+    }
+}
+
+impl Median<f64> for Lomax {
+    /// Returns the median of the Lomax distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// λ * (2^(1/α) - 1)
+    /// ```
+    ///
+    /// where `α` is the shape and `λ` is the scale
+    fn median(&self) -> f64 {
+        self.scale * (2f64.powf(1.0 / self.shape) - 1.0)
+    }
+}
+
+impl Mode<Option<f64>> for Lomax {
+    /// Returns the mode of the Lomax distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn mode(&self) -> Option<f64> {
+        Some(0.0)
+    }
+}
+
+impl Continuous<f64, f64> for Lomax {
+    /// Calculates the probability density function for the Lomax
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if x < 0 {
+    ///     0
+    /// } else {
+    ///     (α/λ) * (1 + x/λ)^(-(α + 1))
+    /// }
+    /// ```
+    ///
+    /// where `α` is the shape and `λ` is the scale
+    fn pdf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            0.0
+        } else {
+            self.shape / self.scale * (-(self.shape + 1.0) * (x / self.scale).ln_1p()).exp()
+        }
+    }
+
+    /// Calculates the log probability density function for the Lomax
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if x < 0 {
+    ///     -INF
+    /// } else {
+    ///     ln(α) - ln(λ) - (α + 1) * ln(1 + x/λ)
+    /// }
+    /// ```
+    ///
+    /// where `α` is the shape and `λ` is the scale
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            f64::NEG_INFINITY
+        } else {
+            self.shape.ln() - self.scale.ln() - (self.shape + 1.0) * (x / self.scale).ln_1p()
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(all(test, feature = "nightly"))]
+mod tests {
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, Lomax, Pareto};
+    use crate::distribution::internal::*;
+
+    fn try_create(shape: f64, scale: f64) -> Lomax {
+        let l = Lomax::new(shape, scale);
+        assert!(l.is_ok());
+        l.unwrap()
+    }
+
+    fn create_case(shape: f64, scale: f64) {
+        let l = try_create(shape, scale);
+        assert_eq!(shape, l.shape());
+        assert_eq!(scale, l.scale());
+    }
+
+    fn bad_create_case(shape: f64, scale: f64) {
+        let l = Lomax::new(shape, scale);
+        assert!(l.is_err());
+    }
+
+    fn test_case<F>(shape: f64, scale: f64, expected: f64, eval: F)
+        where F: Fn(Lomax) -> f64
+    {
+        let x = eval(try_create(shape, scale));
+        assert_eq!(expected, x);
+    }
+
+    fn test_almost<F>(shape: f64, scale: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(Lomax) -> f64
+    {
+        let x = eval(try_create(shape, scale));
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        create_case(0.1, 10.0);
+        create_case(1.0, 5.0);
+        create_case(10.0, 0.1);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(0.0, 1.0);
+        bad_create_case(1.0, 0.0);
+        bad_create_case(-1.0, 1.0);
+        bad_create_case(1.0, -1.0);
+        bad_create_case(f64::NAN, 1.0);
+        bad_create_case(1.0, f64::NAN);
+        bad_create_case(f64::INFINITY, 1.0);
+        bad_create_case(1.0, f64::INFINITY);
+    }
+
+    #[test]
+    fn test_mean() {
+        let mean = |x: Lomax| x.mean().unwrap();
+        test_case(2.0, 1.0, 1.0, mean);
+        test_case(3.0, 2.0, 1.0, mean);
+        test_case(5.0, 10.0, 2.5, mean);
+        assert!(try_create(1.0, 1.0).mean().is_none());
+        assert!(try_create(0.5, 1.0).mean().is_none());
+    }
+
+    #[test]
+    fn test_mean_sampled() {
+        use ::rand::distributions::Distribution;
+        use ::rand::rngs::StdRng;
+        use ::rand::SeedableRng;
+
+        let l = try_create(5.0, 2.0);
+        let mut r: StdRng = SeedableRng::seed_from_u64(23);
+        let samples: Vec<f64> = (0..100_000).map(|_| l.sample(&mut r)).collect();
+        let mean = samples.iter().sum::<f64>() / samples.len() as f64;
+        assert_almost_eq!(mean, 0.5, 0.01);
+        test::check_ks(&samples, |x| l.cdf(x));
+    }
+
+    #[test]
+    fn test_variance() {
+        let variance = |x: Lomax| x.variance().unwrap();
+        test_case(3.0, 1.0, 0.75, variance);
+        test_almost(10.0, 10.0, 125.0 / 81.0, 1e-13, variance);
+        assert!(try_create(2.0, 1.0).variance().is_none());
+    }
+
+    #[test]
+    fn test_entropy() {
+        let entropy = |x: Lomax| x.entropy().unwrap();
+        test_case(1.0, 1.0, 2.0, entropy);
+        test_almost(2.0, 4.0, 2f64.ln() + 1.5, 1e-15, entropy);
+    }
+
+    #[test]
+    fn test_skewness() {
+        let skewness = |x: Lomax| x.skewness().unwrap();
+        test_case(4.0, 1.0, 5.0*2f64.sqrt(), skewness);
+        assert!(try_create(3.0, 1.0).skewness().is_none());
+    }
+
+    #[test]
+    fn test_excess_kurtosis() {
+        let excess_kurtosis = |x: Lomax| x.excess_kurtosis().unwrap();
+        test_almost(5.0, 1.0, 70.8, 1e-12, excess_kurtosis);
+        test_almost(10.0, 3.0, 14.828571428571428571, 1e-13, excess_kurtosis);
+        assert!(try_create(4.0, 1.0).excess_kurtosis().is_none());
+    }
+
+    #[test]
+    fn test_median_mode() {
+        test_case(1.0, 1.0, 1.0, |x| x.median());
+        test_almost(0.5, 3.0, 9.0, 1e-14, |x| x.median());
+        test_case(2.0, 3.0, 0.0, |x| x.mode().unwrap());
+    }
+
+    #[test]
+    fn test_min_max() {
+        test_case(2.0, 3.0, 0.0, |x| x.min());
+        test_case(2.0, 3.0, f64::INFINITY, |x| x.max());
+    }
+
+    #[test]
+    fn test_pdf_cdf_match_shifted_pareto() {
+        // X + λ is Pareto distributed with scale λ and shape α
+        for &(shape, scale) in &[(0.5, 1.0), (2.0, 3.0), (10.0, 0.1)] {
+            let l = try_create(shape, scale);
+            let p = Pareto::new(scale, shape).unwrap();
+            for &x in &[0.0, 0.05, 1.0, 2.5, 100.0] {
+                assert_almost_eq!(l.pdf(x), p.pdf(x + scale), 1e-12);
+                assert_almost_eq!(l.ln_pdf(x), p.ln_pdf(x + scale), 1e-12);
+                assert_almost_eq!(l.cdf(x), p.cdf(x + scale), 1e-12);
+                assert_almost_eq!(l.sf(x), p.sf(x + scale), 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_pdf_cdf_negative() {
+        let l = try_create(2.0, 3.0);
+        assert_eq!(l.pdf(-1.0), 0.0);
+        assert_eq!(l.ln_pdf(-1.0), f64::NEG_INFINITY);
+        assert_eq!(l.cdf(-1.0), 0.0);
+        assert_eq!(l.sf(-1.0), 1.0);
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let probs = [0.0, 1e-4, 0.01, 0.1, 0.25, 0.5, 0.75, 0.9, 0.99, 0.9999];
+        test::check_inverse_cdf(&try_create(0.5, 1.0), &probs, 1e-9);
+        test::check_inverse_cdf(&try_create(3.0, 2.0), &probs, 1e-12);
+        assert_eq!(try_create(3.0, 2.0).inverse_cdf(1.0), f64::INFINITY);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_inverse_cdf_bad_p() {
+        try_create(3.0, 2.0).inverse_cdf(1.5);
+    }
+
+    #[test]
+    fn test_return_level() {
+        // the return level has the closed form λ * (T^(1/α) - 1)
+        let l = try_create(2.0, 3.0);
+        for &t in &[2.0, 10.0, 100.0, 1e4] {
+            assert_almost_eq!(l.return_level(t), 3.0 * (t.sqrt() - 1.0), 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_continuous() {
+        test::check_continuous_distribution(&try_create(3.0, 2.0), 0.0, 200.0);
+        test::check_continuous_distribution(&try_create(10.0, 0.5), 0.0, 50.0);
+    }
+}
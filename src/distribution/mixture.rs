@@ -0,0 +1,273 @@
+use crate::distribution::Continuous;
+use crate::{Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// Implements a finite [mixture](https://en.wikipedia.org/wiki/Mixture_distribution)
+/// of univariate distributions, where each sample is drawn from a component
+/// chosen at random according to the mixture weights
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Continuous, Mixture, Normal};
+///
+/// let components = vec![Normal::new(-1.0, 1.0).unwrap(), Normal::new(1.0, 1.0).unwrap()];
+/// let m = Mixture::new(&[1.0, 1.0], components).unwrap();
+/// assert_eq!(m.pdf(0.0), Normal::new(1.0, 1.0).unwrap().pdf(0.0));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mixture<D> {
+    weights: Vec<f64>,
+    cdf: Vec<f64>,
+    components: Vec<D>,
+}
+
+impl<D> Mixture<D> {
+    /// Constructs a new mixture of `components` with the mixture weights
+    /// `weights`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `weights` and `components` have different
+    /// lengths, if `weights` is empty, the sum of the elements in `weights`
+    /// is 0, or any element is less than 0 or is `f64::NAN`
+    ///
+    /// # Note
+    ///
+    /// The elements in `weights` do not need to be normalized
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Exp, Mixture};
+    ///
+    /// let components = vec![Exp::new(1.0).unwrap(), Exp::new(2.0).unwrap()];
+    /// let mut result = Mixture::new(&[1.0, 3.0], components.clone());
+    /// assert!(result.is_ok());
+    ///
+    /// result = Mixture::new(&[1.0], components);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(weights: &[f64], components: Vec<D>) -> Result<Mixture<D>> {
+        if weights.len() != components.len()
+            || !super::internal::is_valid_multinomial(weights, true)
+        {
+            return Err(StatsError::BadParams);
+        }
+        let sum: f64 = weights.iter().sum();
+        let weights: Vec<f64> = weights.iter().map(|w| w / sum).collect();
+        let cdf = super::categorical::prob_mass_to_cdf(&weights);
+        Ok(Mixture {
+            weights,
+            cdf,
+            components,
+        })
+    }
+
+    /// Returns the normalized mixture weights
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Exp, Mixture};
+    ///
+    /// let components = vec![Exp::new(1.0).unwrap(), Exp::new(2.0).unwrap()];
+    /// let m = Mixture::new(&[1.0, 3.0], components).unwrap();
+    /// assert_eq!(m.weights(), &[0.25, 0.75]);
+    /// ```
+    pub fn weights(&self) -> &[f64] {
+        &self.weights
+    }
+
+    /// Returns the mixture components
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Exp, Mixture};
+    ///
+    /// let components = vec![Exp::new(1.0).unwrap(), Exp::new(2.0).unwrap()];
+    /// let m = Mixture::new(&[1.0, 3.0], components.clone()).unwrap();
+    /// assert_eq!(m.components(), &components[..]);
+    /// ```
+    pub fn components(&self) -> &[D] {
+        &self.components
+    }
+}
+
+impl<D: ::rand::distributions::Distribution<f64>> Mixture<D> {
+    /// Draws a sample from the mixture and returns it together with the
+    /// index of the component it was drawn from, i.e. the latent variable
+    /// of the mixture, as needed by EM or Gibbs samplers
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Mixture, Uniform};
+    ///
+    /// let components = vec![Uniform::new(0.0, 1.0).unwrap(), Uniform::new(5.0, 6.0).unwrap()];
+    /// let m = Mixture::new(&[1.0, 1.0], components).unwrap();
+    /// let (k, x) = m.sample_with_component(&mut rand::thread_rng());
+    /// assert!(k == 0 && x <= 1.0 || k == 1 && x >= 5.0);
+    /// ```
+    pub fn sample_with_component<R: Rng + ?Sized>(&self, rng: &mut R) -> (usize, f64) {
+        let k = super::categorical::sample_unchecked(rng, &self.cdf) as usize;
+        (k, self.components[k].sample(rng))
+    }
+}
+
+impl<D: ::rand::distributions::Distribution<f64>> ::rand::distributions::Distribution<f64>
+    for Mixture<D>
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        self.sample_with_component(rng).1
+    }
+}
+
+impl<D: Continuous<f64, f64>> Continuous<f64, f64> for Mixture<D> {
+    /// Calculates the probability density function for the mixture at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ w_i * f_i(x)
+    /// ```
+    ///
+    /// where `w_i` are the mixture weights and `f_i` the pdfs of the
+    /// components
+    fn pdf(&self, x: f64) -> f64 {
+        self.weights
+            .iter()
+            .zip(self.components.iter())
+            .map(|(w, d)| w * d.pdf(x))
+            .sum()
+    }
+
+    /// Calculates the log probability density function for the mixture at
+    /// `x`, using the log-sum-exp trick for numerical stability
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(Σ w_i * f_i(x))
+    /// ```
+    ///
+    /// where `w_i` are the mixture weights and `f_i` the pdfs of the
+    /// components
+    fn ln_pdf(&self, x: f64) -> f64 {
+        let terms: Vec<f64> = self
+            .weights
+            .iter()
+            .zip(self.components.iter())
+            .map(|(w, d)| w.ln() + d.ln_pdf(x))
+            .collect();
+        let max = terms.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        if max == f64::NEG_INFINITY {
+            return f64::NEG_INFINITY;
+        }
+        max + terms.iter().map(|t| (t - max).exp()).sum::<f64>().ln()
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(all(test, feature = "nightly"))]
+mod tests {
+    use crate::statistics::*;
+    use crate::distribution::{Continuous, Mixture, Normal, Uniform};
+    use ::rand::rngs::StdRng;
+    use ::rand::SeedableRng;
+
+    fn normals() -> Vec<Normal> {
+        vec![Normal::new(-10.0, 1.0).unwrap(), Normal::new(0.0, 2.0).unwrap(), Normal::new(10.0, 1.0).unwrap()]
+    }
+
+    #[test]
+    fn test_create() {
+        let m = Mixture::new(&[1.0, 2.0, 1.0], normals()).unwrap();
+        assert_eq!(m.weights(), &[0.25, 0.5, 0.25]);
+        assert_eq!(m.components(), &normals()[..]);
+        assert!(Mixture::new(&[0.0, 1.0, 0.0], normals()).is_ok());
+    }
+
+    #[test]
+    fn test_bad_create() {
+        assert!(Mixture::new(&[1.0, 1.0], normals()).is_err());
+        assert!(Mixture::new(&[1.0, -1.0, 1.0], normals()).is_err());
+        assert!(Mixture::new(&[0.0, 0.0, 0.0], normals()).is_err());
+        assert!(Mixture::new(&[1.0, f64::NAN, 1.0], normals()).is_err());
+        assert!(Mixture::<Normal>::new(&[], vec![]).is_err());
+    }
+
+    #[test]
+    fn test_sample_with_component_frequencies() {
+        let m = Mixture::new(&[0.2, 0.5, 0.3], normals()).unwrap();
+        let mut r: StdRng = SeedableRng::seed_from_u64(3);
+        let n = 100_000;
+        let mut counts = [0usize; 3];
+        for _ in 0..n {
+            let (k, _) = m.sample_with_component(&mut r);
+            counts[k] += 1;
+        }
+        for (&count, &w) in counts.iter().zip(m.weights().iter()) {
+            // within about five standard deviations of the binomial count
+            let sd = (n as f64 * w * (1.0 - w)).sqrt();
+            assert!((count as f64 - n as f64 * w).abs() < 5.0 * sd);
+        }
+    }
+
+    #[test]
+    fn test_sample_with_component_plausible() {
+        let components = vec![Uniform::new(0.0, 1.0).unwrap(), Uniform::new(5.0, 6.0).unwrap()];
+        let m = Mixture::new(&[1.0, 3.0], components).unwrap();
+        let mut r: StdRng = SeedableRng::seed_from_u64(4);
+        for _ in 0..1000 {
+            let (k, x) = m.sample_with_component(&mut r);
+            let d = &m.components()[k];
+            assert!(x >= d.min() && x <= d.max());
+        }
+
+        let m = Mixture::new(&[1.0, 1.0, 1.0], normals()).unwrap();
+        for _ in 0..1000 {
+            let (k, x) = m.sample_with_component(&mut r);
+            let d = &m.components()[k];
+            // standardized values beyond 6 are vanishingly unlikely
+            assert!((x - d.mean().unwrap()).abs() < 6.0 * d.std_dev().unwrap());
+        }
+    }
+
+    #[test]
+    fn test_sample_never_picks_zero_weight() {
+        let m = Mixture::new(&[0.0, 1.0, 0.0], normals()).unwrap();
+        let mut r: StdRng = SeedableRng::seed_from_u64(5);
+        for _ in 0..1000 {
+            assert_eq!(m.sample_with_component(&mut r).0, 1);
+        }
+    }
+
+    #[test]
+    fn test_pdf() {
+        let m = Mixture::new(&[1.0, 2.0, 1.0], normals()).unwrap();
+        for &x in &[-12.0, -10.0, -1.0, 0.0, 3.0, 10.0] {
+            let expected = normals()
+                .iter()
+                .zip([0.25, 0.5, 0.25].iter())
+                .map(|(d, w)| w * d.pdf(x))
+                .sum::<f64>();
+            assert_almost_eq!(m.pdf(x), expected, 1e-15);
+            assert_almost_eq!(m.ln_pdf(x), expected.ln(), 1e-13);
+        }
+        // far in the tail the pdf underflows but the log pdf does not
+        assert_eq!(m.pdf(100.0), 0.0);
+        assert_almost_eq!(m.ln_pdf(100.0), 0.5f64.ln() + normals()[1].ln_pdf(100.0), 1e-12);
+    }
+
+    #[test]
+    fn test_ln_pdf_outside_support() {
+        let components = vec![Uniform::new(0.0, 1.0).unwrap(), Uniform::new(5.0, 6.0).unwrap()];
+        let m = Mixture::new(&[1.0, 1.0], components).unwrap();
+        assert_eq!(m.ln_pdf(3.0), f64::NEG_INFINITY);
+        assert_eq!(m.pdf(3.0), 0.0);
+        assert_almost_eq!(m.pdf(0.5), 0.5, 1e-15);
+    }
+}
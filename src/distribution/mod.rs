@@ -1,7 +1,7 @@
 //! Defines common interfaces for interacting with statistical distributions
 //! and provides
 //! concrete implementations for a variety of distributions.
-use super::statistics::{Max, Min};
+use super::statistics::{Distribution, Max, Min, Mode};
 use ::num_traits::{float::Float, Bounded, Num};
 
 pub use self::bernoulli::Bernoulli;
@@ -11,6 +11,7 @@ pub use self::categorical::Categorical;
 pub use self::cauchy::Cauchy;
 pub use self::chi::Chi;
 pub use self::chi_squared::ChiSquared;
+pub use self::compound::Compound;
 pub use self::dirac::Dirac;
 pub use self::dirichlet::Dirichlet;
 pub use self::discrete_uniform::DiscreteUniform;
@@ -20,14 +21,20 @@ pub use self::exponential::Exp;
 pub use self::fisher_snedecor::FisherSnedecor;
 pub use self::gamma::Gamma;
 pub use self::geometric::Geometric;
+pub use self::grid::GridDistribution;
 pub use self::hypergeometric::Hypergeometric;
 pub use self::inverse_gamma::InverseGamma;
 pub use self::laplace::Laplace;
 pub use self::log_normal::LogNormal;
+pub use self::log_uniform::LogUniform;
+pub use self::lomax::Lomax;
+pub use self::mixture::Mixture;
 pub use self::multinomial::Multinomial;
 pub use self::multivariate_normal::MultivariateNormal;
 pub use self::negative_binomial::NegativeBinomial;
 pub use self::normal::Normal;
+#[cfg(feature = "typed_params")]
+pub use self::params::{Rate, Scale};
 pub use self::pareto::Pareto;
 pub use self::poisson::Poisson;
 pub use self::students_t::StudentsT;
@@ -42,6 +49,7 @@ mod categorical;
 mod cauchy;
 mod chi;
 mod chi_squared;
+mod compound;
 mod dirac;
 mod dirichlet;
 mod discrete_uniform;
@@ -51,16 +59,22 @@ mod exponential;
 mod fisher_snedecor;
 mod gamma;
 mod geometric;
+mod grid;
 mod hypergeometric;
 #[macro_use]
-mod internal;
+pub(crate) mod internal;
 mod inverse_gamma;
 mod laplace;
 mod log_normal;
+mod log_uniform;
+mod lomax;
+mod mixture;
 mod multinomial;
 mod multivariate_normal;
 mod negative_binomial;
 mod normal;
+#[cfg(feature = "typed_params")]
+mod params;
 mod pareto;
 mod poisson;
 mod students_t;
@@ -70,7 +84,10 @@ mod weibull;
 mod ziggurat;
 mod ziggurat_tables;
 
-use crate::Result;
+use crate::{Result, StatsError};
+use rand::Rng;
+use std::fmt::Display;
+use std::ops::Neg;
 
 /// The `ContinuousCDF` trait is used to specify an interface for univariate
 /// distributions for which cdf float arguments are sensible.
@@ -137,6 +154,451 @@ pub trait ContinuousCDF<K: Float, T: Float>: Min<K> + Max<K> {
         }
         (high + low) / two
     }
+
+    /// Clamps `x` to the support `[min(), max()]` of the distribution, e.g.
+    /// to sanitize externally produced values that fall just outside the
+    /// support due to rounding.
+    ///
+    /// # Remarks
+    ///
+    /// Most implementations of `pdf` and `cdf` already handle values outside
+    /// the support gracefully, returning `0` or `1` as appropriate, so this
+    /// is only needed when the value itself must lie in the support.
+    /// Returns `NaN` if `x` is `NaN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Uniform};
+    ///
+    /// let n = Uniform::new(0.0, 1.0).unwrap();
+    /// assert_eq!(n.clamp_to_support(1.0000000001), 1.0);
+    /// assert_eq!(n.clamp_to_support(-1e-12), 0.0);
+    /// assert_eq!(n.clamp_to_support(0.5), 0.5);
+    /// ```
+    fn clamp_to_support(&self, x: K) -> K {
+        if x.is_nan() {
+            x
+        } else {
+            x.max(self.min()).min(self.max())
+        }
+    }
+
+    /// Maps `x` to the unit interval through the cumulative distribution
+    /// function, i.e. the probability-integral transform of `x`.
+    /// This is an alias of `cdf`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Uniform};
+    ///
+    /// let n = Uniform::new(0.0, 2.0).unwrap();
+    /// assert_eq!(0.25, n.to_uniform(0.5));
+    /// ```
+    fn to_uniform(&self, x: K) -> T {
+        self.cdf(x)
+    }
+
+    /// Maps `u` from the unit interval back into the domain of the
+    /// distribution through the inverse cumulative distribution function.
+    /// This is an alias of `inverse_cdf`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Normal};
+    ///
+    /// let n = Normal::new(1.0, 2.0).unwrap();
+    /// assert_eq!(1.0, n.from_uniform(0.5));
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn from_uniform(&self, u: T) -> K {
+        self.inverse_cdf(u)
+    }
+
+    /// Returns the probability-integral transform of the samples in `data`,
+    /// mapping each of them through `cdf`. If the samples were drawn
+    /// from this distribution, the result is uniformly distributed
+    /// on `[0, 1]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Uniform};
+    ///
+    /// let n = Uniform::new(0.0, 2.0).unwrap();
+    /// assert_eq!(vec![0.0, 0.25, 1.0], n.pit(&[0.0, 0.5, 2.0]));
+    /// ```
+    fn pit(&self, data: &[K]) -> Vec<T> {
+        data.iter().map(|&x| self.cdf(x)).collect()
+    }
+
+    /// Returns the cumulative distribution function of the `k`-th smallest
+    /// of `n` independent samples from the distribution, calculated at `x`.
+    ///
+    /// # Panics
+    ///
+    /// If `k == 0` or `k > n`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// I_(F(x))(k, n - k + 1)
+    /// ```
+    ///
+    /// where `F` is the cdf of the distribution and `I_(x)(a, b)` is the
+    /// regularized incomplete beta function
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Uniform};
+    /// use statrs::prec;
+    ///
+    /// let n = Uniform::new(0.0, 1.0).unwrap();
+    /// // the maximum of two uniforms
+    /// assert!(prec::almost_eq(n.order_statistic_cdf(2, 2, 0.5), 0.25, 1e-15));
+    /// ```
+    fn order_statistic_cdf(&self, k: u64, n: u64, x: K) -> T {
+        if k == 0 || k > n {
+            panic!("k must be in [1, n]");
+        }
+        let p = self.cdf(x).to_f64().unwrap();
+        let q = crate::function::beta::beta_reg(k as f64, (n - k + 1) as f64, p);
+        T::from(q).unwrap()
+    }
+
+    /// Draws a sample from the distribution truncated to `[lower, upper]`
+    /// by applying `inverse_cdf` to a uniform sample on
+    /// `[cdf(lower), cdf(upper)]`. The accuracy of the sample is limited by
+    /// that of `inverse_cdf`, the result is however always clamped to the
+    /// requested interval.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `lower` or `upper` are `NaN`, if
+    /// `lower >= upper`, or if the distribution has no probability mass
+    /// within `[lower, upper]`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Normal};
+    ///
+    /// let n = Normal::new(0.0, 1.0).unwrap();
+    /// let x = n.sample_truncated(&mut rand::thread_rng(), 1.0, 2.0).unwrap();
+    /// assert!((1.0..=2.0).contains(&x));
+    /// assert!(n.sample_truncated(&mut rand::thread_rng(), 2.0, 1.0).is_err());
+    /// ```
+    fn sample_truncated<R: Rng + ?Sized>(&self, rng: &mut R, lower: K, upper: K) -> Result<K> {
+        if lower.is_nan() || upper.is_nan() || lower >= upper {
+            return Err(StatsError::BadParams);
+        }
+        let cdf_lower = self.cdf(lower);
+        let mass = self.cdf(upper) - cdf_lower;
+        if mass.is_nan() || mass <= T::zero() {
+            return Err(StatsError::BadParams);
+        }
+        let u = T::from(rng.gen::<f64>()).unwrap();
+        let p = (cdf_lower + u * mass).min(T::one());
+        Ok(self.inverse_cdf(p).max(lower).min(upper))
+    }
+
+    /// Returns the value-at-risk at level `alpha`, i.e. the `alpha`-quantile
+    /// of the distribution. Losses are taken to be in the lower tail, so
+    /// that an outcome below the value-at-risk has probability `alpha`.
+    /// This is an alias of `inverse_cdf`.
+    ///
+    /// # Panics
+    ///
+    /// Whenever `inverse_cdf` panics for `alpha`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Normal};
+    /// use statrs::prec;
+    ///
+    /// let n = Normal::new(0.0, 1.0).unwrap();
+    /// assert!(prec::almost_eq(n.value_at_risk(0.05), -1.6448536269514729, 1e-12));
+    /// ```
+    fn value_at_risk(&self, alpha: T) -> K {
+        self.inverse_cdf(alpha)
+    }
+
+    /// Returns the return level for `return_period`, the value that is
+    /// exceeded on average once every `return_period` observations, e.g.
+    /// the 100-year flood level for annual maxima.
+    ///
+    /// # Panics
+    ///
+    /// If `return_period` is `NaN` or `return_period <= 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// F^-1(1 - 1 / T)
+    /// ```
+    ///
+    /// where `T` is the return period and `F^-1` is the inverse cdf
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Normal};
+    ///
+    /// let n = Normal::new(10.0, 2.0).unwrap();
+    /// assert_eq!(n.return_level(100.0), n.inverse_cdf(0.99));
+    /// ```
+    fn return_level(&self, return_period: T) -> K {
+        if return_period.is_nan() || return_period <= T::one() {
+            panic!("return period must be greater than 1");
+        }
+        self.inverse_cdf(T::one() - return_period.recip())
+    }
+
+    /// Returns the probability that a sample deviates from the mean by more
+    /// than `k` standard deviations, e.g. for "k-sigma" reporting, or `None`
+    /// if the mean or the variance of the distribution does not exist.
+    ///
+    /// # Panics
+    ///
+    /// If `k` is `NaN` or `k < 0.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// P(|X - μ| > kσ) = F(μ - kσ) + S(μ + kσ)
+    /// ```
+    ///
+    /// where `μ` is the mean, `σ` is the standard deviation, `F` is the cdf
+    /// and `S` is the survival function
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Cauchy, ContinuousCDF, Normal};
+    /// use statrs::prec;
+    ///
+    /// let n = Normal::new(10.0, 2.0).unwrap();
+    /// let p = n.probability_beyond_sigma(3.0).unwrap();
+    /// assert!(prec::almost_eq(p, 0.0026997960632601866, 1e-12));
+    ///
+    /// let n = Cauchy::new(0.0, 1.0).unwrap();
+    /// assert_eq!(n.probability_beyond_sigma(3.0), None);
+    /// ```
+    fn probability_beyond_sigma(&self, k: K) -> Option<T>
+    where
+        Self: Distribution<K>,
+    {
+        if k.is_nan() || k < K::zero() {
+            panic!("k must be non-negative");
+        }
+        let mean = self.mean()?;
+        let std_dev = self.std_dev()?;
+        Some(self.cdf(mean - k * std_dev) + self.sf(mean + k * std_dev))
+    }
+
+    /// Returns the 5th, 25th, 50th, 75th and 95th percentiles of the
+    /// distribution, in that order, e.g. for summary tables.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Normal};
+    ///
+    /// let n = Normal::new(0.0, 1.0).unwrap();
+    /// let (p5, p25, median, p75, p95) = n.five_number_summary();
+    /// assert_eq!(median, 0.0);
+    /// assert!(p5 < p25 && p25 < median && median < p75 && p75 < p95);
+    /// ```
+    fn five_number_summary(&self) -> (K, K, K, K, K) {
+        let q = |p: f64| self.inverse_cdf(T::from(p).unwrap());
+        (q(0.05), q(0.25), q(0.5), q(0.75), q(0.95))
+    }
+
+    /// Returns a table of `(p, inverse_cdf(p))` pairs, one for each
+    /// probability in `probs`, e.g. for export with `to_csv_string`.
+    ///
+    /// # Panics
+    ///
+    /// If any probability is not in `[0, 1]`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Normal};
+    ///
+    /// let n = Normal::new(0.0, 1.0).unwrap();
+    /// let table = n.quantile_table(&[0.25, 0.5]);
+    /// assert_eq!(table, vec![(0.25, n.inverse_cdf(0.25)), (0.5, 0.0)]);
+    /// ```
+    fn quantile_table(&self, probs: &[T]) -> Vec<(T, K)> {
+        probs
+            .iter()
+            .map(|&p| {
+                if !(p >= T::zero() && p <= T::one()) {
+                    panic!("probability must be in [0, 1]");
+                }
+                (p, self.inverse_cdf(p))
+            })
+            .collect()
+    }
+
+    /// Returns the conditional value-at-risk (expected shortfall) at level
+    /// `alpha`, i.e. the mean of the lower `alpha`-tail of the distribution
+    /// `E[X | X <= inverse_cdf(alpha)]`.
+    ///
+    /// The default implementation integrates `inverse_cdf` numerically over
+    /// `(0, alpha)`, which is equivalent to integrating `x * pdf(x)` below
+    /// `inverse_cdf(alpha)`, so its accuracy is limited by that of
+    /// `inverse_cdf`.
+    ///
+    /// # Panics
+    ///
+    /// If `alpha <= 0.0`, `alpha >= 1.0` or `alpha` is `NaN`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 / α) * ∫_0^α F^-1(u) du
+    /// ```
+    ///
+    /// where `α` is `alpha` and `F^-1` is the inverse cdf of the distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Uniform};
+    /// use statrs::prec;
+    ///
+    /// let n = Uniform::new(0.0, 1.0).unwrap();
+    /// assert!(prec::almost_eq(n.conditional_value_at_risk(0.5), 0.25, 1e-6));
+    /// ```
+    fn conditional_value_at_risk(&self, alpha: T) -> K {
+        let a = alpha.to_f64().unwrap();
+        if !(a > 0.0 && a < 1.0) {
+            panic!("alpha must be in (0, 1)");
+        }
+        // substituting u = alpha * s^2 removes the singularity of the
+        // inverse cdf at 0 for distributions unbounded below
+        const STEPS: usize = 10_000;
+        let h = 1.0 / STEPS as f64;
+        let sum = (0..STEPS).fold(0.0, |acc, i| {
+            let s = (i as f64 + 0.5) * h;
+            let x = self.inverse_cdf(T::from(a * s * s).unwrap());
+            acc + 2.0 * s * x.to_f64().unwrap()
+        });
+        K::from(sum * h).unwrap()
+    }
+
+    /// Draws `n` stratified samples from the distribution by partitioning
+    /// `[0, 1]` into `n` strata of equal probability, drawing a uniform
+    /// sample within each and mapping it through `inverse_cdf`. The `i`-th
+    /// sample lies between the `i / n` and `(i + 1) / n` quantiles, so
+    /// estimators based on the samples have lower variance than with plain
+    /// sampling.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Normal};
+    ///
+    /// let n = Normal::new(0.0, 1.0).unwrap();
+    /// let samples = n.sample_stratified(&mut rand::thread_rng(), 4);
+    /// assert_eq!(samples.len(), 4);
+    /// assert!(samples[0] < n.inverse_cdf(0.25));
+    /// assert!(samples[3] > n.inverse_cdf(0.75));
+    /// ```
+    fn sample_stratified<R: Rng + ?Sized>(&self, rng: &mut R, n: usize) -> Vec<K> {
+        let width = 1.0 / n as f64;
+        (0..n)
+            .map(|i| {
+                let p = ((i as f64 + rng.gen::<f64>()) * width).min(1.0);
+                self.inverse_cdf(T::from(p).unwrap())
+            })
+            .collect()
+    }
+
+    /// Draws `n` samples from the distribution and returns them in
+    /// ascending order, i.e. a realization of all `n` order statistics,
+    /// by mapping sorted uniform samples through `inverse_cdf`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Normal};
+    ///
+    /// let n = Normal::new(0.0, 1.0).unwrap();
+    /// let samples = n.sample_sorted(&mut rand::thread_rng(), 10);
+    /// assert_eq!(samples.len(), 10);
+    /// assert!(samples.windows(2).all(|w| w[0] <= w[1]));
+    /// ```
+    fn sample_sorted<R: Rng + ?Sized>(&self, rng: &mut R, n: usize) -> Vec<K> {
+        let mut u: Vec<f64> = (0..n).map(|_| rng.gen::<f64>()).collect();
+        u.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        u.into_iter()
+            .map(|p| self.inverse_cdf(T::from(p).unwrap()))
+            .collect()
+    }
+
+    /// Draws the `k`-th smallest of `n` independent samples from the
+    /// distribution without sampling all `n`, using that the `k`-th order
+    /// statistic of `n` standard uniform samples is `Beta(k, n - k + 1)`
+    /// distributed and mapping it through `inverse_cdf`
+    ///
+    /// # Panics
+    ///
+    /// If `k == 0` or `k > n`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Uniform};
+    ///
+    /// let n = Uniform::new(0.0, 1.0).unwrap();
+    /// let x = n.sample_kth_order_statistic(&mut rand::thread_rng(), 1000, 1000);
+    /// assert!(x > 0.9);
+    /// ```
+    fn sample_kth_order_statistic<R: Rng + ?Sized>(&self, rng: &mut R, k: u64, n: u64) -> K {
+        if k == 0 || k > n {
+            panic!("k must be in [1, n]");
+        }
+        let beta = Beta::new(k as f64, (n - k + 1) as f64).unwrap();
+        let u = ::rand::distributions::Distribution::sample(&beta, rng);
+        self.inverse_cdf(T::from(u).unwrap())
+    }
+
+    /// Returns the quantile residual of the observation `x`, i.e. the
+    /// standard normal quantile of `cdf(x)`. If the observations follow the
+    /// distribution, their quantile residuals are standard normal.
+    /// `rng` is unused for continuous distributions and only accepted for
+    /// symmetry with the randomized residuals of `DiscreteCDF`.
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Φ^-1(F(x))
+    /// ```
+    ///
+    /// where `F` is the cdf of the distribution and `Φ^-1` is the inverse
+    /// cdf of the standard normal distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Normal};
+    /// use statrs::prec;
+    ///
+    /// let n = Normal::new(2.0, 3.0).unwrap();
+    /// let r = n.quantile_residual(5.0, &mut rand::thread_rng());
+    /// assert!(prec::almost_eq(r, 1.0, 1e-9));
+    /// ```
+    fn quantile_residual<R: Rng + ?Sized>(&self, x: K, _rng: &mut R) -> T {
+        let p = self.cdf(x).to_f64().unwrap();
+        T::from(std_normal_inverse_cdf(p)).unwrap()
+    }
 }
 
 /// The `DiscreteCDF` trait is used to specify an interface for univariate
@@ -195,6 +657,223 @@ pub trait DiscreteCDF<K: Bounded + Clone + Num, T: Float>: Min<K> + Max<K> {
         }
         high
     }
+
+    /// Returns the randomized quantile residual of the observation `x`,
+    /// i.e. the standard normal quantile of a uniform sample on
+    /// `(cdf(x - 1), cdf(x)]`. If the observations follow the
+    /// distribution, their quantile residuals are standard normal.
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Φ^-1(F(x - 1) + U * (F(x) - F(x - 1)))
+    /// ```
+    ///
+    /// where `F` is the cdf of the distribution, `F(min - 1) = 0`, `U` is
+    /// uniform on `(0, 1]` and `Φ^-1` is the inverse cdf of the standard
+    /// normal distribution. Observations below `min` have a residual of
+    /// negative infinity
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{DiscreteCDF, Poisson};
+    ///
+    /// let n = Poisson::new(3.0).unwrap();
+    /// let r = n.quantile_residual(0, &mut rand::thread_rng());
+    /// assert!(r < -1.0);
+    /// ```
+    fn quantile_residual<R: Rng + ?Sized>(&self, x: K, rng: &mut R) -> T
+    where
+        K: PartialOrd,
+    {
+        let upper = self.cdf(x.clone()).to_f64().unwrap();
+        let lower = if x <= self.min() {
+            0.0
+        } else {
+            self.cdf(x - K::one()).to_f64().unwrap()
+        };
+        let u = 1.0 - rng.gen::<f64>();
+        T::from(std_normal_inverse_cdf(lower + u * (upper - lower))).unwrap()
+    }
+}
+
+/// Formats a table of `(p, quantile)` pairs, such as the one returned by
+/// `ContinuousCDF::quantile_table`, as a two-column CSV string with a
+/// `p,quantile` header and one line per pair.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::to_csv_string;
+///
+/// let csv = to_csv_string(&[(0.25, -0.5), (0.5, 1.0)]);
+/// assert_eq!(csv, "p,quantile\n0.25,-0.5\n0.5,1\n");
+/// ```
+pub fn to_csv_string<T: Display, K: Display>(table: &[(T, K)]) -> String {
+    let mut csv = String::from("p,quantile\n");
+    for (p, x) in table {
+        csv.push_str(&format!("{},{}\n", p, x));
+    }
+    csv
+}
+
+// integrates a density `f` from a lower endpoint, which may be `-inf`, by
+// tanh-sinh quadrature. The mass between the lower endpoint and the interior
+// point `split` is computed once and cached, so each upper endpoint only
+// costs the quadrature from `split`. Splitting at the mode keeps the peak of
+// the density at an endpoint, where the tanh-sinh nodes cluster, and an
+// infinite lower tail is scaled by the height of the density at `split`.
+struct QuadInf<'a> {
+    f: &'a dyn Fn(f64) -> f64,
+    split: f64,
+    lower_mass: f64,
+}
+
+impl<'a> QuadInf<'a> {
+    fn new(f: &'a dyn Fn(f64) -> f64, lower: f64, split: f64) -> QuadInf<'a> {
+        let lower_mass = if lower.is_finite() {
+            quad_finite(f, lower, split)
+        } else {
+            let height = f(split);
+            let scale = if height > 0.0 && height.recip().is_finite() {
+                height.recip()
+            } else {
+                1.0
+            };
+            // maps t in [0, 1) onto (-inf, split]
+            tanh_sinh(&|t, r| f(split - scale * t / r) * scale / (r * r))
+        };
+        QuadInf {
+            f,
+            split,
+            lower_mass,
+        }
+    }
+
+    // integrates from the lower endpoint to `b`, where `b >= split`
+    fn integrate_to(&self, b: f64) -> f64 {
+        self.lower_mass + quad_finite(self.f, self.split, b)
+    }
+}
+
+// integrates `f` over the finite interval `[a, b]`, evaluating each node
+// relative to its nearer endpoint so that nodes close to `b` stay accurate
+fn quad_finite(f: &dyn Fn(f64) -> f64, a: f64, b: f64) -> f64 {
+    if a >= b {
+        return 0.0;
+    }
+    let width = b - a;
+    let g = |t: f64, r: f64| {
+        if t <= 0.5 {
+            f(a + width * t)
+        } else {
+            f(b - width * r)
+        }
+    };
+    width * tanh_sinh(&g)
+}
+
+// integrates `g` over `[0, 1]` by tanh-sinh quadrature, where `g(t, r)` is
+// passed both `t` and `r = 1 - t` to avoid cancellation near 1. Non-finite
+// values are taken to be zero, so integrable singularities at the endpoints
+// are allowed. The step is halved until successive estimates agree to a
+// relative tolerance, which bounds the number of evaluations of `g` by about
+// `2 * U_MAX * 2^MAX_LEVEL`.
+fn tanh_sinh(g: &dyn Fn(f64, f64) -> f64) -> f64 {
+    const TOL: f64 = 1e-12;
+    const MIN_LEVEL: u32 = 3;
+    const MAX_LEVEL: u32 = 8;
+    const U_MAX: f64 = 6.5;
+
+    let term = |u: f64| {
+        let v = std::f64::consts::FRAC_PI_2 * u.sinh();
+        let t = 1.0 / (1.0 + (-2.0 * v).exp());
+        let r = 1.0 / (1.0 + (2.0 * v).exp());
+        let weight = std::f64::consts::FRAC_PI_4 * u.cosh() / (v.cosh() * v.cosh());
+        if weight == 0.0 || t == 0.0 || r == 0.0 {
+            return 0.0;
+        }
+        let y = g(t, r);
+        if y.is_finite() {
+            weight * y
+        } else {
+            0.0
+        }
+    };
+    let nodes = |h: f64, first: u32, step: u32| {
+        (0..)
+            .map(move |j| (first + step * j) as f64 * h)
+            .take_while(|&u| u <= U_MAX)
+    };
+
+    let mut h = 1.0;
+    let mut sum = term(0.0) + nodes(h, 1, 1).map(|u| term(u) + term(-u)).sum::<f64>();
+    let mut estimate = h * sum;
+    for level in 1..=MAX_LEVEL {
+        h /= 2.0;
+        sum += nodes(h, 1, 2).map(|u| term(u) + term(-u)).sum::<f64>();
+        let next = h * sum;
+        if level >= MIN_LEVEL && (next - estimate).abs() <= TOL * next.abs() {
+            return next;
+        }
+        estimate = next;
+    }
+    estimate
+}
+
+// maximizes `g` over `[a, b]` by golden-section search, assuming that `g` is
+// unimodal there
+fn golden_max(g: &dyn Fn(f64) -> f64, a: f64, b: f64) -> f64 {
+    const INV_PHI: f64 = 0.618_033_988_749_894_8;
+
+    let (mut a, mut b) = (a, b);
+    let mut c = b - (b - a) * INV_PHI;
+    let mut d = a + (b - a) * INV_PHI;
+    let (mut gc, mut gd) = (g(c), g(d));
+    for _ in 0..200 {
+        if b - a <= f64::EPSILON * (a.abs() + b.abs()) {
+            break;
+        }
+        if gc > gd {
+            b = d;
+            d = c;
+            gd = gc;
+            c = b - (b - a) * INV_PHI;
+            gc = g(c);
+        } else {
+            a = c;
+            c = d;
+            gc = gd;
+            d = a + (b - a) * INV_PHI;
+            gd = g(d);
+        }
+    }
+    0.5 * (a + b)
+}
+
+// returns the mode of the density with log-density `ln_f` restricted to
+// `[lo, x]`, assuming that the density is unimodal. For an infinite `lo`
+// the search interval is widened below `x` until it brackets the mode.
+fn restricted_mode(ln_f: &dyn Fn(f64) -> f64, lo: f64, x: f64) -> f64 {
+    let mut a = lo;
+    if !lo.is_finite() {
+        let mut width = 1.0;
+        a = x - width;
+        for _ in 0..64 {
+            if ln_f(a) < ln_f(x) {
+                break;
+            }
+            width *= 2.0;
+            a = x - width;
+        }
+    }
+    golden_max(ln_f, a, x)
+}
+
+// inverse cdf of the standard normal distribution
+fn std_normal_inverse_cdf(p: f64) -> f64 {
+    -std::f64::consts::SQRT_2 * crate::function::erf::erfc_inv(2.0 * p)
 }
 
 /// The `Continuous` trait  provides an interface for interacting with
@@ -233,6 +912,179 @@ pub trait Continuous<K, T> {
     /// assert_eq!(0.0, n.ln_pdf(0.5));
     /// ```
     fn ln_pdf(&self, x: K) -> T;
+
+    /// Returns the probability density function calculated at the mode of
+    /// the distribution, i.e. the peak density, or `None` if the mode
+    /// does not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Continuous, Normal};
+    /// use statrs::prec;
+    ///
+    /// let n = Normal::new(0.0, 1.0).unwrap();
+    /// let expected = 1.0 / (2.0 * std::f64::consts::PI).sqrt();
+    /// assert!(prec::almost_eq(n.mode_density().unwrap(), expected, 1e-15));
+    /// ```
+    fn mode_density(&self) -> Option<T>
+    where
+        Self: Mode<Option<K>>,
+    {
+        self.mode().map(|m| self.pdf(m))
+    }
+
+    /// Numerically finds the mode of the distribution within `[lo, hi]` by
+    /// golden-section search on the probability density function, for
+    /// distributions without a closed-form mode.
+    ///
+    /// # Remarks
+    ///
+    /// The search assumes that the density is unimodal on `[lo, hi]`. For a
+    /// multimodal density it converges to one of the local maxima, which is
+    /// not necessarily the global one, so `[lo, hi]` should bracket the
+    /// desired mode. The result is accurate to about the square root of the
+    /// machine precision relative to the magnitude of the mode, since the
+    /// density is flat near its peak.
+    ///
+    /// # Panics
+    ///
+    /// If `lo` or `hi` are `NaN` or `lo > hi`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Continuous, Gamma};
+    ///
+    /// let n = Gamma::new(3.0, 2.0).unwrap();
+    /// assert!((n.mode_numeric(0.0, 10.0) - 1.0).abs() < 1e-6);
+    /// ```
+    fn mode_numeric(&self, lo: K, hi: K) -> K
+    where
+        K: Float,
+        T: PartialOrd,
+    {
+        if lo.is_nan() || hi.is_nan() || lo > hi {
+            panic!("lo and hi must not be NaN and lo must not exceed hi");
+        }
+        let inv_phi = K::from(0.618_033_988_749_894_8).unwrap();
+        let (mut a, mut b) = (lo, hi);
+        let mut c = b - (b - a) * inv_phi;
+        let mut d = a + (b - a) * inv_phi;
+        let (mut fc, mut fd) = (self.pdf(c), self.pdf(d));
+        for _ in 0..200 {
+            if b - a <= K::epsilon() * (a.abs() + b.abs()) {
+                break;
+            }
+            if fc > fd {
+                b = d;
+                d = c;
+                fd = fc;
+                c = b - (b - a) * inv_phi;
+                fc = self.pdf(c);
+            } else {
+                a = c;
+                c = d;
+                fc = fd;
+                d = a + (b - a) * inv_phi;
+                fd = self.pdf(d);
+            }
+        }
+        (a + b) / (K::one() + K::one())
+    }
+
+    /// Numerically evaluates the cumulative distribution function at `x` by
+    /// integrating the probability density function from the lower bound
+    /// of the support, for distributions that only provide a density.
+    ///
+    /// # Remarks
+    ///
+    /// The integral is split at the mode of the density below `x`, found by
+    /// golden-section search on `ln_pdf`, and both pieces are computed by
+    /// tanh-sinh quadrature, mapping an infinite lower bound onto a finite
+    /// interval. For unimodal densities the result is typically accurate to
+    /// about `1e-10`, including densities that are highly concentrated,
+    /// centred far from zero or unbounded at the lower bound of the support.
+    /// Mass below the smallest positive double, as for a gamma density with
+    /// a very small shape, is lost. Multimodal densities may be integrated
+    /// less accurately, and the analytic `cdf` should be preferred where it
+    /// exists.
+    ///
+    /// # Panics
+    ///
+    /// If `x` is `NaN`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Continuous, ContinuousCDF, Gamma};
+    /// use statrs::prec;
+    ///
+    /// let n = Gamma::new(3.0, 2.0).unwrap();
+    /// assert!(prec::almost_eq(n.cdf_numeric(1.5), n.cdf(1.5), 1e-8));
+    /// ```
+    fn cdf_numeric(&self, x: K) -> T
+    where
+        K: Float,
+        T: Float,
+        Self: Min<K>,
+    {
+        if x.is_nan() {
+            panic!("x must not be NaN");
+        }
+        let lo = self.min().to_f64().unwrap();
+        let x = x.to_f64().unwrap();
+        if x <= lo {
+            return T::zero();
+        }
+        if x == f64::INFINITY {
+            return T::one();
+        }
+        let pdf = |t: f64| self.pdf(K::from(t).unwrap()).to_f64().unwrap();
+        let ln_pdf = |t: f64| self.ln_pdf(K::from(t).unwrap()).to_f64().unwrap();
+        let split = restricted_mode(&ln_pdf, lo, x);
+        let p = QuadInf::new(&pdf, lo, split).integrate_to(x);
+        T::from(p.clamp(0.0, 1.0)).unwrap()
+    }
+
+    /// Returns the information content, or surprisal, of observing `x`,
+    /// i.e. the negative log of the probability density function.
+    /// May panic depending on the implementor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Continuous, Uniform};
+    ///
+    /// let n = Uniform::new(0.0, 4.0).unwrap();
+    /// assert_eq!(4f64.ln(), n.information_content(1.0));
+    /// ```
+    fn information_content(&self, x: K) -> T
+    where
+        T: Neg<Output = T>,
+    {
+        -self.ln_pdf(x)
+    }
+
+    /// Returns the expected information content of the distribution, which
+    /// is its (differential) entropy, or `None` if the entropy does not
+    /// exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Continuous, Uniform};
+    ///
+    /// let n = Uniform::new(0.0, 4.0).unwrap();
+    /// assert_eq!(4f64.ln(), n.expected_information().unwrap());
+    /// ```
+    fn expected_information(&self) -> Option<T>
+    where
+        T: Float,
+        Self: Distribution<T>,
+    {
+        self.entropy()
+    }
 }
 
 /// The `Discrete` trait provides an interface for interacting with discrete
@@ -273,4 +1125,113 @@ pub trait Discrete<K, T> {
     /// assert!(prec::almost_eq(n.ln_pmf(5), (0.24609375f64).ln(), 1e-15));
     /// ```
     fn ln_pmf(&self, x: K) -> T;
+
+    /// Returns the probability mass function calculated at the mode of the
+    /// distribution, i.e. the largest probability mass, or `None` if the
+    /// mode does not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Discrete, Binomial};
+    /// use statrs::prec;
+    ///
+    /// let n = Binomial::new(0.5, 10).unwrap();
+    /// assert!(prec::almost_eq(n.mode_density().unwrap(), 0.24609375, 1e-15));
+    /// ```
+    fn mode_density(&self) -> Option<T>
+    where
+        Self: Mode<Option<K>>,
+    {
+        self.mode().map(|m| self.pmf(m))
+    }
+
+    /// Returns the information content, or surprisal, of observing `x`,
+    /// i.e. the negative log of the probability mass function.
+    /// May panic depending on the implementor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Discrete, Binomial};
+    /// use statrs::prec;
+    ///
+    /// let n = Binomial::new(0.5, 1).unwrap();
+    /// assert!(prec::almost_eq(n.information_content(1), 2f64.ln(), 1e-15));
+    /// ```
+    fn information_content(&self, x: K) -> T
+    where
+        T: Neg<Output = T>,
+    {
+        -self.ln_pmf(x)
+    }
+
+    /// Returns the expected information content of the distribution, which
+    /// is its entropy, or `None` if the entropy does not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Discrete, Binomial};
+    /// use statrs::prec;
+    ///
+    /// let n = Binomial::new(0.5, 1).unwrap();
+    /// assert!(prec::almost_eq(n.expected_information().unwrap(), 2f64.ln(), 1e-15));
+    /// ```
+    fn expected_information(&self) -> Option<T>
+    where
+        T: Float,
+        Self: Distribution<T>,
+    {
+        self.entropy()
+    }
+}
+
+/// The `ApproxEq` trait provides an approximate comparison of two
+/// distributions of the same family, e.g. for checking that two
+/// parameterizations describe the same distribution
+pub trait ApproxEq {
+    /// Returns true if every parameter of `self` differs from the
+    /// corresponding parameter of `other` by at most `tol`.
+    ///
+    /// # Remarks
+    ///
+    /// The comparison uses the absolute difference of the stored parameters,
+    /// so `tol` should be chosen relative to their magnitude. Equal infinite
+    /// parameters compare as equal and integer parameters, such as the
+    /// number of trials of a binomial distribution, must match exactly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ApproxEq, Gamma};
+    ///
+    /// let n = Gamma::new(3.0, 2.0).unwrap();
+    /// assert!(n.approx_eq(&Gamma::new_with_scale(3.0, 0.5).unwrap(), 1e-12));
+    /// assert!(!n.approx_eq(&Gamma::new(3.0, 2.1).unwrap(), 1e-12));
+    /// ```
+    fn approx_eq(&self, other: &Self, tol: f64) -> bool;
+}
+
+/// The `MomentMatch` trait provides a constructor for distributions that
+/// have a given mean and variance, e.g. for quick approximations
+pub trait MomentMatch: Sized {
+    /// Constructs the distribution whose mean is `mean` and whose variance
+    /// is `variance`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no distribution of the family has the given
+    /// moments, e.g. if `variance <= 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Gamma, MomentMatch};
+    ///
+    /// let n = Gamma::from_mean_variance(6.0, 12.0).unwrap();
+    /// assert_eq!(n, Gamma::new(3.0, 0.5).unwrap());
+    /// assert!(Gamma::from_mean_variance(-1.0, 1.0).is_err());
+    /// ```
+    fn from_mean_variance(mean: f64, variance: f64) -> Result<Self>;
 }
@@ -1,4 +1,4 @@
-use crate::distribution::Discrete;
+use crate::distribution::{ApproxEq, Discrete};
 use crate::function::factorial;
 use crate::statistics::*;
 use crate::{Result, StatsError};
@@ -90,6 +90,12 @@ impl Multinomial {
     }
 }
 
+impl ApproxEq for Multinomial {
+    fn approx_eq(&self, other: &Multinomial, tol: f64) -> bool {
+        self.n == other.n && super::internal::params_approx_eq(&self.p, &other.p, tol)
+    }
+}
+
 impl ::rand::distributions::Distribution<Vec<f64>> for Multinomial {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<f64> {
         let p_cdf = super::categorical::prob_mass_to_cdf(self.p());
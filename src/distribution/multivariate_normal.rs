@@ -1,5 +1,5 @@
-use crate::distribution::Continuous;
 use crate::distribution::Normal;
+use crate::distribution::{ApproxEq, Continuous};
 use crate::statistics::{Max, MeanN, Min, Mode, VarianceN};
 use crate::{Result, StatsError};
 use nalgebra::{
@@ -79,6 +79,61 @@ impl MultivariateNormal {
             }
         }
     }
+    /// Constructs a new multivariate normal distribution with a mean of
+    /// `mean` and precision (inverse covariance) matrix `precision`, the
+    /// information form used e.g. for Gaussian graphical models
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the given precision matrix is not
+    /// symmetric or positive-definite, or if its dimension does not match
+    /// the dimension of `mean`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::MultivariateNormal;
+    /// use statrs::statistics::VarianceN;
+    /// use nalgebra::DMatrix;
+    ///
+    /// let precision = DMatrix::from_vec(2, 2, vec![4., 0., 0., 16.]);
+    /// let mvn = MultivariateNormal::from_precision(vec![0., 0.], precision).unwrap();
+    /// assert_eq!(mvn.variance().unwrap(), DMatrix::from_vec(2, 2, vec![0.25, 0., 0., 0.0625]));
+    /// ```
+    pub fn from_precision(mean: Vec<f64>, precision: DMatrix<f64>) -> Result<Self> {
+        if precision.nrows() != mean.len()
+            || precision.ncols() != mean.len()
+            || precision.iter().any(|f| f.is_nan())
+            || precision.lower_triangle() != precision.upper_triangle().transpose()
+        {
+            return Err(StatsError::BadParams);
+        }
+        let cov = Cholesky::new(precision.clone())
+            .ok_or(StatsError::BadParams)?
+            .inverse();
+        // the inverse is only symmetric up to rounding
+        let cov = (&cov + cov.transpose()) * 0.5;
+        let mut mvn = MultivariateNormal::new(mean, cov.as_slice().to_vec())?;
+        mvn.precision = precision;
+        Ok(mvn)
+    }
+
+    /// Returns the precision matrix of the multivariate normal
+    /// distribution, the inverse of the covariance matrix
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::MultivariateNormal;
+    /// use nalgebra::DMatrix;
+    ///
+    /// let mvn = MultivariateNormal::new(vec![0., 0.], vec![4., 0., 0., 16.]).unwrap();
+    /// assert_eq!(mvn.precision_matrix(), DMatrix::from_vec(2, 2, vec![0.25, 0., 0., 0.0625]));
+    /// ```
+    pub fn precision_matrix(&self) -> DMatrix<f64> {
+        self.precision.clone()
+    }
+
     /// Returns the entropy of the multivariate normal distribution
     ///
     /// # Formula
@@ -98,6 +153,99 @@ impl MultivariateNormal {
                 .ln(),
         )
     }
+
+    /// Draws `n` samples from the multivariate normal distribution at once,
+    /// returning them as the rows of an `n × k` matrix where `k` is the
+    /// dimension of the distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Z * transpose(L) + 1 * transpose(μ)
+    /// ```
+    ///
+    /// where `L` is the Cholesky decomposition of the covariance matrix,
+    /// `Z` is an `n × k` matrix of normally distributed random variables,
+    /// `1` is a column vector of ones and `μ` is the mean vector
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::MultivariateNormal;
+    ///
+    /// let mvn = MultivariateNormal::new(vec![0., 0.], vec![1., 0., 0., 1.]).unwrap();
+    /// let samples = mvn.sample_matrix(&mut rand::thread_rng(), 100);
+    /// assert_eq!(samples.shape(), (100, 2));
+    /// ```
+    pub fn sample_matrix<R: Rng + ?Sized>(&self, rng: &mut R, n: usize) -> DMatrix<f64> {
+        let d = Normal::new(0., 1.).unwrap();
+        let z = DMatrix::<f64>::from_distribution(n, self.dim, &d, rng);
+        let mut samples = z * self.cov_chol_decomp.transpose();
+        for mut row in samples.row_iter_mut() {
+            row += self.mu.transpose();
+        }
+        samples
+    }
+
+    /// Returns the Kullback-Leibler divergence `KL(self || other)` of the
+    /// `other` multivariate normal distribution from this one
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the dimensions of the distributions differ
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 / 2) * (tr(inv(Σ_2) * Σ_1) + transpose(μ_2 - μ_1) * inv(Σ_2) * (μ_2 - μ_1) - k + ln(det(Σ_2) / det(Σ_1)))
+    /// ```
+    ///
+    /// where `μ_1`, `Σ_1` are the mean and covariance matrix of this
+    /// distribution, `μ_2`, `Σ_2` those of `other` and `k` is the dimension.
+    /// All three terms are computed from the Cholesky decompositions of the
+    /// covariance matrices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::MultivariateNormal;
+    ///
+    /// let a = MultivariateNormal::new(vec![0., 0.], vec![1., 0., 0., 1.]).unwrap();
+    /// let b = MultivariateNormal::new(vec![2., 0.], vec![1., 0., 0., 1.]).unwrap();
+    /// assert_eq!(a.kl_divergence(&b).unwrap(), 2.0);
+    /// ```
+    pub fn kl_divergence(&self, other: &MultivariateNormal) -> Result<f64> {
+        if self.dim != other.dim {
+            return Err(StatsError::BadParams);
+        }
+        let l_other = &other.cov_chol_decomp;
+        // tr(inv(Σ_2) * Σ_1) is the squared Frobenius norm of inv(L_2) * L_1
+        let trace = l_other
+            .solve_lower_triangular(&self.cov_chol_decomp)
+            .ok_or(StatsError::BadParams)?
+            .norm_squared();
+        let mahalanobis = l_other
+            .solve_lower_triangular(&(&other.mu - &self.mu))
+            .ok_or(StatsError::BadParams)?
+            .norm_squared();
+        // ln(det(Σ)) is twice the sum of the logs of the diagonal of L
+        let log_det_ratio = 2.0
+            * l_other
+                .diagonal()
+                .iter()
+                .zip(self.cov_chol_decomp.diagonal().iter())
+                .map(|(a, b)| a.ln() - b.ln())
+                .sum::<f64>();
+        Ok(0.5 * (trace + mahalanobis - self.dim as f64 + log_det_ratio))
+    }
+}
+
+impl ApproxEq for MultivariateNormal {
+    fn approx_eq(&self, other: &MultivariateNormal, tol: f64) -> bool {
+        self.dim == other.dim
+            && super::internal::params_approx_eq(self.mu.as_slice(), other.mu.as_slice(), tol)
+            && super::internal::params_approx_eq(self.cov.as_slice(), other.cov.as_slice(), tol)
+    }
 }
 
 impl ::rand::distributions::Distribution<DVector<f64>> for MultivariateNormal {
@@ -353,4 +501,99 @@ mod tests  {
         test_case(vec![0., 0.], vec![f64::INFINITY, 0., 0., f64::INFINITY], f64::NEG_INFINITY, ln_pdf(dvec![10., 10.]));
         test_case(vec![0., 0.], vec![f64::INFINITY, 0., 0., f64::INFINITY], f64::NEG_INFINITY, ln_pdf(dvec![100., 100.]));
     }
+
+    #[test]
+    fn test_from_precision() {
+        let precision = mat2![2., -1., -1., 2.];
+        let mvn = MultivariateNormal::from_precision(vec![1., 2.], precision.clone()).unwrap();
+        assert_eq!(mvn.mean().unwrap(), dvec![1., 2.]);
+        assert_eq!(mvn.precision_matrix(), precision);
+        let expected = mat2![2. / 3., 1. / 3., 1. / 3., 2. / 3.];
+        let cov = mvn.variance().unwrap();
+        for (a, b) in cov.iter().zip(expected.iter()) {
+            assert_almost_eq!(*a, *b, 1e-15);
+        }
+        let identity = cov * &precision;
+        for (a, b) in identity.iter().zip(DMatrix::<f64>::identity(2, 2).iter()) {
+            assert_almost_eq!(*a, *b, 1e-15);
+        }
+        // the density matches the covariance form
+        let other = try_create(vec![1., 2.], vec![2. / 3., 1. / 3., 1. / 3., 2. / 3.]);
+        let x = dvec![0.5, 3.];
+        assert_almost_eq!(mvn.ln_pdf(&x), other.ln_pdf(&x), 1e-14);
+    }
+
+    #[test]
+    fn test_from_precision_bad() {
+        // not symmetric
+        assert!(MultivariateNormal::from_precision(vec![0., 0.], mat2![1., 1., 0., 1.]).is_err());
+        // not positive-definite
+        assert!(MultivariateNormal::from_precision(vec![0., 0.], mat2![1., 2., 2., 1.]).is_err());
+        // dimension mismatch
+        assert!(MultivariateNormal::from_precision(vec![0.], mat2![1., 0., 0., 1.]).is_err());
+        // NaN
+        assert!(MultivariateNormal::from_precision(vec![0., 0.], mat2![f64::NAN, 0., 0., 1.]).is_err());
+    }
+
+    #[test]
+    fn test_precision_matrix() {
+        let mvn = try_create(vec![0., 0.], vec![2., 1., 1., 2.]);
+        let identity = mvn.precision_matrix() * mvn.variance().unwrap();
+        for (a, b) in identity.iter().zip(DMatrix::<f64>::identity(2, 2).iter()) {
+            assert_almost_eq!(*a, *b, 1e-15);
+        }
+    }
+
+    #[test]
+    fn test_kl_divergence() {
+        let p = try_create(vec![0., 0.], vec![1., 0., 0., 1.]);
+        let q = try_create(vec![1., 1.], vec![2., 1., 1., 2.]);
+        // tr(inv(Σ_q)) = 4/3, the Mahalanobis term is 2/3 and det(Σ_q) = 3
+        assert_almost_eq!(p.kl_divergence(&q).unwrap(), 0.5 * 3f64.ln(), 1e-14);
+        assert_eq!(p.kl_divergence(&p).unwrap(), 0.0);
+        assert_almost_eq!(q.kl_divergence(&q).unwrap(), 0.0, 1e-15);
+    }
+
+    #[test]
+    fn test_kl_divergence_diagonal_matches_normal() {
+        use crate::distribution::Normal;
+
+        let p = try_create(vec![1., -2.], vec![4., 0., 0., 0.25]);
+        let q = try_create(vec![0., 0.5], vec![1., 0., 0., 9.]);
+        let expected = Normal::new(1., 2.).unwrap().kl_divergence(&Normal::new(0., 1.).unwrap())
+            + Normal::new(-2., 0.5).unwrap().kl_divergence(&Normal::new(0.5, 3.).unwrap());
+        assert_almost_eq!(p.kl_divergence(&q).unwrap(), expected, 1e-14);
+    }
+
+    #[test]
+    fn test_kl_divergence_dimension_mismatch() {
+        let p = try_create(vec![0., 0.], vec![1., 0., 0., 1.]);
+        let q = try_create(vec![0., 0., 0.], vec![1., 0., 0., 0., 1., 0., 0., 0., 1.]);
+        assert!(p.kl_divergence(&q).is_err());
+        assert!(q.kl_divergence(&p).is_err());
+    }
+
+    #[test]
+    fn test_sample_matrix() {
+        use ::rand::rngs::StdRng;
+        use ::rand::SeedableRng;
+
+        let mean = vec![0.5, -2.0];
+        let cov = vec![2.0, 0.6, 0.6, 1.0];
+        let mvn = try_create(mean.clone(), cov.clone());
+        let mut r: StdRng = SeedableRng::seed_from_u64(42);
+        let n = 50_000;
+        let samples = mvn.sample_matrix(&mut r, n);
+        assert_eq!(samples.shape(), (n, 2));
+
+        let sample_mean = samples.row_mean();
+        for i in 0..2 {
+            assert_almost_eq!(mean[i], sample_mean[i], 0.03);
+        }
+        let centered = DMatrix::from_fn(n, 2, |i, j| samples[(i, j)] - sample_mean[j]);
+        let sample_cov = centered.transpose() * &centered / (n as f64 - 1.0);
+        for (expected, x) in cov.iter().zip(sample_cov.iter()) {
+            assert_almost_eq!(*expected, *x, 0.05);
+        }
+    }
 }
@@ -1,4 +1,4 @@
-use crate::distribution::{self, poisson, Discrete, DiscreteCDF};
+use crate::distribution::{self, poisson, ApproxEq, Discrete, DiscreteCDF};
 use crate::function::{beta, gamma};
 use crate::statistics::*;
 use crate::{Result, StatsError};
@@ -102,11 +102,65 @@ impl NegativeBinomial {
     pub fn r(&self) -> f64 {
         self.r
     }
+
+    /// Returns the gradient of the total log-likelihood of the samples in
+    /// `data` with respect to the mean `μ` and the dispersion `r` of the
+    /// negative binomial distribution, as `(∂/∂μ, ∂/∂r)`.
+    ///
+    /// In this parameterization the variance is `μ + μ^2 / r` and the
+    /// distribution corresponds to
+    /// `NegativeBinomial::new(r, r / (r + μ))`.
+    ///
+    /// # Panics
+    ///
+    /// If `mean` or `dispersion` are `NaN`, infinite or not positive
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ∂/∂μ = Σ (x_i / μ - (x_i + r) / (μ + r))
+    /// ∂/∂r = Σ (ψ(x_i + r) - ψ(r) + ln(r / (μ + r)) + 1 - (x_i + r) / (μ + r))
+    /// ```
+    ///
+    /// where `ψ` is the digamma function
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NegativeBinomial;
+    ///
+    /// let data = [0, 2, 3, 7];
+    /// // the gradient with respect to the mean vanishes at the sample mean
+    /// let (d_mean, _) = NegativeBinomial::loglik_grad(&data, 3.0, 1.5);
+    /// assert!(d_mean.abs() < 1e-12);
+    /// ```
+    pub fn loglik_grad(data: &[u64], mean: f64, dispersion: f64) -> (f64, f64) {
+        if !mean.is_finite() || mean <= 0.0 || !dispersion.is_finite() || dispersion <= 0.0 {
+            panic!("mean and dispersion must be finite and positive");
+        }
+        let (mu, r) = (mean, dispersion);
+        let ln_p = (r / (mu + r)).ln();
+        let digamma_r = gamma::digamma(r);
+        data.iter().fold((0.0, 0.0), |(d_mu, d_r), &x| {
+            let k = x as f64;
+            let ratio = (k + r) / (mu + r);
+            (
+                d_mu + k / mu - ratio,
+                d_r + gamma::digamma(k + r) - digamma_r + ln_p + 1.0 - ratio,
+            )
+        })
+    }
+}
+
+impl ApproxEq for NegativeBinomial {
+    fn approx_eq(&self, other: &NegativeBinomial, tol: f64) -> bool {
+        super::internal::params_approx_eq(&[self.r, self.p], &[other.r, other.p], tol)
+    }
 }
 
 impl ::rand::distributions::Distribution<u64> for NegativeBinomial {
     fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> u64 {
-        let lambda = distribution::gamma::sample_unchecked(r, self.r, (1.0 - self.p) / self.p);
+        let lambda = distribution::gamma::sample_unchecked(r, self.r, self.p / (1.0 - self.p));
         poisson::sample_unchecked(r, lambda).floor() as u64
     }
 }
@@ -208,6 +262,16 @@ impl DiscreteDistribution<f64> for NegativeBinomial {
     fn skewness(&self) -> Option<f64> {
         Some((2.0 - self.p) / f64::sqrt(self.r * (1.0 - self.p)))
     }
+    /// Returns the excess kurtosis of the negative binomial distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 6 / r + p^2 / (r * (1 - p))
+    /// ```
+    fn excess_kurtosis(&self) -> Option<f64> {
+        Some(6.0 / self.r + self.p * self.p / (self.r * (1.0 - self.p)))
+    }
 }
 
 impl Mode<Option<f64>> for NegativeBinomial {
@@ -380,6 +444,25 @@ mod tests {
         test_case(1.0, 1.0, f64::INFINITY, skewness);
     }
 
+    #[test]
+    fn test_excess_kurtosis() {
+        let excess_kurtosis = |x: NegativeBinomial| x.excess_kurtosis().unwrap();
+        test_almost(0.1, 0.3, 61.285714285714282197, 1e-12, excess_kurtosis);
+        test_case(4.0, 0.5, 1.625, excess_kurtosis);
+        test_almost(2.5, 0.8, 3.68, 1e-14, excess_kurtosis);
+    }
+
+    #[test]
+    fn test_cumulants() {
+        let n = try_create(4.0, 0.5);
+        let (mean, variance) = (n.mean().unwrap(), n.variance().unwrap());
+        let k = n.cumulants().unwrap();
+        assert_eq!(k[0], mean);
+        assert_eq!(k[1], variance);
+        assert_almost_eq!(k[2], n.skewness().unwrap() * variance.powf(1.5), 1e-12);
+        assert_almost_eq!(k[3], 1.625 * variance * variance, 1e-12);
+    }
+
     #[test]
     fn test_mode() {
         let mode = |x: NegativeBinomial| x.mode().unwrap();
@@ -502,4 +585,70 @@ mod tests {
         let sf = |arg: u64| move |x: NegativeBinomial| x.sf(arg);
         test_almost(3.0, 0.5, 5.282409836586059e-28, 1e-28, sf(100));
     }
+
+    #[test]
+    fn test_counts_failures() {
+        // the support starts at 0, counting failures before the r-th success
+        for &p in &[0.1, 0.3, 0.75] {
+            let nb = try_create(1.0, p);
+            assert_eq!(nb.min(), 0);
+            assert_almost_eq!(nb.pmf(0), p, 1e-14);
+            assert_almost_eq!(nb.mean().unwrap(), (1.0 - p) / p, 1e-14);
+            assert_almost_eq!(nb.variance().unwrap(), (1.0 - p) / (p * p), 1e-14);
+            assert_eq!(nb.mode(), Some(0.0));
+        }
+        let nb = try_create(4.0, 0.5);
+        assert_almost_eq!(nb.pmf(0), 0.0625, 1e-14);
+        assert_eq!(nb.mean(), Some(4.0));
+        assert_eq!(nb.variance(), Some(8.0));
+        assert_eq!(nb.mode(), Some(3.0));
+    }
+
+    #[test]
+    fn test_sample_counts_failures() {
+        use ::rand::distributions::Distribution;
+        use ::rand::rngs::StdRng;
+        use ::rand::SeedableRng;
+
+        let nb = try_create(1.0, 0.25);
+        let mut r: StdRng = SeedableRng::seed_from_u64(1);
+        let samples: Vec<u64> = (0..10_000).map(|_| nb.sample(&mut r)).collect();
+        assert!(samples.contains(&0));
+        let mean = samples.iter().sum::<u64>() as f64 / samples.len() as f64;
+        assert_almost_eq!(mean, 3.0, 0.15);
+    }
+
+    #[test]
+    fn test_loglik_grad() {
+        let data = [0, 1, 1, 2, 4, 5, 9, 12, 30];
+        let loglik = |mean: f64, dispersion: f64| {
+            let dist = try_create(dispersion, dispersion / (dispersion + mean));
+            data.iter().map(|&x| dist.ln_pmf(x)).sum::<f64>()
+        };
+        let h = 1e-6;
+        for &(mean, dispersion) in &[(1.0, 0.5), (7.1, 2.0), (20.0, 10.0)] {
+            let (d_mean, d_dispersion) = NegativeBinomial::loglik_grad(&data, mean, dispersion);
+            let fd_mean = (loglik(mean + h, dispersion) - loglik(mean - h, dispersion)) / (2.0 * h);
+            let fd_dispersion = (loglik(mean, dispersion + h) - loglik(mean, dispersion - h)) / (2.0 * h);
+            assert_almost_eq!(d_mean, fd_mean, 1e-5);
+            assert_almost_eq!(d_dispersion, fd_dispersion, 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_loglik_grad_root() {
+        // the maximum likelihood mean is the sample mean for any dispersion
+        let data = [0, 1, 1, 2, 4, 5, 9, 12, 30];
+        let mean = data.iter().sum::<u64>() as f64 / data.len() as f64;
+        for &dispersion in &[0.1, 1.0, 100.0] {
+            let (d_mean, _) = NegativeBinomial::loglik_grad(&data, mean, dispersion);
+            assert_almost_eq!(d_mean, 0.0, 1e-12);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_loglik_grad_bad_mean() {
+        NegativeBinomial::loglik_grad(&[1, 2], 0.0, 1.0);
+    }
 }
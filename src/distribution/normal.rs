@@ -1,4 +1,4 @@
-use crate::distribution::{ziggurat, Continuous, ContinuousCDF};
+use crate::distribution::{ziggurat, ApproxEq, Continuous, ContinuousCDF, MomentMatch};
 use crate::function::erf;
 use crate::statistics::*;
 use crate::{consts, Result, StatsError};
@@ -51,6 +51,143 @@ impl Normal {
             Ok(Normal { mean, std_dev })
         }
     }
+
+    /// Returns the 1-Wasserstein (earth mover's) distance between this and
+    /// the `other` normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// |Δσ| * sqrt(2 / π) * e^(-Δμ^2 / (2Δσ^2)) + Δμ * (1 - 2Φ(-Δμ / |Δσ|))
+    /// ```
+    ///
+    /// where `Δμ` and `Δσ` are the differences of the means and of the
+    /// standard deviations and `Φ` is the cdf of the standard normal
+    /// distribution. The distance reduces to `|Δμ|` if `Δσ = 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Normal;
+    ///
+    /// let a = Normal::new(0.0, 1.0).unwrap();
+    /// let b = Normal::new(3.0, 1.0).unwrap();
+    /// assert_eq!(a.wasserstein_1d(&b), 3.0);
+    /// ```
+    pub fn wasserstein_1d(&self, other: &Normal) -> f64 {
+        // the distance is E|Δμ + Δσ * Z| for a standard normal Z, the mean
+        // of a folded normal distribution
+        let d_mean = self.mean - other.mean;
+        let d_std_dev = (self.std_dev - other.std_dev).abs();
+        if d_std_dev == 0.0 {
+            return d_mean.abs();
+        }
+        let c = d_mean / d_std_dev;
+        d_std_dev * (2.0 / f64::consts::PI).sqrt() * (-c * c / 2.0).exp()
+            + d_mean * (1.0 - 2.0 * cdf_unchecked(-c, 0.0, 1.0))
+    }
+
+    /// Returns the Kullback-Leibler divergence `KL(self || other)` of the
+    /// `other` normal distribution from this one
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(σ_2 / σ_1) + (σ_1^2 + (μ_1 - μ_2)^2) / (2σ_2^2) - 1 / 2
+    /// ```
+    ///
+    /// where `μ_1`, `σ_1` are the mean and standard deviation of this
+    /// distribution and `μ_2`, `σ_2` those of `other`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Normal;
+    ///
+    /// let a = Normal::new(0.0, 1.0).unwrap();
+    /// let b = Normal::new(2.0, 1.0).unwrap();
+    /// assert_eq!(a.kl_divergence(&b), 2.0);
+    /// ```
+    pub fn kl_divergence(&self, other: &Normal) -> f64 {
+        let ratio = self.std_dev / other.std_dev;
+        let d_mean = (self.mean - other.mean) / other.std_dev;
+        -ratio.ln() + (ratio * ratio + d_mean * d_mean) / 2.0 - 0.5
+    }
+
+    /// Returns the two-sided prediction interval at the confidence level
+    /// `confidence` for a new observation predicted as `point_estimate`,
+    /// using the standard deviation of the distribution as the standard error
+    /// of prediction, e.g. for a fitted distribution of regression residuals
+    ///
+    /// # Panics
+    ///
+    /// If `confidence` is not in `(0, 1)`
+    ///
+    /// # Remarks
+    ///
+    /// The interval is centered on `point_estimate`, the mean of the
+    /// distribution is not used
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (x - z_(1 - α/2) * σ, x + z_(1 - α/2) * σ)
+    /// ```
+    ///
+    /// where `x` is the point estimate, `α = 1 - confidence`, `σ` is the
+    /// standard deviation and `z_p` is the `p`-quantile of the standard
+    /// normal distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Normal;
+    /// use statrs::prec;
+    ///
+    /// let n = Normal::new(0.0, 2.0).unwrap();
+    /// let (lower, upper) = n.prediction_interval(5.0, 0.95);
+    /// assert!(prec::almost_eq(lower, 5.0 - 2.0 * 1.959963984540054, 1e-12));
+    /// assert!(prec::almost_eq(upper, 5.0 + 2.0 * 1.959963984540054, 1e-12));
+    /// ```
+    pub fn prediction_interval(&self, point_estimate: f64, confidence: f64) -> (f64, f64) {
+        if !(confidence > 0.0 && confidence < 1.0) {
+            panic!("confidence must be in (0, 1)");
+        }
+        let half_width = super::std_normal_inverse_cdf(0.5 + 0.5 * confidence) * self.std_dev;
+        (point_estimate - half_width, point_estimate + half_width)
+    }
+}
+
+impl MomentMatch for Normal {
+    /// Constructs the normal distribution with a mean of `mean` and a
+    /// variance of `variance`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `mean` or `variance` are `NaN` or inf, or if
+    /// `variance <= 0.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ = mean, σ = sqrt(variance)
+    /// ```
+    fn from_mean_variance(mean: f64, variance: f64) -> Result<Normal> {
+        if !mean.is_finite() || !variance.is_finite() || variance <= 0.0 {
+            return Err(StatsError::BadParams);
+        }
+        Normal::new(mean, variance.sqrt())
+    }
+}
+
+impl ApproxEq for Normal {
+    fn approx_eq(&self, other: &Normal, tol: f64) -> bool {
+        super::internal::params_approx_eq(
+            &[self.mean, self.std_dev],
+            &[other.mean, other.std_dev],
+            tol,
+        )
+    }
 }
 
 impl ::rand::distributions::Distribution<f64> for Normal {
@@ -121,6 +258,30 @@ impl ContinuousCDF<f64, f64> for Normal {
             self.mean - (self.std_dev * f64::consts::SQRT_2 * erf::erfc_inv(2.0 * x))
         }
     }
+
+    /// Calculates the conditional value-at-risk of the normal distribution
+    /// at level `alpha`, i.e. the mean of its lower `alpha`-tail
+    ///
+    /// # Panics
+    ///
+    /// If `alpha <= 0.0`, `alpha >= 1.0` or `alpha` is `NaN`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ - σ * φ(Φ^-1(α)) / α
+    /// ```
+    ///
+    /// where `μ` is the mean, `σ` is the standard deviation, `α` is `alpha`
+    /// and `φ` and `Φ^-1` are the pdf and inverse cdf of the standard normal
+    /// distribution
+    fn conditional_value_at_risk(&self, alpha: f64) -> f64 {
+        if !(alpha > 0.0 && alpha < 1.0) {
+            panic!("alpha must be in (0, 1)");
+        }
+        let z = -f64::consts::SQRT_2 * erf::erfc_inv(2.0 * alpha);
+        self.mean - self.std_dev * pdf_unchecked(z, 0.0, 1.0) / alpha
+    }
 }
 
 impl Min<f64> for Normal {
@@ -194,6 +355,29 @@ impl Distribution<f64> for Normal {
     fn skewness(&self) -> Option<f64> {
         Some(0.0)
     }
+    /// Returns the excess kurtosis of the normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn excess_kurtosis(&self) -> Option<f64> {
+        Some(0.0)
+    }
+    /// Returns the center of symmetry of the normal distribution, i.e.
+    /// the mean
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ
+    /// ```
+    ///
+    /// where `μ` is the mean
+    fn symmetry_center(&self) -> Option<f64> {
+        Some(self.mean)
+    }
 }
 
 impl Median<f64> for Normal {
@@ -269,6 +453,24 @@ pub fn sf_unchecked(x: f64, mean: f64, std_dev: f64) -> f64 {
     0.5 * erf::erfc((x - mean) / (std_dev * f64::consts::SQRT_2))
 }
 
+/// performs an unchecked log(cdf) calculation for a normal distribution
+/// with the given mean and standard deviation at x. Far in the lower tail,
+/// where the cdf underflows, the asymptotic expansion of the Mills ratio
+/// is used instead
+pub fn ln_cdf_unchecked(x: f64, mean: f64, std_dev: f64) -> f64 {
+    let z = (x - mean) / std_dev;
+    if z > 0.0 {
+        (-sf_unchecked(z, 0.0, 1.0)).ln_1p()
+    } else if z > -30.0 {
+        cdf_unchecked(z, 0.0, 1.0).ln()
+    } else {
+        let r = 1.0 / (z * z);
+        let series =
+            1.0 - r * (1.0 - 3.0 * r * (1.0 - 5.0 * r * (1.0 - 7.0 * r * (1.0 - 9.0 * r))));
+        -0.5 * z * z - (-z).ln() - consts::LN_SQRT_2PI + series.ln()
+    }
+}
+
 /// performs an unchecked pdf calculation for a normal distribution
 /// with the given mean and standard deviation at x
 pub fn pdf_unchecked(x: f64, mean: f64, std_dev: f64) -> f64 {
@@ -292,7 +494,7 @@ pub fn sample_unchecked<R: Rng + ?Sized>(rng: &mut R, mean: f64, std_dev: f64) -
 #[cfg(all(test, feature = "nightly"))]
 mod tests {
     use crate::statistics::*;
-    use crate::distribution::{ContinuousCDF, Continuous, Normal};
+    use crate::distribution::{to_csv_string, ContinuousCDF, Continuous, MomentMatch, Normal};
     use crate::distribution::internal::*;
     use crate::consts::ACC;
 
@@ -356,6 +558,19 @@ mod tests {
         test_case(0.0, f64::INFINITY, f64::INFINITY, variance);
     }
 
+    #[test]
+    fn test_wasserstein_1d() {
+        let a = try_create(0.0, 1.0);
+        assert_eq!(a.wasserstein_1d(&a), 0.0);
+        assert_eq!(a.wasserstein_1d(&try_create(-2.5, 1.0)), 2.5);
+        // equal means give |Δσ| * E|Z|
+        let b = try_create(0.0, 3.0);
+        assert_almost_eq!(a.wasserstein_1d(&b), 2.0 * (2.0 / std::f64::consts::PI).sqrt(), 1e-15);
+        let c = try_create(1.0, 2.0);
+        assert_almost_eq!(a.wasserstein_1d(&c), c.wasserstein_1d(&a), 1e-15);
+        assert!(a.wasserstein_1d(&c) >= 1.0);
+    }
+
     #[test]
     fn test_entropy() {
         let entropy = |x: Normal| x.entropy().unwrap();
@@ -374,6 +589,29 @@ mod tests {
         test_case(0.0, f64::INFINITY, 0.0, skewness);
     }
 
+    #[test]
+    fn test_excess_kurtosis() {
+        let excess_kurtosis = |x: Normal| x.excess_kurtosis().unwrap();
+        test_case(0.0, 0.1, 0.0, excess_kurtosis);
+        test_case(4.0, 1.0, 0.0, excess_kurtosis);
+        test_case(0.0, f64::INFINITY, 0.0, excess_kurtosis);
+    }
+
+    #[test]
+    fn test_cumulants() {
+        assert_eq!(try_create(0.0, 1.0).cumulants().unwrap(), [0.0, 1.0, 0.0, 0.0]);
+        assert_eq!(try_create(-3.0, 2.5).cumulants().unwrap(), [-3.0, 6.25, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_symmetry_center() {
+        let center = |x: Normal| x.symmetry_center().unwrap();
+        test_case(0.0, 1.0, 0.0, center);
+        test_case(-5.0, 2.0, -5.0, center);
+        test_case(10.0, 0.1, 10.0, center);
+        assert!(try_create(1.0, 1.0).is_symmetric());
+    }
+
     #[test]
     fn test_mode() {
         let mode = |x: Normal| x.mode().unwrap();
@@ -385,6 +623,160 @@ mod tests {
         test_case(f64::INFINITY, 1.0, f64::INFINITY, mode);
     }
 
+    #[test]
+    fn test_mode_density() {
+        let mode_density = |x: Normal| x.mode_density().unwrap();
+        let peak = 1.0 / (2.0 * std::f64::consts::PI).sqrt();
+        test_almost(0.0, 1.0, peak, 1e-15, mode_density);
+        test_almost(5.0, 1.0, peak, 1e-15, mode_density);
+        test_almost(0.0, 2.0, peak / 2.0, 1e-15, mode_density);
+    }
+
+    #[test]
+    fn test_information_content() {
+        let n = try_create(1.0, 2.0);
+        for &x in &[-3.0, 0.0, 1.0, 2.5, 10.0] {
+            assert_almost_eq!(n.information_content(x), -n.pdf(x).ln(), 1e-12);
+        }
+        // the surprisal at the mean is the negative log of the peak density
+        assert_almost_eq!(n.information_content(1.0), (2.0 * (2.0 * std::f64::consts::PI).sqrt()).ln(), 1e-15);
+    }
+
+    #[test]
+    fn test_expected_information() {
+        for &(mean, std_dev) in &[(0.0, 1.0), (5.0, 0.1), (-2.0, 3.0)] {
+            let n = try_create(mean, std_dev);
+            // midpoint rule for E[-ln f(X)] over mean ± 12 std_dev
+            let steps = 100_000;
+            let h = 24.0 * std_dev / steps as f64;
+            let expected = (0..steps).fold(0.0, |acc, i| {
+                let x = mean - 12.0 * std_dev + (i as f64 + 0.5) * h;
+                acc + n.pdf(x) * n.information_content(x) * h
+            });
+            assert_almost_eq!(n.expected_information().unwrap(), expected, 1e-8);
+            assert_eq!(n.expected_information(), n.entropy());
+        }
+    }
+
+    // skew-normal density 2 φ(x) Φ(αx), whose mode has no closed form
+    struct SkewNormal {
+        alpha: f64,
+    }
+
+    impl Continuous<f64, f64> for SkewNormal {
+        fn pdf(&self, x: f64) -> f64 {
+            let n = Normal::new(0.0, 1.0).unwrap();
+            2.0 * n.pdf(x) * n.cdf(self.alpha * x)
+        }
+
+        fn ln_pdf(&self, x: f64) -> f64 {
+            self.pdf(x).ln()
+        }
+    }
+
+    #[test]
+    fn test_mode_numeric() {
+        for &(mean, std_dev) in &[(0.0, 1.0), (5.0, 0.1), (-2.0, 3.0)] {
+            let n = try_create(mean, std_dev);
+            let found = n.mode_numeric(mean - 10.0 * std_dev, mean + 7.0 * std_dev);
+            assert_almost_eq!(found, mean, 1e-6 * std_dev);
+        }
+        let n = try_create(1.0, 1.0);
+        assert_eq!(n.mode_numeric(4.0, 4.0), 4.0);
+        // the density is decreasing over an interval to the right of the mode
+        assert_almost_eq!(n.mode_numeric(2.0, 5.0), 2.0, 1e-6);
+    }
+
+    #[test]
+    fn test_mode_numeric_skew_normal() {
+        let symmetric = SkewNormal { alpha: 0.0 }.mode_numeric(-10.0, 10.0);
+        assert_almost_eq!(symmetric, 0.0, 1e-6);
+        let right = SkewNormal { alpha: 4.0 }.mode_numeric(-10.0, 10.0);
+        let left = SkewNormal { alpha: -4.0 }.mode_numeric(-10.0, 10.0);
+        assert!(right > 0.0);
+        assert_almost_eq!(left, -right, 1e-6);
+        // the mode of the skew-normal lies between 0 and the mean
+        let mean = 4.0 / 17f64.sqrt() * (2.0 / std::f64::consts::PI).sqrt();
+        assert!(right < mean);
+        let d = SkewNormal { alpha: 4.0 };
+        assert!(d.pdf(right) >= d.pdf(right - 1e-4));
+        assert!(d.pdf(right) >= d.pdf(right + 1e-4));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mode_numeric_bad_interval() {
+        try_create(0.0, 1.0).mode_numeric(1.0, -1.0);
+    }
+
+    #[test]
+    fn test_sample_sorted() {
+        use ::rand::rngs::StdRng;
+        use ::rand::SeedableRng;
+
+        let n = try_create(1.0, 2.0);
+        let mut r: StdRng = SeedableRng::seed_from_u64(29);
+        let samples = n.sample_sorted(&mut r, 1000);
+        assert_eq!(samples.len(), 1000);
+        assert!(samples.windows(2).all(|w| w[0] <= w[1]));
+        test::check_ks(&samples, |x| n.cdf(x));
+        assert!(n.sample_sorted(&mut r, 0).is_empty());
+    }
+
+    #[test]
+    fn test_sample_kth_order_statistic() {
+        use ::rand::rngs::StdRng;
+        use ::rand::SeedableRng;
+
+        let n = try_create(1.0, 2.0);
+        let mut r: StdRng = SeedableRng::seed_from_u64(31);
+        let (k, size, reps) = (15, 20, 4000);
+        let direct: Vec<f64> = (0..reps).map(|_| n.sample_kth_order_statistic(&mut r, k, size)).collect();
+        let sorted: Vec<f64> = (0..reps).map(|_| n.sample_sorted(&mut r, size as usize)[k as usize - 1]).collect();
+        let mean = |v: &[f64]| v.iter().sum::<f64>() / v.len() as f64;
+        let var = |v: &[f64]| {
+            let m = mean(v);
+            v.iter().map(|x| (x - m) * (x - m)).sum::<f64>() / (v.len() - 1) as f64
+        };
+        // the two estimates of the mean agree within about five standard errors
+        let se = ((var(&direct) + var(&sorted)) / reps as f64).sqrt();
+        assert!((mean(&direct) - mean(&sorted)).abs() < 5.0 * se);
+        assert!((var(&direct) / var(&sorted) - 1.0).abs() < 0.15);
+
+        // with a single sample the order statistic is a plain sample
+        let samples: Vec<f64> = (0..2000).map(|_| n.sample_kth_order_statistic(&mut r, 1, 1)).collect();
+        test::check_ks(&samples, |x| n.cdf(x));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sample_kth_order_statistic_k_zero() {
+        try_create(0.0, 1.0).sample_kth_order_statistic(&mut rand::thread_rng(), 0, 5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sample_kth_order_statistic_k_gt_n() {
+        try_create(0.0, 1.0).sample_kth_order_statistic(&mut rand::thread_rng(), 6, 5);
+    }
+
+    #[test]
+    fn test_probability_beyond_sigma() {
+        let beyond = |k: f64| move |x: Normal| x.probability_beyond_sigma(k).unwrap();
+        // the familiar 68-95-99.7 rule
+        test_almost(0.0, 1.0, 0.31731050786291410, 1e-10, beyond(1.0));
+        test_almost(5.0, 2.0, 0.045500263896358417, 1e-10, beyond(2.0));
+        test_almost(-3.0, 0.5, 0.0026997960632601866, 1e-12, beyond(3.0));
+        test_almost(0.0, 1.0, 6.3342483666239957e-5, 1e-14, beyond(4.0));
+        test_case(1.0, 3.0, 1.0, beyond(0.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_probability_beyond_sigma_negative_k() {
+        try_create(0.0, 1.0).probability_beyond_sigma(-1.0);
+    }
+
     #[test]
     fn test_median() {
         let median = |x: Normal| x.median();
@@ -475,6 +867,26 @@ mod tests {
         test_almost(5.0, 2.0, 0.993790334674, 1e-12, cdf(10.0));
     }
 
+    #[test]
+    fn test_ln_cdf_unchecked() {
+        let n = try_create(5.0, 2.0);
+        for &x in &[-50.0, -10.0, 0.0, 4.0, 5.0, 6.0, 10.0, 20.0] {
+            assert_almost_eq!(super::ln_cdf_unchecked(x, 5.0, 2.0), n.cdf(x).ln(), 1e-12);
+        }
+        assert_almost_eq!(super::ln_cdf_unchecked(-30.5, 0.0, 1.0), -469.4627373229121143867, 1e-12);
+        assert_almost_eq!(super::ln_cdf_unchecked(-40.0, 0.0, 1.0), -804.6084420137537881666, 1e-12);
+        assert_almost_eq!(super::ln_cdf_unchecked(-100.0, 0.0, 1.0), -5005.524208694205088626, 1e-11);
+        assert_almost_eq!(super::ln_cdf_unchecked(-1e5, 0.0, 1.0), -5000000012.431863998275, 1e-5);
+    }
+
+    #[test]
+    fn test_cdf_numeric_far_from_zero() {
+        let n = try_create(1e6, 1.0);
+        for &x in &[1e6 - 3.0, 1e6 - 0.5, 1e6, 1e6 + 1.0, 2e6] {
+            assert_almost_eq!(n.cdf_numeric(x), n.cdf(x), 1e-8);
+        }
+    }
+
     #[test]
     fn test_sf() {
         let sf = |arg: f64| move |x: Normal| x.sf(arg);
@@ -488,12 +900,33 @@ mod tests {
         test_almost(5.0, 2.0, 0.006209665325512148, 1e-12, sf(10.0));
     }
 
+    #[test]
+    fn test_from_mean_variance() {
+        for &(mean, variance) in &[(0.0, 1.0), (-3.0, 4.0), (100.0, 0.01)] {
+            let n = Normal::from_mean_variance(mean, variance).unwrap();
+            assert_eq!(n.mean().unwrap(), mean);
+            assert_almost_eq!(n.variance().unwrap(), variance, 1e-15);
+        }
+        assert!(Normal::from_mean_variance(0.0, 0.0).is_err());
+        assert!(Normal::from_mean_variance(0.0, -1.0).is_err());
+        assert!(Normal::from_mean_variance(f64::NAN, 1.0).is_err());
+        assert!(Normal::from_mean_variance(f64::INFINITY, 1.0).is_err());
+    }
+
     #[test]
     fn test_continuous() {
         test::check_continuous_distribution(&try_create(0.0, 1.0), -10.0, 10.0);
         test::check_continuous_distribution(&try_create(20.0, 0.5), 10.0, 30.0);
     }
 
+    #[test]
+    fn test_inverse_cdf_round_trip() {
+        let probs = [1e-4, 0.01, 0.1, 0.25, 0.5, 0.75, 0.9, 0.99, 0.9999];
+        test::check_inverse_cdf(&try_create(0.0, 1.0), &probs, 1e-9);
+        test::check_inverse_cdf(&try_create(20.0, 0.5), &probs, 1e-9);
+        test::check_inverse_cdf(&try_create(-5.0, 100.0), &probs, 1e-7);
+    }
+
     #[test]
     fn test_inverse_cdf() {
         let inverse_cdf = |arg: f64| move |x: Normal| x.inverse_cdf(arg);
@@ -508,4 +941,269 @@ mod tests {
         test_almost(5.0, 2.0, 10.0, 1e-14, inverse_cdf(0.9937903346742238648330218954258077788721022530769078));
         test_case(5.0, 2.0, f64::INFINITY, inverse_cdf(1.0));
     }
+
+    #[test]
+    fn test_to_from_uniform() {
+        let n = try_create(5.0, 2.0);
+        for &x in &[-1.0, 2.0, 5.0, 6.5, 11.0] {
+            assert_eq!(n.cdf(x), n.to_uniform(x));
+            assert_almost_eq!(x, n.from_uniform(n.to_uniform(x)), 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_pit() {
+        use ::rand::distributions::Distribution;
+        use ::rand::rngs::StdRng;
+        use ::rand::SeedableRng;
+
+        let n = try_create(5.0, 2.0);
+        let mut r: StdRng = SeedableRng::seed_from_u64(1);
+        let samples: Vec<f64> = (0..10_000).map(|_| n.sample(&mut r)).collect();
+        let u = n.pit(&samples);
+        assert!(u.iter().all(|&x| (0.0..=1.0).contains(&x)));
+        test::check_ks(&u, |x| x);
+    }
+
+    #[test]
+    fn test_sample_truncated() {
+        use ::rand::rngs::StdRng;
+        use ::rand::SeedableRng;
+
+        let n = try_create(1.0, 2.0);
+        let (lower, upper) = (1.5, 4.0);
+        let mut r: StdRng = SeedableRng::seed_from_u64(3);
+        let samples: Vec<f64> = (0..20_000)
+            .map(|_| n.sample_truncated(&mut r, lower, upper).unwrap())
+            .collect();
+        assert!(samples.iter().all(|&x| lower <= x && x <= upper));
+
+        // mean of the truncated density by the midpoint rule
+        let steps = 10_000;
+        let step = (upper - lower) / steps as f64;
+        let expected = (0..steps)
+            .map(|i| lower + (i as f64 + 0.5) * step)
+            .map(|x| x * n.pdf(x) * step)
+            .sum::<f64>() / (n.cdf(upper) - n.cdf(lower));
+        let mean = samples.iter().sum::<f64>() / samples.len() as f64;
+        assert_almost_eq!(expected, mean, 0.01);
+    }
+
+    #[test]
+    fn test_bad_sample_truncated() {
+        let n = try_create(0.0, 1.0);
+        let mut r = ::rand::thread_rng();
+        assert!(n.sample_truncated(&mut r, 1.0, 1.0).is_err());
+        assert!(n.sample_truncated(&mut r, 1.0, -1.0).is_err());
+        assert!(n.sample_truncated(&mut r, f64::NAN, 1.0).is_err());
+        assert!(n.sample_truncated(&mut r, 100.0, 200.0).is_err());
+    }
+
+    #[test]
+    fn test_sample_stratified() {
+        use ::rand::rngs::StdRng;
+        use ::rand::SeedableRng;
+
+        let n = try_create(2.0, 3.0);
+        let mut r: StdRng = SeedableRng::seed_from_u64(17);
+        let samples = n.sample_stratified(&mut r, 100);
+        assert_eq!(samples.len(), 100);
+        for (i, &x) in samples.iter().enumerate() {
+            assert!(n.inverse_cdf(i as f64 / 100.0) <= x);
+            assert!(x <= n.inverse_cdf((i + 1) as f64 / 100.0));
+        }
+    }
+
+    #[test]
+    fn test_sample_stratified_variance() {
+        use ::rand::distributions::Distribution;
+        use ::rand::rngs::StdRng;
+        use ::rand::SeedableRng;
+
+        // compare the spread of estimates of E[exp(X)] over many repetitions
+        let n = try_create(0.0, 1.0);
+        let mut r: StdRng = SeedableRng::seed_from_u64(19);
+        let estimate = |xs: Vec<f64>| xs.iter().map(|x| x.exp()).sum::<f64>() / xs.len() as f64;
+        let variance = |estimates: Vec<f64>| {
+            let mean = estimates.iter().sum::<f64>() / estimates.len() as f64;
+            estimates.iter().map(|e| (e - mean) * (e - mean)).sum::<f64>() / estimates.len() as f64
+        };
+        let plain: Vec<f64> = (0..500)
+            .map(|_| estimate((0..50).map(|_| n.sample(&mut r)).collect()))
+            .collect();
+        let stratified: Vec<f64> = (0..500)
+            .map(|_| estimate(n.sample_stratified(&mut r, 50)))
+            .collect();
+        assert!(variance(stratified) < variance(plain) / 5.0);
+    }
+
+    #[test]
+    fn test_conditional_value_at_risk() {
+        let n = try_create(0.0, 1.0);
+        assert_almost_eq!(n.conditional_value_at_risk(0.05), -2.0627128075074257, 1e-12);
+        assert_almost_eq!(n.conditional_value_at_risk(0.5), -0.7978845608028654, 1e-12);
+        let n = try_create(3.0, 2.0);
+        assert_almost_eq!(n.conditional_value_at_risk(0.05), 3.0 - 2.0 * 2.0627128075074257, 1e-12);
+    }
+
+    #[test]
+    fn test_conditional_value_at_risk_numeric() {
+        use crate::statistics::{Max, Min};
+
+        // forwards everything but conditional_value_at_risk so the
+        // generic numerical integration is used
+        struct Generic(Normal);
+        impl Min<f64> for Generic {
+            fn min(&self) -> f64 { self.0.min() }
+        }
+        impl Max<f64> for Generic {
+            fn max(&self) -> f64 { self.0.max() }
+        }
+        impl ContinuousCDF<f64, f64> for Generic {
+            fn cdf(&self, x: f64) -> f64 { self.0.cdf(x) }
+            fn sf(&self, x: f64) -> f64 { self.0.sf(x) }
+            fn inverse_cdf(&self, p: f64) -> f64 { self.0.inverse_cdf(p) }
+        }
+
+        for &(mean, std_dev) in &[(0.0, 1.0), (-2.0, 0.5), (10.0, 4.0)] {
+            let n = try_create(mean, std_dev);
+            for &alpha in &[0.01, 0.05, 0.3, 0.9] {
+                let numeric = Generic(n).conditional_value_at_risk(alpha);
+                assert_almost_eq!(numeric, n.conditional_value_at_risk(alpha), 1e-4 * std_dev);
+            }
+        }
+    }
+
+    #[test]
+    fn test_value_at_risk() {
+        let n = try_create(0.0, 1.0);
+        assert_almost_eq!(n.value_at_risk(0.05), -1.6448536269514729, 1e-12);
+        assert_eq!(n.value_at_risk(0.5), 0.0);
+
+        // the expected shortfall is the mean of the tail below the
+        // value-at-risk
+        let n = try_create(2.0, 3.0);
+        for &alpha in &[0.01, 0.05, 0.25] {
+            let var = n.value_at_risk(alpha);
+            let cvar = n.conditional_value_at_risk(alpha);
+            assert!(cvar < var);
+            // standard normal density at the standardized value-at-risk
+            let phi = 3.0 * n.pdf(var);
+            let expected = 2.0 - 3.0 * phi / alpha;
+            assert_almost_eq!(cvar, expected, 1e-10);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_conditional_value_at_risk_alpha_zero() {
+        try_create(0.0, 1.0).conditional_value_at_risk(0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_conditional_value_at_risk_alpha_one() {
+        try_create(0.0, 1.0).conditional_value_at_risk(1.0);
+    }
+
+    #[test]
+    fn test_kl_divergence() {
+        let a = try_create(0.0, 1.0);
+        let b = try_create(1.0, 2.0);
+        assert_almost_eq!(a.kl_divergence(&b), 2f64.ln() - 0.25, 1e-15);
+        assert_almost_eq!(b.kl_divergence(&a), 2.0 - 2f64.ln(), 1e-15);
+        assert_eq!(a.kl_divergence(&a), 0.0);
+        assert_eq!(b.kl_divergence(&b), 0.0);
+        // the divergence is invariant under a common shift and scaling
+        let c = try_create(5.0, 3.0);
+        let d = try_create(8.0, 6.0);
+        assert_almost_eq!(c.kl_divergence(&d), a.kl_divergence(&b), 1e-15);
+    }
+
+    #[test]
+    fn test_prediction_interval() {
+        let n = try_create(4.0, 2.0);
+        let (lower, upper) = n.prediction_interval(10.0, 0.95);
+        assert_almost_eq!(upper - lower, 2.0 * 2.0 * 1.959963984540054, 1e-12);
+        assert_almost_eq!(lower + upper, 20.0, 1e-12);
+        // the interval matches the quantiles of a normal centered on the estimate
+        let m = try_create(10.0, 2.0);
+        assert_almost_eq!(lower, m.inverse_cdf(0.025), 1e-9);
+        assert_almost_eq!(upper, m.inverse_cdf(0.975), 1e-9);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_prediction_interval_bad_confidence() {
+        try_create(0.0, 1.0).prediction_interval(0.0, 0.0);
+    }
+
+    #[test]
+    fn test_return_level() {
+        let n = try_create(10.0, 2.0);
+        assert_eq!(n.return_level(100.0), n.inverse_cdf(0.99));
+        assert_eq!(n.return_level(2.0), 10.0);
+        assert!(n.return_level(1000.0) > n.return_level(100.0));
+        assert_eq!(n.return_level(f64::INFINITY), f64::INFINITY);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_return_level_bad_period() {
+        try_create(10.0, 2.0).return_level(1.0);
+    }
+
+    #[test]
+    fn test_five_number_summary() {
+        let (p5, p25, median, p75, p95) = try_create(0.0, 1.0).five_number_summary();
+        assert_eq!(median, 0.0);
+        assert_almost_eq!(p25, -0.6744897501960817, 1e-14);
+        assert_almost_eq!(p75, 0.6744897501960817, 1e-14);
+        assert_almost_eq!(p5, -1.6448536269514729, 1e-14);
+        assert_almost_eq!(p95, 1.6448536269514729, 1e-14);
+
+        let n = try_create(3.0, 2.0);
+        let (p5, p25, median, p75, p95) = n.five_number_summary();
+        assert_eq!(median, 3.0);
+        assert_eq!(p5, n.inverse_cdf(0.05));
+        assert_eq!(p25, n.inverse_cdf(0.25));
+        assert_eq!(p75, n.inverse_cdf(0.75));
+        assert_eq!(p95, n.inverse_cdf(0.95));
+    }
+
+    #[test]
+    fn test_quantile_table() {
+        let n = try_create(1.0, 2.0);
+        let probs = [0.0, 0.1, 0.5, 0.975, 1.0];
+        let table = n.quantile_table(&probs);
+        assert_eq!(table.len(), probs.len());
+        for (&(p, x), &q) in table.iter().zip(probs.iter()) {
+            assert_eq!(p, q);
+            assert_eq!(x, n.inverse_cdf(q));
+        }
+        assert_eq!(table[0].1, f64::NEG_INFINITY);
+        assert_eq!(table[2].1, 1.0);
+    }
+
+    #[test]
+    fn test_quantile_table_csv() {
+        let n = try_create(0.0, 1.0);
+        let table = n.quantile_table(&[0.5, 0.975]);
+        let csv = to_csv_string(&table);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], "p,quantile");
+        assert_eq!(lines[1], "0.5,0");
+        let fields: Vec<&str> = lines[2].split(',').collect();
+        assert_eq!(fields[0], "0.975");
+        assert_eq!(fields[1].parse::<f64>().unwrap(), n.inverse_cdf(0.975));
+        assert!(csv.ends_with('\n'));
+        assert_eq!(to_csv_string::<f64, f64>(&[]), "p,quantile\n");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_quantile_table_bad_prob() {
+        try_create(0.0, 1.0).quantile_table(&[0.5, 1.5]);
+    }
 }
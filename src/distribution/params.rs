@@ -0,0 +1,77 @@
+//! Newtypes for distribution parameters that are easily confused, so that
+//! swapped arguments are caught by the compiler. Only available with the
+//! `typed_params` feature.
+
+/// A rate parameter, the reciprocal of a [`Scale`]
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Gamma, Rate};
+///
+/// let n = Gamma::new_typed(3.0, Rate(2.0)).unwrap();
+/// assert_eq!(n.rate(), 2.0);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct Rate(pub f64);
+
+/// A scale parameter, the reciprocal of a [`Rate`]
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Gamma, Scale};
+///
+/// let n = Gamma::from_scale(3.0, Scale(0.5)).unwrap();
+/// assert_eq!(n.rate(), 2.0);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct Scale(pub f64);
+
+impl From<Scale> for Rate {
+    fn from(scale: Scale) -> Rate {
+        Rate(scale.0.recip())
+    }
+}
+
+impl From<Rate> for Scale {
+    fn from(rate: Rate) -> Scale {
+        Scale(rate.0.recip())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distribution::{Exp, Gamma};
+
+    #[test]
+    fn test_conversion() {
+        assert_eq!(Rate::from(Scale(0.25)), Rate(4.0));
+        assert_eq!(Scale::from(Rate(4.0)), Scale(0.25));
+    }
+
+    #[test]
+    fn test_gamma_typed_matches_untyped() {
+        for &(shape, rate) in &[(1.0, 0.1), (2.5, 1.0), (10.0, 4.0)] {
+            let n = Gamma::new(shape, rate).unwrap();
+            assert_eq!(Gamma::new_typed(shape, Rate(rate)).unwrap(), n);
+            assert_eq!(Gamma::from_scale(shape, Scale(1.0 / rate)).unwrap(), n);
+        }
+        assert!(Gamma::new_typed(0.0, Rate(1.0)).is_err());
+        assert!(Gamma::new_typed(1.0, Rate(-1.0)).is_err());
+        assert!(Gamma::from_scale(1.0, Scale(0.0)).is_err());
+        assert!(Gamma::from_scale(1.0, Scale(f64::NAN)).is_err());
+    }
+
+    #[test]
+    fn test_exp_typed_matches_untyped() {
+        for &rate in &[0.1, 1.0, 4.0] {
+            let n = Exp::new(rate).unwrap();
+            assert_eq!(Exp::new_typed(Rate(rate)).unwrap(), n);
+            assert_eq!(Exp::from_scale(Scale(1.0 / rate)).unwrap(), n);
+        }
+        assert!(Exp::new_typed(Rate(0.0)).is_err());
+        assert!(Exp::from_scale(Scale(-2.0)).is_err());
+    }
+}
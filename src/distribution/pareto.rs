@@ -1,4 +1,4 @@
-use crate::distribution::{Continuous, ContinuousCDF};
+use crate::distribution::{ApproxEq, Continuous, ContinuousCDF};
 use crate::statistics::*;
 use crate::{Result, StatsError};
 use rand::distributions::OpenClosed01;
@@ -83,6 +83,16 @@ impl Pareto {
     }
 }
 
+impl ApproxEq for Pareto {
+    fn approx_eq(&self, other: &Pareto, tol: f64) -> bool {
+        super::internal::params_approx_eq(
+            &[self.scale, self.shape],
+            &[other.scale, other.shape],
+            tol,
+        )
+    }
+}
+
 impl ::rand::distributions::Distribution<f64> for Pareto {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
         // Inverse transform sampling
@@ -246,6 +256,27 @@ impl Distribution<f64> for Pareto {
             )
         }
     }
+    /// Returns the excess kurtosis of the Pareto distribution
+    ///
+    /// # None
+    ///
+    /// If `α <= 4`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 6(α^3 + α^2 - 6α - 2) / (α(α - 3)(α - 4))
+    /// ```
+    ///
+    /// where `α` is the shape
+    fn excess_kurtosis(&self) -> Option<f64> {
+        if self.shape <= 4.0 {
+            None
+        } else {
+            let a = self.shape;
+            Some(6.0 * (a * a * a + a * a - 6.0 * a - 2.0) / (a * (a - 3.0) * (a - 4.0)))
+        }
+    }
 }
 
 impl Median<f64> for Pareto {
@@ -430,6 +461,14 @@ mod tests {
         get_value(1.0, 3.0, skewness);
     }
 
+    #[test]
+    fn test_excess_kurtosis() {
+        let excess_kurtosis = |x: Pareto| x.excess_kurtosis().unwrap();
+        test_almost(1.0, 5.0, 70.8, 1e-12, excess_kurtosis);
+        test_almost(3.0, 10.0, 14.828571428571428571, 1e-13, excess_kurtosis);
+        assert!(try_create(1.0, 4.0).excess_kurtosis().is_none());
+    }
+
     #[test]
     fn test_mode() {
         let mode = |x: Pareto| x.mode().unwrap();
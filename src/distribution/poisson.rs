@@ -1,4 +1,5 @@
-use crate::distribution::{Discrete, DiscreteCDF};
+use crate::distribution::gamma::gamma_lr_inv;
+use crate::distribution::{ApproxEq, Discrete, DiscreteCDF};
 use crate::function::{factorial, gamma};
 use crate::statistics::*;
 use crate::{Result, StatsError};
@@ -65,6 +66,193 @@ impl Poisson {
     pub fn lambda(&self) -> f64 {
         self.lambda
     }
+
+    /// Returns the derivative of the total log-likelihood of the samples in
+    /// `data` with respect to the rate `λ`. The derivative vanishes at the
+    /// maximum likelihood estimate, the sample mean.
+    ///
+    /// # Panics
+    ///
+    /// If `lambda` is `NaN`, infinite or `lambda <= 0.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ x_i / λ - n
+    /// ```
+    ///
+    /// where `n` is the number of samples
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Poisson;
+    ///
+    /// let data = [1, 2, 6];
+    /// assert_eq!(Poisson::loglik_grad(&data, 3.0), 0.0);
+    /// assert_eq!(Poisson::loglik_grad(&data, 1.5), 3.0);
+    /// ```
+    pub fn loglik_grad(data: &[u64], lambda: f64) -> f64 {
+        if !lambda.is_finite() || lambda <= 0.0 {
+            panic!("lambda must be finite and positive");
+        }
+        let sum = data.iter().sum::<u64>() as f64;
+        sum / lambda - data.len() as f64
+    }
+
+    /// Returns the exact (Garwood) two-sided confidence interval for the
+    /// rate `λ` of a Poisson distribution from which `observed` events were
+    /// counted, at the confidence level `confidence`
+    ///
+    /// # Panics
+    ///
+    /// If `confidence` is not in `(0, 1)`
+    ///
+    /// # Remarks
+    ///
+    /// The lower bound is `0` if `observed == 0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (χ²_(α/2)(2k) / 2, χ²_(1 - α/2)(2k + 2) / 2)
+    /// ```
+    ///
+    /// where `k` is the observed count, `α = 1 - confidence` and `χ²_p(ν)`
+    /// is the `p`-quantile of the chi-squared distribution with `ν` degrees
+    /// of freedom
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Poisson;
+    /// use statrs::prec;
+    ///
+    /// let (lower, upper) = Poisson::rate_confidence_interval(10, 0.95);
+    /// assert!(prec::almost_eq(lower, 4.795389, 1e-6));
+    /// assert!(prec::almost_eq(upper, 18.390356, 1e-6));
+    /// ```
+    pub fn rate_confidence_interval(observed: u64, confidence: f64) -> (f64, f64) {
+        if !(confidence > 0.0 && confidence < 1.0) {
+            panic!("confidence must be in (0, 1)");
+        }
+        let alpha = 1.0 - confidence;
+        let k = observed as f64;
+        // half a chi-squared quantile with 2k degrees of freedom is the
+        // quantile of a gamma distribution with shape k and unit rate
+        let lower = if observed == 0 {
+            0.0
+        } else {
+            gamma_lr_inv(k, alpha / 2.0)
+        };
+        let upper = gamma_lr_inv(k + 1.0, 1.0 - alpha / 2.0);
+        (lower, upper)
+    }
+
+    /// Returns the distribution of the number of events kept when each event
+    /// is independently kept with probability `q`, i.e. the thinned process,
+    /// which is poisson with a rate of `q * λ`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `q` is `NaN` or not in `[0, 1]`, or if `q == 0.0`
+    /// since the resulting degenerate distribution with a rate of `0` is not
+    /// a valid poisson distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Poisson;
+    ///
+    /// let n = Poisson::new(10.0).unwrap();
+    /// assert_eq!(n.thin(0.5).unwrap(), Poisson::new(5.0).unwrap());
+    /// assert!(n.thin(1.5).is_err());
+    /// ```
+    pub fn thin(&self, q: f64) -> Result<Poisson> {
+        if !(0.0..=1.0).contains(&q) {
+            Err(StatsError::BadParams)
+        } else {
+            Poisson::new(q * self.lambda)
+        }
+    }
+
+    /// Simulates a homogeneous Poisson process with rate `λ` per unit length
+    /// on `interval = (a, b)` and returns the sorted event locations. The
+    /// number of events is drawn from `Poisson(λ * (b - a))` and, given
+    /// their number, the events are placed independently and uniformly on
+    /// the interval.
+    ///
+    /// # Panics
+    ///
+    /// If `a` or `b` are not finite or `a > b`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Poisson;
+    ///
+    /// let n = Poisson::new(2.0).unwrap();
+    /// let events = n.sample_process_on((-1.0, 4.0), &mut rand::thread_rng());
+    /// assert!(events.iter().all(|&x| (-1.0..=4.0).contains(&x)));
+    /// assert!(events.windows(2).all(|w| w[0] <= w[1]));
+    /// ```
+    pub fn sample_process_on<R: Rng + ?Sized>(
+        &self,
+        interval: (f64, f64),
+        rng: &mut R,
+    ) -> Vec<f64> {
+        let (a, b) = interval;
+        if !a.is_finite() || !b.is_finite() || a > b {
+            panic!("interval must be finite with a <= b");
+        }
+        if a == b {
+            return Vec::new();
+        }
+        let count = sample_unchecked(rng, self.lambda * (b - a)) as usize;
+        let mut events: Vec<f64> = (0..count)
+            .map(|_| (a + (b - a) * rng.gen::<f64>()).min(b))
+            .collect();
+        events.sort_by(|x, y| x.partial_cmp(y).unwrap());
+        events
+    }
+
+    /// Evaluates the log probability mass function at each of `ks`, writing
+    /// the results into `out`. This is equivalent to calling `ln_pmf` on
+    /// every element but only computes `ln(λ)` once, which pays off when the
+    /// same support is evaluated for many parameter values.
+    ///
+    /// # Panics
+    ///
+    /// If `ks` and `out` do not have the same length
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Discrete, Poisson};
+    ///
+    /// let n = Poisson::new(2.5).unwrap();
+    /// let ks = [0, 1, 5, 100];
+    /// let mut out = [0.0; 4];
+    /// n.ln_pmf_slice(&ks, &mut out);
+    /// for (&k, &x) in ks.iter().zip(out.iter()) {
+    ///     assert_eq!(x, n.ln_pmf(k));
+    /// }
+    /// ```
+    pub fn ln_pmf_slice(&self, ks: &[u64], out: &mut [f64]) {
+        if ks.len() != out.len() {
+            panic!("ks and out must have the same length");
+        }
+        let ln_lambda = self.lambda.ln();
+        for (o, &k) in out.iter_mut().zip(ks) {
+            *o = -self.lambda + k as f64 * ln_lambda - factorial::ln_factorial(k);
+        }
+    }
+}
+
+impl ApproxEq for Poisson {
+    fn approx_eq(&self, other: &Poisson, tol: f64) -> bool {
+        super::internal::params_approx_eq(&[self.lambda], &[other.lambda], tol)
+    }
 }
 
 impl ::rand::distributions::Distribution<f64> for Poisson {
@@ -190,6 +378,18 @@ impl Distribution<f64> for Poisson {
     fn skewness(&self) -> Option<f64> {
         Some(1.0 / self.lambda.sqrt())
     }
+    /// Returns the excess kurtosis of the poisson distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 / λ
+    /// ```
+    ///
+    /// where `λ` is the rate
+    fn excess_kurtosis(&self) -> Option<f64> {
+        Some(1.0 / self.lambda)
+    }
 }
 
 impl Median<f64> for Poisson {
@@ -387,6 +587,13 @@ mod tests {
         test_almost(10.8, 0.3042903097250922852539, 1e-16, skewness);
     }
 
+    #[test]
+    fn test_excess_kurtosis() {
+        let excess_kurtosis = |x: Poisson| x.excess_kurtosis().unwrap();
+        test_almost(1.5, 0.66666666666666666667, 1e-15, excess_kurtosis);
+        test_almost(5.4, 0.185185185185185173, 1e-15, excess_kurtosis);
+    }
+
     #[test]
     fn test_median() {
         let median = |x: Poisson| x.median();
@@ -403,6 +610,133 @@ mod tests {
         test_case(10.8, 10, mode);
     }
 
+    #[test]
+    fn test_rate_confidence_interval() {
+        let (lower, upper) = Poisson::rate_confidence_interval(10, 0.95);
+        assert_almost_eq!(lower, 4.795389, 1e-6);
+        assert_almost_eq!(upper, 18.390356, 1e-6);
+        let (lower, upper) = Poisson::rate_confidence_interval(1, 0.9);
+        assert_almost_eq!(lower, 0.051293, 1e-6);
+        assert_almost_eq!(upper, 4.743865, 1e-6);
+        // the bounds are where the observed count becomes too unlikely
+        let (lower, upper) = Poisson::rate_confidence_interval(7, 0.99);
+        assert_almost_eq!(Poisson::new(lower).unwrap().sf(6), 0.005, 1e-12);
+        assert_almost_eq!(Poisson::new(upper).unwrap().cdf(7), 0.005, 1e-12);
+    }
+
+    #[test]
+    fn test_rate_confidence_interval_zero_count() {
+        // the upper bound is -ln(α / 2)
+        let (lower, upper) = Poisson::rate_confidence_interval(0, 0.95);
+        assert_eq!(lower, 0.0);
+        assert_almost_eq!(upper, -(0.025f64).ln(), 1e-12);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rate_confidence_interval_bad_confidence() {
+        Poisson::rate_confidence_interval(3, 1.0);
+    }
+
+    #[test]
+    fn test_mode_density() {
+        let mode_density = |x: Poisson| x.mode_density().unwrap();
+        test_almost(1.5, 0.33469524022264474, 1e-15, mode_density);
+        test_almost(5.4, 0.17282133307605735, 1e-15, mode_density);
+    }
+
+    #[test]
+    fn test_thin() {
+        let n = try_create(10.0);
+        let thinned = n.thin(0.3).unwrap();
+        assert_almost_eq!(thinned.lambda(), 3.0, 1e-15);
+        assert_almost_eq!(thinned.mean().unwrap(), 3.0, 1e-15);
+        assert_almost_eq!(thinned.variance().unwrap(), 3.0, 1e-15);
+        assert_eq!(n.thin(1.0).unwrap(), n);
+        assert!(n.thin(0.0).is_err());
+        assert!(n.thin(-0.1).is_err());
+        assert!(n.thin(1.1).is_err());
+        assert!(n.thin(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_thin_matches_simulation() {
+        use ::rand::distributions::Distribution;
+        use ::rand::rngs::StdRng;
+        use ::rand::{Rng, SeedableRng};
+
+        let n = try_create(10.0);
+        let thinned = n.thin(0.3).unwrap();
+        let mut r: StdRng = SeedableRng::seed_from_u64(11);
+        let trials = 20_000;
+        let total: f64 = (0..trials)
+            .map(|_| {
+                let events = n.sample(&mut r) as u64;
+                (0..events).filter(|_| r.gen::<f64>() < 0.3).count() as f64
+            })
+            .sum();
+        // within about five standard errors of the thinned mean
+        let se = (thinned.variance().unwrap() / trials as f64).sqrt();
+        assert!((total / trials as f64 - thinned.mean().unwrap()).abs() < 5.0 * se);
+    }
+
+    #[test]
+    fn test_sample_into() {
+        use ::rand::rngs::StdRng;
+        use ::rand::SeedableRng;
+
+        let n = try_create(4.5);
+        let mut r: StdRng = SeedableRng::seed_from_u64(23);
+        let mut out = vec![-1.0; 20_000];
+        n.sample_into(&mut r, &mut out);
+        assert!(out.iter().all(|&x| x >= 0.0 && x.fract() == 0.0));
+        let mean = out.iter().sum::<f64>() / out.len() as f64;
+        let se = (n.variance().unwrap() / out.len() as f64).sqrt();
+        assert!((mean - 4.5).abs() < 5.0 * se);
+    }
+
+    #[test]
+    fn test_sample_process_on() {
+        use ::rand::rngs::StdRng;
+        use ::rand::SeedableRng;
+
+        let n = try_create(1.5);
+        let (a, b) = (-2.0, 4.0);
+        let expected = try_create(1.5 * (b - a));
+        let mut r: StdRng = SeedableRng::seed_from_u64(47);
+        let reps = 5000;
+        let mut counts = vec![0usize; 40];
+        let mut positions = Vec::new();
+        for _ in 0..reps {
+            let events = n.sample_process_on((a, b), &mut r);
+            assert!(events.windows(2).all(|w| w[0] <= w[1]));
+            assert!(events.iter().all(|&x| a <= x && x <= b));
+            counts[events.len().min(39)] += 1;
+            positions.extend(events);
+        }
+        // the count frequencies match Poisson(λ * (b - a)) within about five
+        // standard deviations
+        for (k, &c) in counts.iter().enumerate().take(25) {
+            let p = expected.pmf(k as u64);
+            let sd = (reps as f64 * p * (1.0 - p)).sqrt();
+            assert!((c as f64 - reps as f64 * p).abs() <= 5.0 * sd + 1.0);
+        }
+        // the event locations are uniform on the interval
+        test::check_ks(&positions, |x| ((x - a) / (b - a)).clamp(0.0, 1.0));
+    }
+
+    #[test]
+    fn test_sample_process_on_empty_interval() {
+        let n = try_create(100.0);
+        assert!(n.sample_process_on((3.0, 3.0), &mut rand::thread_rng()).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sample_process_on_bad_interval() {
+        try_create(1.0).sample_process_on((1.0, 0.0), &mut rand::thread_rng());
+    }
+
     #[test]
     fn test_min_max() {
         let min = |x: Poisson| x.min();
@@ -476,4 +810,61 @@ mod tests {
         test::check_discrete_distribution(&try_create(0.3), 10);
         test::check_discrete_distribution(&try_create(4.5), 30);
     }
+
+    #[test]
+    fn test_quantile_residual() {
+        use ::rand::distributions::Distribution;
+        use ::rand::rngs::StdRng;
+        use ::rand::SeedableRng;
+        use crate::distribution::{ContinuousCDF, Normal};
+
+        let n = try_create(4.5);
+        let std_normal = Normal::new(0.0, 1.0).unwrap();
+        let mut r: StdRng = SeedableRng::seed_from_u64(13);
+        let residuals: Vec<f64> = (0..10_000)
+            .map(|_| {
+                let x = n.sample(&mut r) as u64;
+                n.quantile_residual(x, &mut r)
+            })
+            .collect();
+        test::check_ks(&residuals, |x| std_normal.cdf(x));
+    }
+
+    #[test]
+    fn test_ln_pmf_slice() {
+        let ks: Vec<u64> = (0..200).chain(vec![1000, 100_000]).collect();
+        let mut out = vec![0.0; ks.len()];
+        for &lambda in &[0.1, 1.5, 5.4, 30.0, 1000.0] {
+            let dist = try_create(lambda);
+            dist.ln_pmf_slice(&ks, &mut out);
+            for (&k, &x) in ks.iter().zip(out.iter()) {
+                assert_eq!(x, dist.ln_pmf(k));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ln_pmf_slice_length_mismatch() {
+        try_create(1.5).ln_pmf_slice(&[1, 2], &mut [0.0; 3]);
+    }
+
+    #[test]
+    fn test_loglik_grad() {
+        let data = [0, 1, 1, 2, 4, 5, 9];
+        let loglik = |lambda: f64| data.iter().map(|&x| try_create(lambda).ln_pmf(x)).sum::<f64>();
+        let h = 1e-6;
+        for &lambda in &[0.5, 3.0, 3.142857142857143, 10.0] {
+            let fd = (loglik(lambda + h) - loglik(lambda - h)) / (2.0 * h);
+            assert_almost_eq!(Poisson::loglik_grad(&data, lambda), fd, 1e-6);
+        }
+        let mean = data.iter().sum::<u64>() as f64 / data.len() as f64;
+        assert_almost_eq!(Poisson::loglik_grad(&data, mean), 0.0, 1e-12);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_loglik_grad_bad_lambda() {
+        Poisson::loglik_grad(&[1, 2], 0.0);
+    }
 }
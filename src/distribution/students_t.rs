@@ -1,4 +1,4 @@
-use crate::distribution::{Continuous, ContinuousCDF};
+use crate::distribution::{ApproxEq, Continuous, ContinuousCDF, FisherSnedecor};
 use crate::function::{beta, gamma};
 use crate::is_zero;
 use crate::statistics::*;
@@ -102,6 +102,91 @@ impl StudentsT {
     pub fn freedom(&self) -> f64 {
         self.freedom
     }
+
+    /// Returns the distribution of the square of the standardized
+    /// student's t-distribution, i.e. of `((X - μ) / σ)^2` where `X` follows
+    /// this distribution, `μ` is the location and `σ` is the scale.
+    /// This is the fisher-snedecor distribution with `1` and `v` degrees of
+    /// freedom, where `v` is the freedom.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the freedom is infinite
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::StudentsT;
+    ///
+    /// let n = StudentsT::new(0.0, 1.0, 5.0).unwrap();
+    /// let f = n.to_f_squared().unwrap();
+    /// assert_eq!(f.freedom_1(), 1.0);
+    /// assert_eq!(f.freedom_2(), 5.0);
+    /// ```
+    pub fn to_f_squared(&self) -> Result<FisherSnedecor> {
+        FisherSnedecor::new(1.0, self.freedom)
+    }
+
+    /// Returns the two-sided prediction interval at the confidence level
+    /// `confidence` for a new observation predicted as `point_estimate`,
+    /// using the scale of the distribution as the standard error of
+    /// prediction, e.g. for a fitted distribution of regression residuals
+    ///
+    /// # Panics
+    ///
+    /// If `confidence` is not in `(0, 1)`
+    ///
+    /// # Remarks
+    ///
+    /// The interval is centered on `point_estimate`, the location of the
+    /// distribution is not used
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (x - t_(1 - α/2)(v) * σ, x + t_(1 - α/2)(v) * σ)
+    /// ```
+    ///
+    /// where `x` is the point estimate, `α = 1 - confidence`, `σ` is the
+    /// scale and `t_p(v)` is the `p`-quantile of the standard student's
+    /// t-distribution with `v` degrees of freedom
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::StudentsT;
+    /// use statrs::prec;
+    ///
+    /// let n = StudentsT::new(0.0, 2.0, 10.0).unwrap();
+    /// let (lower, upper) = n.prediction_interval(5.0, 0.95);
+    /// assert!(prec::almost_eq(lower, 5.0 - 2.0 * 2.228138851964938, 1e-9));
+    /// assert!(prec::almost_eq(upper, 5.0 + 2.0 * 2.228138851964938, 1e-9));
+    /// ```
+    pub fn prediction_interval(&self, point_estimate: f64, confidence: f64) -> (f64, f64) {
+        if !(confidence > 0.0 && confidence < 1.0) {
+            panic!("confidence must be in (0, 1)");
+        }
+        let p = 0.5 + 0.5 * confidence;
+        let critical = if self.freedom.is_infinite() {
+            super::std_normal_inverse_cdf(p)
+        } else {
+            StudentsT::new(0.0, 1.0, self.freedom)
+                .unwrap()
+                .inverse_cdf(p)
+        };
+        let half_width = critical * self.scale;
+        (point_estimate - half_width, point_estimate + half_width)
+    }
+}
+
+impl ApproxEq for StudentsT {
+    fn approx_eq(&self, other: &StudentsT, tol: f64) -> bool {
+        super::internal::params_approx_eq(
+            &[self.location, self.scale, self.freedom],
+            &[other.location, other.scale, other.freedom],
+            tol,
+        )
+    }
 }
 
 impl ::rand::distributions::Distribution<f64> for StudentsT {
@@ -319,6 +404,39 @@ impl Distribution<f64> for StudentsT {
             Some(0.0)
         }
     }
+    /// Returns the excess kurtosis of the student's t-distribution
+    ///
+    /// # None
+    ///
+    /// If `freedom <= 4`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 6 / (v - 4)
+    /// ```
+    ///
+    /// where `v` is the freedom
+    fn excess_kurtosis(&self) -> Option<f64> {
+        if self.freedom <= 4.0 {
+            None
+        } else {
+            Some(6.0 / (self.freedom - 4.0))
+        }
+    }
+    /// Returns the center of symmetry of the student's t-distribution, i.e.
+    /// the location
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ
+    /// ```
+    ///
+    /// where `μ` is the location
+    fn symmetry_center(&self) -> Option<f64> {
+        Some(self.location)
+    }
 }
 
 impl Median<f64> for StudentsT {
@@ -430,6 +548,36 @@ mod tests {
         try_create((10.0, 10.0, f64::INFINITY));
     }
 
+    #[test]
+    fn test_prediction_interval() {
+        // t-quantiles from scipy.stats.t.ppf
+        let cases = [
+            (1.0, 0.95, 12.706204736174698),
+            (5.0, 0.9, 2.015048372669157),
+            (10.0, 0.95, 2.2281388519649385),
+            (30.0, 0.99, 2.7499956535672277),
+        ];
+        for &(freedom, confidence, critical) in cases.iter() {
+            let n = try_create((0.0, 1.5, freedom));
+            let (lower, upper) = n.prediction_interval(3.0, confidence);
+            assert_almost_eq!(upper - lower, 2.0 * critical * 1.5, 1e-8);
+            assert_almost_eq!(3.0 - lower, upper - 3.0, 1e-12);
+        }
+        // the location does not shift the interval
+        let (lower, upper) = try_create((7.0, 1.5, 10.0)).prediction_interval(3.0, 0.95);
+        assert_almost_eq!(lower + upper, 6.0, 1e-12);
+        // with infinite freedom the interval is normal
+        let (lower, upper) = try_create((0.0, 2.0, f64::INFINITY)).prediction_interval(0.0, 0.95);
+        assert_almost_eq!(upper, 2.0 * 1.959963984540054, 1e-9);
+        assert_almost_eq!(lower, -upper, 1e-12);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_prediction_interval_bad_confidence() {
+        try_create((0.0, 1.0, 5.0)).prediction_interval(0.0, 1.0);
+    }
+
     // #[test]
     // fn foo() {
     //     let dist = StudentsT::new(0.0,1.0,1.0).unwrap();
@@ -498,6 +646,24 @@ mod tests {
         get_value((1.0, 1.0, 1.0), skewness);
     }
 
+    #[test]
+    fn test_excess_kurtosis() {
+        let excess_kurtosis = |x: StudentsT| x.excess_kurtosis().unwrap();
+        test_case((0.0, 1.0, 5.0), 6.0, excess_kurtosis);
+        test_case((1.0, 3.0, 10.0), 1.0, excess_kurtosis);
+        test_case((0.0, 1.0, f64::INFINITY), 0.0, excess_kurtosis);
+        test_none((0.0, 1.0, 4.0), |x: StudentsT| x.excess_kurtosis());
+    }
+
+    #[test]
+    fn test_symmetry_center() {
+        let center = |x: StudentsT| x.symmetry_center().unwrap();
+        test_case((0.0, 1.0, 1.0), 0.0, center);
+        test_case((-2.0, 0.1, 5.0), -2.0, center);
+        test_case((7.0, 10.0, f64::INFINITY), 7.0, center);
+        assert!(try_create((0.0, 1.0, 1.0)).is_symmetric());
+    }
+
     #[test]
     fn test_mode() {
         let mode = |x: StudentsT| x.mode().unwrap();
@@ -1158,4 +1324,31 @@ mod tests {
         let d = StudentsT::new(0.0, 1.0, 12.0).unwrap();
         assert_eq!(d.inverse_cdf(1.0), std::f64::INFINITY);
     }
+
+    #[test]
+    fn test_to_f_squared() {
+        let f = try_create((1.0, 2.0, 7.0)).to_f_squared().unwrap();
+        assert_eq!(f.freedom_1(), 1.0);
+        assert_eq!(f.freedom_2(), 7.0);
+        let n = try_create((0.0, 1.0, f64::INFINITY));
+        assert!(n.to_f_squared().is_err());
+    }
+
+    #[test]
+    fn test_to_f_squared_samples() {
+        use ::rand::distributions::Distribution;
+        use ::rand::rngs::StdRng;
+        use ::rand::SeedableRng;
+
+        let (location, scale) = (1.0, 2.0);
+        for &freedom in &[1.0, 3.5, 10.0] {
+            let t = try_create((location, scale, freedom));
+            let f = t.to_f_squared().unwrap();
+            let mut r: StdRng = SeedableRng::seed_from_u64(11);
+            let samples: Vec<f64> = (0..10_000)
+                .map(|_| ((t.sample(&mut r) - location) / scale).powi(2))
+                .collect();
+            test::check_ks(&samples, |x| f.cdf(x));
+        }
+    }
 }
@@ -1,4 +1,4 @@
-use crate::distribution::{Continuous, ContinuousCDF};
+use crate::distribution::{ApproxEq, Continuous, ContinuousCDF};
 use crate::statistics::*;
 use crate::{Result, StatsError};
 use rand::Rng;
@@ -57,6 +57,96 @@ impl Triangular {
         }
         Ok(Triangular { min, max, mode })
     }
+
+    /// Constructs a new triangular distribution from a three-point estimate
+    /// as used in project estimation, i.e. an `optimistic` (minimum),
+    /// `most_likely` (mode) and `pessimistic` (maximum) value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the estimates are `NaN` or `±INF`, if
+    /// they are not ordered such that
+    /// `optimistic <= most_likely <= pessimistic`, or if
+    /// `optimistic == pessimistic`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Triangular;
+    /// use statrs::statistics::Distribution;
+    ///
+    /// let n = Triangular::from_three_point(2.0, 4.0, 9.0).unwrap();
+    /// assert_eq!(n.mean().unwrap(), 5.0);
+    ///
+    /// let result = Triangular::from_three_point(4.0, 2.0, 9.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn from_three_point(
+        optimistic: f64,
+        most_likely: f64,
+        pessimistic: f64,
+    ) -> Result<Triangular> {
+        if optimistic > most_likely || most_likely > pessimistic {
+            return Err(StatsError::BadParams);
+        }
+        Triangular::new(optimistic, pessimistic, most_likely)
+    }
+
+    /// Estimates a triangular distribution from the samples in `data`.
+    /// The bounds are taken from the sample minimum and maximum and the mode
+    /// is the center of the most populated bin of a histogram of the data,
+    /// using Sturges' rule for the number of bins.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` contains fewer than two elements, contains
+    /// `NaN` or `±INF`, or if all elements are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Triangular;
+    /// use statrs::statistics::{Max, Min};
+    ///
+    /// let data = [1.0, 2.0, 2.1, 2.2, 2.3, 3.0, 5.0];
+    /// let n = Triangular::from_data(&data).unwrap();
+    /// assert_eq!(n.min(), 1.0);
+    /// assert_eq!(n.max(), 5.0);
+    /// ```
+    pub fn from_data(data: &[f64]) -> Result<Triangular> {
+        if data.len() < 2 || data.iter().any(|x| !x.is_finite()) {
+            return Err(StatsError::BadParams);
+        }
+        let min = data.iter().fold(f64::INFINITY, |acc, &x| acc.min(x));
+        let max = data.iter().fold(f64::NEG_INFINITY, |acc, &x| acc.max(x));
+        if ulps_eq!(max, min, max_ulps = 0) {
+            return Err(StatsError::BadParams);
+        }
+
+        let bins = (data.len() as f64).log2().ceil() as usize + 1;
+        let width = (max - min) / bins as f64;
+        let mut counts = vec![0usize; bins];
+        for &x in data {
+            let i = (((x - min) / width) as usize).min(bins - 1);
+            counts[i] += 1;
+        }
+        let peak = counts
+            .iter()
+            .enumerate()
+            .fold(0, |best, (i, &c)| if c > counts[best] { i } else { best });
+        let mode = min + (peak as f64 + 0.5) * width;
+        Triangular::new(min, max, mode)
+    }
+}
+
+impl ApproxEq for Triangular {
+    fn approx_eq(&self, other: &Triangular, tol: f64) -> bool {
+        super::internal::params_approx_eq(
+            &[self.min, self.max, self.mode],
+            &[other.min, other.max, other.mode],
+            tol,
+        )
+    }
 }
 
 impl ::rand::distributions::Distribution<f64> for Triangular {
@@ -206,6 +296,16 @@ impl Distribution<f64> for Triangular {
         let d = 5.0 * (a * a + b * b + c * c - a * b - a * c - b * c).powf(3.0 / 2.0);
         Some(q / d)
     }
+    /// Returns the excess kurtosis of the triangular distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -3 / 5
+    /// ```
+    fn excess_kurtosis(&self) -> Option<f64> {
+        Some(-0.6)
+    }
 }
 
 impl Median<f64> for Triangular {
@@ -382,6 +482,46 @@ mod tests {
         bad_create_case(0.0, f64::INFINITY, 0.5);
     }
 
+    #[test]
+    fn test_from_three_point() {
+        let n = Triangular::from_three_point(1.0, 3.0, 8.0).unwrap();
+        assert_eq!(n.min(), 1.0);
+        assert_eq!(n.max(), 8.0);
+        assert_eq!(n.mode().unwrap(), 3.0);
+        assert_eq!(n.mean().unwrap(), (1.0 + 3.0 + 8.0) / 3.0);
+        let n = Triangular::from_three_point(-4.0, -4.0, 2.0).unwrap();
+        assert_eq!(n.mean().unwrap(), (-4.0 + -4.0 + 2.0) / 3.0);
+    }
+
+    #[test]
+    fn test_bad_from_three_point() {
+        assert!(Triangular::from_three_point(3.0, 1.0, 8.0).is_err());
+        assert!(Triangular::from_three_point(1.0, 9.0, 8.0).is_err());
+        assert!(Triangular::from_three_point(8.0, 3.0, 1.0).is_err());
+        assert!(Triangular::from_three_point(2.0, 2.0, 2.0).is_err());
+        assert!(Triangular::from_three_point(f64::NAN, 3.0, 8.0).is_err());
+        assert!(Triangular::from_three_point(1.0, 3.0, f64::INFINITY).is_err());
+    }
+
+    #[test]
+    fn test_from_data() {
+        let data = [0.0, 1.0, 1.2, 1.4, 1.6, 1.8, 2.0, 5.0, 10.0];
+        let n = Triangular::from_data(&data).unwrap();
+        assert_eq!(n.min(), 0.0);
+        assert_eq!(n.max(), 10.0);
+        // 5 bins of width 2.0, the peak bin is [0, 2)
+        assert_eq!(n.mode().unwrap(), 1.0);
+    }
+
+    #[test]
+    fn test_bad_from_data() {
+        assert!(Triangular::from_data(&[]).is_err());
+        assert!(Triangular::from_data(&[1.0]).is_err());
+        assert!(Triangular::from_data(&[1.0, 1.0, 1.0]).is_err());
+        assert!(Triangular::from_data(&[1.0, f64::NAN, 2.0]).is_err());
+        assert!(Triangular::from_data(&[1.0, f64::INFINITY, 2.0]).is_err());
+    }
+
     #[test]
     fn test_variance() {
         let variance = |x: Triangular| x.variance().unwrap();
@@ -415,6 +555,13 @@ mod tests {
         test_case(15.0, 134.0, 21.0, 0.5605920922751860613217, skewness);
     }
 
+    #[test]
+    fn test_excess_kurtosis() {
+        let excess_kurtosis = |x: Triangular| x.excess_kurtosis().unwrap();
+        test_case(0.0, 1.0, 0.5, -0.6, excess_kurtosis);
+        test_case(-5.0, 8.0, -3.5, -0.6, excess_kurtosis);
+    }
+
     #[test]
     fn test_mode() {
         let mode = |x: Triangular| x.mode().unwrap();
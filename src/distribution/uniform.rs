@@ -1,4 +1,4 @@
-use crate::distribution::{Continuous, ContinuousCDF};
+use crate::distribution::{ApproxEq, Continuous, ContinuousCDF};
 use crate::statistics::*;
 use crate::{Result, StatsError};
 use rand::distributions::Uniform as RandUniform;
@@ -54,6 +54,12 @@ impl Uniform {
     }
 }
 
+impl ApproxEq for Uniform {
+    fn approx_eq(&self, other: &Uniform, tol: f64) -> bool {
+        super::internal::params_approx_eq(&[self.min, self.max], &[other.min, other.max], tol)
+    }
+}
+
 impl ::rand::distributions::Distribution<f64> for Uniform {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
         let d = RandUniform::new_inclusive(self.min, self.max);
@@ -157,6 +163,27 @@ impl Distribution<f64> for Uniform {
     fn skewness(&self) -> Option<f64> {
         Some(0.0)
     }
+    /// Returns the excess kurtosis of the continuous uniform distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -6 / 5
+    /// ```
+    fn excess_kurtosis(&self) -> Option<f64> {
+        Some(-1.2)
+    }
+    /// Returns the center of symmetry of the continuous uniform
+    /// distribution, i.e. the midpoint of its support
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (min + max) / 2
+    /// ```
+    fn symmetry_center(&self) -> Option<f64> {
+        Some((self.min + self.max) / 2.0)
+    }
 }
 
 impl Median<f64> for Uniform {
@@ -331,6 +358,22 @@ mod tests {
         test_case(0.0, f64::INFINITY, 0.0, skewness);
     }
 
+    #[test]
+    fn test_excess_kurtosis() {
+        let excess_kurtosis = |x: Uniform| x.excess_kurtosis().unwrap();
+        test_case(0.0, 2.0, -1.2, excess_kurtosis);
+        test_case(10.0, 11.0, -1.2, excess_kurtosis);
+    }
+
+    #[test]
+    fn test_symmetry_center() {
+        let center = |x: Uniform| x.symmetry_center().unwrap();
+        test_case(-2.0, 2.0, 0.0, center);
+        test_case(1.0, 10.0, 5.5, center);
+        test_case(10.0, 11.0, 10.5, center);
+        assert!(try_create(0.0, 1.0).is_symmetric());
+    }
+
     #[test]
     fn test_mode() {
         let mode = |x: Uniform| x.mode().unwrap();
@@ -462,6 +505,17 @@ mod tests {
         test::check_continuous_distribution(&try_create(-2.0, 15.0), -2.0, 15.0);
     }
 
+    #[test]
+    fn test_clamp_to_support() {
+        let n = try_create(-2.0, 15.0);
+        assert_eq!(n.clamp_to_support(-2.0000001), -2.0);
+        assert_eq!(n.clamp_to_support(f64::NEG_INFINITY), -2.0);
+        assert_eq!(n.clamp_to_support(15.0 + 1e-9), 15.0);
+        assert_eq!(n.clamp_to_support(100.0), 15.0);
+        assert_eq!(n.clamp_to_support(3.0), 3.0);
+        assert!(n.clamp_to_support(f64::NAN).is_nan());
+    }
+
     #[test]
     fn test_samples_in_range() {
         use rand::rngs::StdRng;
@@ -1,4 +1,4 @@
-use crate::distribution::{Continuous, ContinuousCDF};
+use crate::distribution::{ApproxEq, Continuous, ContinuousCDF};
 use crate::function::gamma;
 use crate::is_zero;
 use crate::statistics::*;
@@ -88,6 +88,40 @@ impl Weibull {
     pub fn scale(&self) -> f64 {
         self.scale
     }
+
+    /// Returns the accelerated-failure-time reparameterization of the
+    /// distribution, i.e. the distribution of `X / factor`, which is
+    /// weibull with the same shape and a scale of `λ / factor`
+    ///
+    /// # Panics
+    ///
+    /// If `factor` is not finite or `factor <= 0.0`, or if the new scale
+    /// underflows to `0.0`. A new scale that overflows is infinite
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Weibull;
+    ///
+    /// let n = Weibull::new(2.0, 3.0).unwrap();
+    /// assert_eq!(n.accelerate(2.0), Weibull::new(2.0, 1.5).unwrap());
+    /// ```
+    pub fn accelerate(&self, factor: f64) -> Weibull {
+        if !factor.is_finite() || factor <= 0.0 {
+            panic!("factor must be finite and positive");
+        }
+        Weibull::new(self.shape, self.scale / factor).expect("accelerated scale underflows to zero")
+    }
+}
+
+impl ApproxEq for Weibull {
+    fn approx_eq(&self, other: &Weibull, tol: f64) -> bool {
+        super::internal::params_approx_eq(
+            &[self.shape, self.scale],
+            &[other.shape, other.scale],
+            tol,
+        )
+    }
 }
 
 impl ::rand::distributions::Distribution<f64> for Weibull {
@@ -229,6 +263,30 @@ impl Distribution<f64> for Weibull {
             / sigma3;
         Some(skew)
     }
+    /// Returns the excess kurtosis of the weibull distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (Γ(1 + 4 / k) * λ^4 - 4γμσ^3 - 6μ^2σ^2 - μ^4) / σ^4 - 3
+    /// ```
+    ///
+    /// where `k` is the shape, `λ` is the scale, `Γ` is the gamma function,
+    /// `μ` is the mean, `σ` the standard deviation and `γ` the skewness of
+    /// the distribution
+    fn excess_kurtosis(&self) -> Option<f64> {
+        let mu = self.mean()?;
+        let sigma = self.std_dev()?;
+        let skew = self.skewness()?;
+        let sigma2 = sigma * sigma;
+        let scale4 = self.scale.powi(4);
+        let kurt = (scale4 * gamma::gamma(1.0 + 4.0 / self.shape)
+            - 4.0 * skew * mu * sigma2 * sigma
+            - 6.0 * mu * mu * sigma2
+            - mu * mu * mu * mu)
+            / (sigma2 * sigma2);
+        Some(kurt - 3.0)
+    }
 }
 
 impl Median<f64> for Weibull {
@@ -325,7 +383,7 @@ impl Continuous<f64, f64> for Weibull {
 #[cfg(all(test, feature = "nightly"))]
 mod tests {
     use crate::statistics::*;
-    use crate::distribution::{ContinuousCDF, Continuous, Weibull};
+    use crate::distribution::{ContinuousCDF, Continuous, Exp, Weibull};
     use crate::distribution::internal::*;
     use crate::consts::ACC;
 
@@ -424,6 +482,15 @@ mod tests {
         test_almost(10.0, 1.0, -0.63763713390314440916597757156663888653981696212127, 1e-11, skewness);
     }
 
+    #[test]
+    fn test_excess_kurtosis() {
+        let excess_kurtosis = |x: Weibull| x.excess_kurtosis().unwrap();
+        test_almost(0.5, 1.0, 84.72, 1e-10, excess_kurtosis);
+        test_almost(1.0, 1.0, 6.0, 1e-12, excess_kurtosis);
+        test_almost(2.0, 3.0, 0.24508930068763806285, 1e-12, excess_kurtosis);
+        test_almost(5.0, 0.5, -0.11970993621779815824, 1e-11, excess_kurtosis);
+    }
+
     #[test]
     fn test_median() {
         let median = |x: Weibull| x.median();
@@ -522,4 +589,39 @@ mod tests {
     fn test_continuous() {
         test::check_continuous_distribution(&try_create(1.0, 0.2), 0.0, 10.0);
     }
+
+    #[test]
+    fn test_accelerate() {
+        let n = try_create(2.0, 3.0);
+        let m = n.accelerate(4.0);
+        assert_eq!(m.shape(), 2.0);
+        assert_eq!(m.scale(), 0.75);
+        assert_almost_eq!(m.mean().unwrap(), n.mean().unwrap() / 4.0, 1e-15);
+        for &x in &[0.1, 0.5, 1.0, 2.0] {
+            assert_almost_eq!(m.cdf(x), n.cdf(4.0 * x), 1e-15);
+        }
+        // a weibull with shape 1 is exponential, so this matches Exp
+        let e = Exp::new(0.5).unwrap().accelerate(3.0);
+        let w = try_create(1.0, 2.0).accelerate(3.0);
+        for &x in &[0.1, 0.5, 1.0, 2.0] {
+            assert_almost_eq!(w.cdf(x), e.cdf(x), 1e-15);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_accelerate_negative() {
+        try_create(2.0, 3.0).accelerate(-1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_accelerate_underflow() {
+        try_create(2.0, 1e-300).accelerate(1e300);
+    }
+
+    #[test]
+    fn test_accelerate_overflow() {
+        assert_eq!(try_create(2.0, 1e300).accelerate(1e-300).scale(), f64::INFINITY);
+    }
 }
@@ -0,0 +1,96 @@
+//! Provides the [binary entropy](https://en.wikipedia.org/wiki/Binary_entropy_function)
+//! and related information-theoretic functions
+
+use crate::error::StatsError;
+use crate::Result;
+use std::f64;
+
+/// Computes the binary entropy function in bits, the entropy of a
+/// bernoulli trial with success probability `p`, using the convention
+/// `0 * log(0) = 0`
+///
+/// # Panics
+///
+/// If `p < 0.0` or `p > 1.0`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::function::entropy::binary_entropy;
+///
+/// assert_eq!(binary_entropy(0.5), 1.0);
+/// assert_eq!(binary_entropy(1.0), 0.0);
+/// ```
+pub fn binary_entropy(p: f64) -> f64 {
+    checked_binary_entropy(p).unwrap()
+}
+
+/// Computes the binary entropy function in bits, the entropy of a
+/// bernoulli trial with success probability `p`, using the convention
+/// `0 * log(0) = 0`
+///
+/// # Errors
+///
+/// If `p < 0.0` or `p > 1.0`
+pub fn checked_binary_entropy(p: f64) -> Result<f64> {
+    if !(0.0..=1.0).contains(&p) {
+        Err(StatsError::ArgIntervalIncl("p", 0.0, 1.0))
+    } else if p == 0.0 || p == 1.0 {
+        Ok(0.0)
+    } else {
+        // ln(1 - p) via ln_1p keeps full precision for small p
+        let nats = -p * p.ln() - (1.0 - p) * (-p).ln_1p();
+        Ok(nats / f64::consts::LN_2)
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use std::f64;
+
+    #[test]
+    fn test_binary_entropy() {
+        assert_eq!(super::binary_entropy(0.0), 0.0);
+        assert_eq!(super::binary_entropy(0.5), 1.0);
+        assert_eq!(super::binary_entropy(1.0), 0.0);
+        assert_almost_eq!(super::binary_entropy(0.25), 2.0 - 0.75 * 3f64.log2(), 1e-15);
+        assert_almost_eq!(super::binary_entropy(0.11), 0.499915958164528, 1e-15);
+        assert_almost_eq!(super::binary_entropy(1e-10), 3.466197598969045e-9, 1e-22);
+    }
+
+    #[test]
+    fn test_binary_entropy_symmetric() {
+        for &p in &[1e-12, 0.01, 0.1, 0.25, 0.3, 0.49] {
+            assert_almost_eq!(super::binary_entropy(p), super::binary_entropy(1.0 - p), 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_binary_entropy_bounds() {
+        for i in 0..=100 {
+            let h = super::binary_entropy(i as f64 / 100.0);
+            assert!((0.0..=1.0).contains(&h));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_binary_entropy_p_lt_0() {
+        super::binary_entropy(-0.1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_binary_entropy_p_gt_1() {
+        super::binary_entropy(1.1);
+    }
+
+    #[test]
+    fn test_checked_binary_entropy() {
+        assert!(super::checked_binary_entropy(-0.1).is_err());
+        assert!(super::checked_binary_entropy(1.1).is_err());
+        assert!(super::checked_binary_entropy(f64::NAN).is_err());
+        assert!(super::checked_binary_entropy(0.5).is_ok());
+    }
+}
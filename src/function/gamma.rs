@@ -346,6 +346,71 @@ pub fn checked_gamma_lr(a: f64, x: f64) -> Result<f64> {
     Ok(1.0 - ax.exp() * ans)
 }
 
+/// Computes the derivative of the lower incomplete regularized gamma
+/// function with respect to `x`, `d/dx P(a,x) = x^(a-1) * exp(-x) / Gamma(a)`,
+/// i.e. the density of the gamma distribution with shape `a` and unit rate.
+/// The value is computed in log space to avoid overflow of the individual
+/// factors.
+///
+/// # Remarks
+///
+/// Returns `f64::NAN` if either argument is `f64::NAN`
+///
+/// # Panics
+///
+/// if `a` is not in `(0, +inf)` or `x < 0`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::function::gamma::gamma_lr_prime;
+/// use statrs::prec;
+///
+/// assert!(prec::almost_eq(gamma_lr_prime(1.0, 2.0), (-2f64).exp(), 1e-15));
+/// ```
+pub fn gamma_lr_prime(a: f64, x: f64) -> f64 {
+    checked_gamma_lr_prime(a, x).unwrap()
+}
+
+/// Computes the derivative of the lower incomplete regularized gamma
+/// function with respect to `x`, `d/dx P(a,x) = x^(a-1) * exp(-x) / Gamma(a)`,
+/// i.e. the density of the gamma distribution with shape `a` and unit rate.
+/// The value is computed in log space to avoid overflow of the individual
+/// factors.
+///
+/// # Remarks
+///
+/// Returns `f64::NAN` if either argument is `f64::NAN`
+///
+/// # Errors
+///
+/// if `a` is not in `(0, +inf)` or `x < 0`
+pub fn checked_gamma_lr_prime(a: f64, x: f64) -> Result<f64> {
+    if a.is_nan() || x.is_nan() {
+        return Ok(f64::NAN);
+    }
+    if a <= 0.0 || a == f64::INFINITY {
+        return Err(StatsError::ArgIntervalExcl("a", 0.0, f64::INFINITY));
+    }
+    if x < 0.0 {
+        return Err(StatsError::ArgNotNegative("x"));
+    }
+    if x == 0.0 {
+        // x^(a-1) is singular at zero for a < 1
+        return Ok(if a < 1.0 {
+            f64::INFINITY
+        } else if a == 1.0 {
+            1.0
+        } else {
+            0.0
+        });
+    }
+    if x == f64::INFINITY {
+        return Ok(0.0);
+    }
+    Ok(((a - 1.0) * x.ln() - x - ln_gamma(a)).exp())
+}
+
 /// Computes the Digamma function which is defined as the derivative of
 /// the log of the gamma function. The implementation is based on
 /// "Algorithm AS 103", Jose Bernardo, Applied Statistics, Volume 25, Number 3
@@ -391,34 +456,73 @@ pub fn digamma(x: f64) -> f64 {
     result
 }
 
-pub fn inv_digamma(x: f64) -> f64 {
-    if x.is_nan() {
+/// Computes the inverse of the digamma function, i.e. the positive `x`
+/// such that `digamma(x) = y`, by Newton's method. The initial guess is
+/// `exp(y) + 0.5` for `y >= -2.22` and `-1 / (y - digamma(1))` otherwise,
+/// following "Estimating a Dirichlet distribution", Thomas P. Minka, 2000
+///
+/// # Remarks
+///
+/// Returns `NaN` if `y` is `NaN`, `0.0` if `y` is `-INF` and `INF` if `y`
+/// is `INF`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::function::gamma::{digamma, inv_digamma};
+/// use statrs::prec;
+///
+/// let x = inv_digamma(-3.0);
+/// assert!(prec::almost_eq(digamma(x), -3.0, 1e-14));
+/// ```
+pub fn inv_digamma(y: f64) -> f64 {
+    if y.is_nan() {
         return f64::NAN;
     }
-    if x == f64::NEG_INFINITY {
+    if y == f64::NEG_INFINITY {
         return 0.0;
     }
-    if x == f64::INFINITY {
+    if y == f64::INFINITY {
         return f64::INFINITY;
     }
-    let mut y = x.exp();
-    let mut i = 1.0;
-    while i > 1e-15 {
-        y += i * signum(x - digamma(y));
-        i /= 2.0;
+    let mut x = if y >= -2.22 {
+        y.exp() + 0.5
+    } else {
+        -1.0 / (y - digamma(1.0))
+    };
+    for _ in 0..100 {
+        let step = (digamma(x) - y) / trigamma(x);
+        x -= step;
+        if step.abs() <= 1e-15 * x {
+            break;
+        }
     }
-    y
+    x
 }
 
-// modified signum that returns 0.0 if x == 0.0. Used
-// by inv_digamma, may consider extracting into a public
-// method
-fn signum(x: f64) -> f64 {
-    if x == 0.0 {
-        0.0
-    } else {
-        x.signum()
+/// Computes the trigamma function, the derivative of the digamma function,
+/// for `x > 0` by shifting `x` with the recurrence
+/// `trigamma(x) = trigamma(x + 1) + 1 / x^2` and evaluating the asymptotic
+/// expansion
+fn trigamma(x: f64) -> f64 {
+    let mut result = 0.0;
+    let mut z = x;
+    while z < 12.0 {
+        result += 1.0 / (z * z);
+        z += 1.0;
     }
+    let r = 1.0 / (z * z);
+    // Bernoulli numbers B_2, ..., B_12 of the asymptotic expansion
+    let bernoulli = [
+        1.0 / 6.0,
+        -1.0 / 30.0,
+        1.0 / 42.0,
+        -1.0 / 30.0,
+        5.0 / 66.0,
+        -691.0 / 2730.0,
+    ];
+    let series = bernoulli.iter().rev().fold(0.0, |acc, b| acc * r + b);
+    result + 1.0 / z + r / 2.0 + r / z * series
 }
 
 #[rustfmt::skip]
@@ -790,6 +894,7 @@ mod tests {
     fn test_inv_digamma() {
         assert!(super::inv_digamma(f64::NAN).is_nan());
         assert_eq!(super::inv_digamma(f64::NEG_INFINITY), 0.0);
+        assert_eq!(super::inv_digamma(f64::INFINITY), f64::INFINITY);
         assert_almost_eq!(super::inv_digamma(-10.423754940411076232100295314502760886768558023951363), 0.1, 1e-15);
         assert_almost_eq!(super::inv_digamma(-0.57721566490153286060651209008240243104215933593992359), 1.0, 1e-14);
         assert_almost_eq!(super::inv_digamma(0.036489973978576520559023667001244432806840395339565888), 1.5, 1e-14);
@@ -805,4 +910,77 @@ mod tests {
         assert_almost_eq!(super::inv_digamma(1.6110931485817511237336268416044190359814435699427405), 5.5, 1e-14);
         assert_almost_eq!(super::inv_digamma(2.2622143570941481235561593642219403924532310597356171), 10.1, 1e-13);
     }
+
+    #[test]
+    fn test_inv_digamma_round_trip() {
+        for &y in &[-1e6, -100.0, -20.0, -5.0, -2.22, -1.0, 0.0, 0.5, 1.0, 5.0, 20.0, 100.0] {
+            let x = super::inv_digamma(y);
+            assert!(x > 0.0);
+            assert_almost_eq!(super::digamma(x), y, 1e-12 * y.abs().max(1.0));
+        }
+    }
+
+    #[test]
+    fn test_gamma_lr_prime() {
+        assert!(super::gamma_lr_prime(f64::NAN, 1.0).is_nan());
+        assert!(super::gamma_lr_prime(1.0, f64::NAN).is_nan());
+        assert_almost_eq!(super::gamma_lr_prime(1.0, 2.0), (-2f64).exp(), 1e-15);
+        assert_almost_eq!(super::gamma_lr_prime(3.0, 2.0), 2.0 * (-2f64).exp(), 1e-15);
+        assert_eq!(super::gamma_lr_prime(0.5, 0.0), f64::INFINITY);
+        assert_eq!(super::gamma_lr_prime(1.0, 0.0), 1.0);
+        assert_eq!(super::gamma_lr_prime(2.0, 0.0), 0.0);
+        assert_eq!(super::gamma_lr_prime(2.0, f64::INFINITY), 0.0);
+        // the factors overflow separately but not in log space
+        assert!(super::gamma_lr_prime(500.0, 500.0) > 0.0);
+        assert!(super::gamma_lr_prime(500.0, 500.0).is_finite());
+    }
+
+    #[test]
+    fn test_gamma_lr_prime_finite_difference() {
+        for &a in &[0.5, 1.0, 2.5, 10.0, 100.0] {
+            for &x in &[0.1, 0.5, 1.0, 3.0, 10.0, 90.0] {
+                let h = 1e-5 * x;
+                let expected = (super::gamma_lr(a, x + h) - super::gamma_lr(a, x - h)) / (2.0 * h);
+                let prime = super::gamma_lr_prime(a, x);
+                assert_almost_eq!(prime, expected, 1e-7 * prime.max(1e-10));
+            }
+        }
+    }
+
+    #[test]
+    fn test_gamma_lr_prime_integrates_to_gamma_lr() {
+        // Simpson's rule from 0, for shapes where the integrand is smooth at 0
+        for &a in &[1.0, 3.0, 7.0] {
+            for &x in &[0.5, 2.0, 10.0] {
+                let n = 1000;
+                let h = x / n as f64;
+                let sum = (0..=n).fold(0.0, |acc, i| {
+                    let weight = if i == 0 || i == n { 1.0 } else if i % 2 == 1 { 4.0 } else { 2.0 };
+                    acc + weight * super::gamma_lr_prime(a, i as f64 * h)
+                });
+                assert_almost_eq!(sum * h / 3.0, super::gamma_lr(a, x), 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gamma_lr_prime_negative_x() {
+        super::gamma_lr_prime(1.0, -1.0);
+    }
+
+    #[test]
+    fn test_checked_gamma_lr_prime() {
+        assert!(super::checked_gamma_lr_prime(0.0, 1.0).is_err());
+        assert!(super::checked_gamma_lr_prime(f64::INFINITY, 1.0).is_err());
+        assert!(super::checked_gamma_lr_prime(1.0, -1.0).is_err());
+        assert!(super::checked_gamma_lr_prime(1.0, 1.0).is_ok());
+    }
+
+    #[test]
+    fn test_trigamma() {
+        assert_almost_eq!(super::trigamma(1.0), consts::PI * consts::PI / 6.0, 1e-14);
+        assert_almost_eq!(super::trigamma(0.5), consts::PI * consts::PI / 2.0, 1e-14);
+        assert_almost_eq!(super::trigamma(10.0), 0.10516633568168574612, 1e-15);
+    }
 }
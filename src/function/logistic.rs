@@ -31,6 +31,59 @@ pub fn checked_logit(p: f64) -> Result<f64> {
     }
 }
 
+/// Computes the derivative of the logistic function, `σ(x) * (1 - σ(x))`,
+/// where `σ` is the logistic function
+///
+/// # Remarks
+///
+/// Uses the identity `1 - σ(x) = σ(-x)` so that the result does not
+/// collapse to zero for large positive `x`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::function::logistic::logistic_derivative;
+///
+/// assert_eq!(logistic_derivative(0.0), 0.25);
+/// ```
+pub fn logistic_derivative(x: f64) -> f64 {
+    logistic(x) * logistic(-x)
+}
+
+/// Computes the softplus function `ln(1 + exp(x))`, a smooth approximation
+/// of `max(0, x)`, without overflowing for large `x`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::function::logistic::softplus;
+///
+/// assert_eq!(softplus(0.0), 2f64.ln());
+/// assert_eq!(softplus(1000.0), 1000.0);
+/// ```
+pub fn softplus(x: f64) -> f64 {
+    if x > 0.0 {
+        x + (-x).exp().ln_1p()
+    } else {
+        x.exp().ln_1p()
+    }
+}
+
+/// Computes the log of the logistic function, `ln(σ(x)) = -softplus(-x)`,
+/// which stays accurate for large negative `x` where `σ(x)` underflows
+///
+/// # Examples
+///
+/// ```
+/// use statrs::function::logistic::log_sigmoid;
+///
+/// assert_eq!(log_sigmoid(0.0), -2f64.ln());
+/// assert_eq!(log_sigmoid(-1000.0), -1000.0);
+/// ```
+pub fn log_sigmoid(x: f64) -> f64 {
+    -softplus(-x)
+}
+
 #[rustfmt::skip]
 #[cfg(test)]
 mod tests {
@@ -62,6 +115,59 @@ mod tests {
         assert_eq!(super::logit(1.0), f64::INFINITY);
     }
 
+    #[test]
+    fn test_logistic_derivative() {
+        assert_eq!(super::logistic_derivative(0.0), 0.25);
+        assert_eq!(super::logistic_derivative(f64::INFINITY), 0.0);
+        assert_eq!(super::logistic_derivative(f64::NEG_INFINITY), 0.0);
+        for &x in &[-30.0, -5.0, -0.5, 0.3, 2.0, 30.0] {
+            let h = 1e-6;
+            let expected = (super::logistic(x + h) - super::logistic(x - h)) / (2.0 * h);
+            assert_almost_eq!(super::logistic_derivative(x), expected, 1e-9);
+            assert_eq!(super::logistic_derivative(x), super::logistic_derivative(-x));
+        }
+        // 1 - σ(x) rounds to zero here but the derivative does not
+        assert_almost_eq!(super::logistic_derivative(40.0), (-40f64).exp(), 1e-30);
+    }
+
+    #[test]
+    fn test_softplus() {
+        assert_eq!(super::softplus(0.0), 2f64.ln());
+        assert_almost_eq!(super::softplus(1.0), (1.0 + 1f64.exp()).ln(), 1e-15);
+        assert_almost_eq!(super::softplus(-1.0), (1.0 + (-1f64).exp()).ln(), 1e-15);
+        assert_eq!(super::softplus(1000.0), 1000.0);
+        assert_eq!(super::softplus(-1000.0), 0.0);
+        assert_almost_eq!(super::softplus(-50.0), (-50f64).exp(), 1e-35);
+        assert_eq!(super::softplus(f64::INFINITY), f64::INFINITY);
+        assert_eq!(super::softplus(f64::NEG_INFINITY), 0.0);
+        assert!(super::softplus(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_log_sigmoid() {
+        assert_eq!(super::log_sigmoid(0.0), -2f64.ln());
+        for &x in &[-20.0, -3.0, -0.1, 0.7, 5.0, 20.0] {
+            assert_almost_eq!(super::log_sigmoid(x), super::logistic(x).ln(), 1e-14);
+        }
+        // σ(x) underflows to zero so its log is -inf, but log_sigmoid(x) ≈ x
+        assert_eq!(super::logistic(-800.0).ln(), f64::NEG_INFINITY);
+        assert_eq!(super::log_sigmoid(-800.0), -800.0);
+        // σ(x) rounds to one so its log is 0, but log_sigmoid(x) ≈ -exp(-x)
+        assert_eq!(super::logistic(40.0).ln(), 0.0);
+        assert_almost_eq!(super::log_sigmoid(40.0), -(-40f64).exp(), 1e-30);
+        assert_eq!(super::log_sigmoid(f64::NEG_INFINITY), f64::NEG_INFINITY);
+        assert_eq!(super::log_sigmoid(f64::INFINITY), 0.0);
+    }
+
+    #[test]
+    fn test_logit_logistic_round_trip() {
+        // for large positive x, σ(x) rounds towards one and the round trip
+        // loses precision regardless of the implementation
+        for &x in &[-30.0, -10.0, -1.0, -0.01, 0.0, 0.5, 3.0, 10.0, 15.0] {
+            assert_almost_eq!(super::logit(super::logistic(x)), x, 1e-9 * x.abs().max(1.0));
+        }
+    }
+
     #[test]
     #[should_panic]
     fn test_logit_p_lt_0() {
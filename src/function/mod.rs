@@ -2,6 +2,7 @@
 //! the error function)
 
 pub mod beta;
+pub mod entropy;
 pub mod erf;
 pub mod evaluate;
 pub mod exponential;
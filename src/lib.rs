@@ -57,7 +57,9 @@ pub mod euclid;
 pub mod function;
 pub mod generate;
 pub mod prec;
+pub mod sampling;
 pub mod statistics;
+pub mod stats_tests;
 
 mod error;
 
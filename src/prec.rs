@@ -25,3 +25,127 @@ pub fn almost_eq(a: f64, b: f64, acc: f64) -> bool {
 
     (a - b).abs() < acc
 }
+
+/// Returns the least floating point number greater than `x`.
+/// Returns `x` if `x` is `NAN` or positive infinity and the
+/// smallest positive subnormal number if `x` is `±0.0`.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::prec;
+///
+/// assert_eq!(prec::next_up(1.0), 1.0 + f64::EPSILON);
+/// assert_eq!(prec::next_up(f64::MAX), f64::INFINITY);
+/// ```
+pub fn next_up(x: f64) -> f64 {
+    if x.is_nan() || x == f64::INFINITY {
+        return x;
+    }
+    if x == 0.0 {
+        return f64::from_bits(1);
+    }
+    let bits = x.to_bits();
+    if x > 0.0 {
+        f64::from_bits(bits + 1)
+    } else {
+        f64::from_bits(bits - 1)
+    }
+}
+
+/// Returns the greatest floating point number less than `x`.
+/// Returns `x` if `x` is `NAN` or negative infinity and the
+/// smallest negative subnormal number if `x` is `±0.0`.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::prec;
+///
+/// assert_eq!(prec::next_down(1.0), 1.0 - f64::EPSILON / 2.0);
+/// assert_eq!(prec::next_down(f64::MIN), f64::NEG_INFINITY);
+/// ```
+pub fn next_down(x: f64) -> f64 {
+    -next_up(-x)
+}
+
+/// Returns the number of representable floating point steps between
+/// `a` and `b`, treating `0.0` and `-0.0` as equal. Returns `u64::MAX` if
+/// either number is a `NAN`.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::prec;
+///
+/// assert_eq!(prec::ulps_between(1.0, 1.0), 0);
+/// assert_eq!(prec::ulps_between(1.0, prec::next_up(1.0)), 1);
+/// assert_eq!(prec::ulps_between(-0.0, 0.0), 0);
+/// ```
+pub fn ulps_between(a: f64, b: f64) -> u64 {
+    if a.is_nan() || b.is_nan() {
+        return u64::MAX;
+    }
+    // maps the bits to integers ordered the same way as the floats, with
+    // both zeros mapping to 0
+    fn ordered(x: f64) -> i64 {
+        let bits = x.to_bits() as i64;
+        if bits < 0 {
+            i64::MIN - bits
+        } else {
+            bits
+        }
+    }
+    (ordered(a) as i128 - ordered(b) as i128).unsigned_abs() as u64
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use std::f64;
+
+    #[test]
+    fn test_next_up_down_across_zero() {
+        let tiny = f64::from_bits(1);
+        assert_eq!(super::next_up(0.0), tiny);
+        assert_eq!(super::next_up(-0.0), tiny);
+        assert_eq!(super::next_down(0.0), -tiny);
+        assert_eq!(super::next_down(-0.0), -tiny);
+        assert_eq!(super::next_up(-tiny), 0.0);
+        assert_eq!(super::next_down(tiny), 0.0);
+        assert_eq!(super::ulps_between(-tiny, tiny), 2);
+        assert_eq!(super::ulps_between(0.0, -0.0), 0);
+    }
+
+    #[test]
+    fn test_next_up_down_across_power_of_two() {
+        assert_eq!(super::next_up(1.0), 1.0 + f64::EPSILON);
+        assert_eq!(super::next_down(1.0), 1.0 - f64::EPSILON / 2.0);
+        assert_eq!(super::next_up(super::next_down(2.0)), 2.0);
+        assert_eq!(super::next_down(super::next_up(2.0)), 2.0);
+        assert_eq!(super::next_up(-1.0), -1.0 + f64::EPSILON / 2.0);
+        assert_eq!(super::next_down(-1.0), -1.0 - f64::EPSILON);
+        assert_eq!(super::ulps_between(super::next_down(4.0), super::next_up(4.0)), 2);
+        assert_eq!(super::ulps_between(1.0, 2.0), 1 << 52);
+    }
+
+    #[test]
+    fn test_next_up_down_near_infinity() {
+        assert_eq!(super::next_up(f64::MAX), f64::INFINITY);
+        assert_eq!(super::next_down(f64::INFINITY), f64::MAX);
+        assert_eq!(super::next_up(f64::INFINITY), f64::INFINITY);
+        assert_eq!(super::next_down(f64::MIN), f64::NEG_INFINITY);
+        assert_eq!(super::next_up(f64::NEG_INFINITY), f64::MIN);
+        assert_eq!(super::next_down(f64::NEG_INFINITY), f64::NEG_INFINITY);
+        assert_eq!(super::ulps_between(f64::MAX, f64::INFINITY), 1);
+        assert_eq!(super::ulps_between(f64::NEG_INFINITY, f64::INFINITY), 2 * f64::INFINITY.to_bits());
+    }
+
+    #[test]
+    fn test_nan() {
+        assert!(super::next_up(f64::NAN).is_nan());
+        assert!(super::next_down(f64::NAN).is_nan());
+        assert_eq!(super::ulps_between(f64::NAN, 1.0), u64::MAX);
+        assert_eq!(super::ulps_between(1.0, f64::NAN), u64::MAX);
+    }
+}
@@ -0,0 +1,100 @@
+//! Provides utility functions for sampling on bounded supports
+
+use crate::euclid::Modulus;
+
+/// Folds `value` back into `[lower, upper]` by repeatedly reflecting it at
+/// the bounds, as if it bounced between two walls. Values within the
+/// interval are returned unchanged. This is useful for random-walk
+/// proposals on bounded supports, where reflection keeps the proposal
+/// symmetric while rejection would not.
+///
+/// # Panics
+///
+/// If `lower` or `upper` are not finite or if `lower >= upper`
+///
+/// # Remarks
+///
+/// Returns `NaN` if `value` is `NaN` or infinite
+///
+/// # Examples
+///
+/// ```
+/// use statrs::sampling;
+///
+/// assert_eq!(sampling::reflect(0.25, 0.0, 1.0), 0.25);
+/// assert_eq!(sampling::reflect(1.25, 0.0, 1.0), 0.75);
+/// assert_eq!(sampling::reflect(-0.25, 0.0, 1.0), 0.25);
+/// assert_eq!(sampling::reflect(2.25, 0.0, 1.0), 0.25);
+/// ```
+pub fn reflect(value: f64, lower: f64, upper: f64) -> f64 {
+    if !lower.is_finite() || !upper.is_finite() || lower >= upper {
+        panic!("bounds must be finite with lower < upper");
+    }
+    if !value.is_finite() {
+        return f64::NAN;
+    }
+    if (lower..=upper).contains(&value) {
+        return value;
+    }
+    // reflection is periodic with period 2 * width, within a period the
+    // value rises from lower to upper and falls back again
+    let width = upper - lower;
+    let offset = (value - lower).modulus(2.0 * width);
+    let folded = if offset > width {
+        2.0 * width - offset
+    } else {
+        offset
+    };
+    (lower + folded).max(lower).min(upper)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::reflect;
+
+    #[test]
+    fn test_reflect_inside() {
+        for &x in &[-2.0, -1.5, 0.0, 0.3, 1.0] {
+            assert_eq!(reflect(x, -2.0, 1.0), x);
+        }
+    }
+
+    #[test]
+    fn test_reflect_far_outside() {
+        assert_almost_eq!(reflect(10.3, 0.0, 1.0), 0.3, 1e-12);
+        assert_almost_eq!(reflect(11.3, 0.0, 1.0), 0.7, 1e-12);
+        assert_almost_eq!(reflect(-10.3, 0.0, 1.0), 0.3, 1e-12);
+        assert_almost_eq!(reflect(-11.3, 0.0, 1.0), 0.7, 1e-12);
+        assert_almost_eq!(reflect(1e6 + 2.5, 2.0, 4.0), 2.5, 1e-9);
+        for &x in &[-1e12, -123.456, 77.7, 1e15] {
+            let y = reflect(x, -3.0, 5.0);
+            assert!((-3.0..=5.0).contains(&y));
+            assert_eq!(reflect(x, -3.0, 5.0), y);
+        }
+    }
+
+    #[test]
+    fn test_reflect_continuous_at_bounds() {
+        let eps = 1e-9;
+        for &bound in &[-2.0, 3.0] {
+            for &k in &[0.0, 5.0, 5.0 * 2.0] {
+                let x = bound + k;
+                let left = reflect(x - eps, -2.0, 3.0);
+                let right = reflect(x + eps, -2.0, 3.0);
+                assert_almost_eq!(left, right, 1e-8);
+            }
+        }
+    }
+
+    #[test]
+    fn test_reflect_non_finite() {
+        assert!(reflect(f64::NAN, 0.0, 1.0).is_nan());
+        assert!(reflect(f64::INFINITY, 0.0, 1.0).is_nan());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_reflect_bad_bounds() {
+        reflect(0.5, 1.0, 1.0);
+    }
+}
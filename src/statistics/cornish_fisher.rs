@@ -0,0 +1,102 @@
+use crate::function::erf;
+use std::f64::consts::SQRT_2;
+
+/// Approximates the `p`-quantile of a distribution with the given mean,
+/// standard deviation, skewness and excess kurtosis by the Cornish-Fisher
+/// expansion around the quantile of the standard normal distribution, e.g.
+/// for data or distributions without a closed-form inverse cdf.
+///
+/// # Panics
+///
+/// If `p` is not in `(0, 1)` or if `std_dev` is `NaN` or negative
+///
+/// # Remarks
+///
+/// The expansion is accurate for moderate skewness and kurtosis and
+/// quantiles that are not too far in the tails. For strongly skewed
+/// distributions the approximation is not guaranteed to be monotone in `p`.
+///
+/// # Formula
+///
+/// ```ignore
+/// μ + σ * (z + (z^2 - 1) * γ1 / 6 + (z^3 - 3z) * γ2 / 24 - (2z^3 - 5z) * γ1^2 / 36)
+/// ```
+///
+/// where `z` is the `p`-quantile of the standard normal distribution, `μ`
+/// is the mean, `σ` is the standard deviation, `γ1` is the skewness and
+/// `γ2` is the excess kurtosis
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::cornish_fisher_quantile;
+/// use statrs::prec;
+///
+/// // without skewness and excess kurtosis this is the normal quantile
+/// let q = cornish_fisher_quantile(0.975, 1.0, 2.0, 0.0, 0.0);
+/// assert!(prec::almost_eq(q, 1.0 + 2.0 * 1.959963984540054, 1e-12));
+/// ```
+pub fn cornish_fisher_quantile(
+    p: f64,
+    mean: f64,
+    std_dev: f64,
+    skewness: f64,
+    excess_kurtosis: f64,
+) -> f64 {
+    if !(p > 0.0 && p < 1.0) {
+        panic!("p must be in (0, 1)");
+    }
+    if std_dev.is_nan() || std_dev < 0.0 {
+        panic!("std_dev must be non-negative");
+    }
+    let z = -SQRT_2 * erf::erfc_inv(2.0 * p);
+    let z2 = z * z;
+    let z3 = z2 * z;
+    let w = z + (z2 - 1.0) * skewness / 6.0 + (z3 - 3.0 * z) * excess_kurtosis / 24.0
+        - (2.0 * z3 - 5.0 * z) * skewness * skewness / 36.0;
+    mean + std_dev * w
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distribution::{ContinuousCDF, Gamma, Normal};
+    use crate::statistics::Distribution;
+
+    #[test]
+    fn test_reduces_to_normal() {
+        let n = Normal::new(3.0, 1.5).unwrap();
+        for &p in [0.001, 0.05, 0.3, 0.5, 0.8, 0.99].iter() {
+            let q = cornish_fisher_quantile(p, 3.0, 1.5, 0.0, 0.0);
+            assert_almost_eq!(n.cdf(q), p, 1e-10);
+        }
+        assert_eq!(cornish_fisher_quantile(0.5, 3.0, 1.5, 0.0, 0.0), 3.0);
+    }
+
+    #[test]
+    fn test_skewed_gamma() {
+        let g = Gamma::new(5.0, 2.0).unwrap();
+        let (mean, std_dev) = (g.mean().unwrap(), g.std_dev().unwrap());
+        let (skewness, excess_kurtosis) = (g.skewness().unwrap(), g.excess_kurtosis().unwrap());
+        for &p in [0.01, 0.05, 0.25, 0.5, 0.75, 0.95, 0.99].iter() {
+            let normal = cornish_fisher_quantile(p, mean, std_dev, 0.0, 0.0);
+            let cf = cornish_fisher_quantile(p, mean, std_dev, skewness, excess_kurtosis);
+            let normal_error = (g.cdf(normal) - p).abs();
+            let cf_error = (g.cdf(cf) - p).abs();
+            assert!(cf_error < normal_error);
+            assert!(cf_error < 2e-3);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_bad_p() {
+        cornish_fisher_quantile(1.0, 0.0, 1.0, 0.0, 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_bad_std_dev() {
+        cornish_fisher_quantile(0.5, 0.0, -1.0, 0.0, 0.0);
+    }
+}
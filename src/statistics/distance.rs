@@ -0,0 +1,283 @@
+use crate::distribution::ContinuousCDF;
+use crate::{Result, StatsError};
+
+/// Computes the 1-Wasserstein (earth mover's) distance between the
+/// distributions `p` and `q` by numerically integrating the absolute
+/// difference of their cdfs over `[lower, upper]`. The bounds should cover
+/// nearly all of the probability mass of both distributions.
+///
+/// # Errors
+///
+/// Returns an error if `lower` or `upper` are not finite or if
+/// `lower >= upper`
+///
+/// # Formula
+///
+/// ```ignore
+/// ∫ |F_p(x) - F_q(x)| dx
+/// ```
+///
+/// where `F_p` and `F_q` are the cdfs of `p` and `q`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::Uniform;
+/// use statrs::statistics::wasserstein_1d;
+/// use statrs::prec;
+///
+/// let p = Uniform::new(0.0, 1.0).unwrap();
+/// let q = Uniform::new(2.0, 3.0).unwrap();
+/// let w = wasserstein_1d(&p, &q, -1.0, 4.0).unwrap();
+/// assert!(prec::almost_eq(w, 2.0, 1e-6));
+/// ```
+pub fn wasserstein_1d<P, Q>(p: &P, q: &Q, lower: f64, upper: f64) -> Result<f64>
+where
+    P: ContinuousCDF<f64, f64>,
+    Q: ContinuousCDF<f64, f64>,
+{
+    if !lower.is_finite() || !upper.is_finite() || lower >= upper {
+        return Err(StatsError::BadParams);
+    }
+    const STEPS: usize = 100_000;
+    let h = (upper - lower) / STEPS as f64;
+    let sum = (0..STEPS).fold(0.0, |acc, i| {
+        let x = lower + (i as f64 + 0.5) * h;
+        acc + (p.cdf(x) - q.cdf(x)).abs()
+    });
+    Ok(sum * h)
+}
+
+/// Computes the 1-Wasserstein (earth mover's) distance between the
+/// empirical distributions of the samples `a` and `b`, i.e. the integral
+/// of the absolute difference of their empirical cdfs. The samples may
+/// differ in size.
+///
+/// Sorts `a` and `b` in place.
+///
+/// # Errors
+///
+/// Returns an error if `a` or `b` are empty
+///
+/// # Remarks
+///
+/// Returns `NaN` if `a` or `b` contain a `NaN` entry
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::wasserstein_1d_samples;
+///
+/// let mut a = [1.0, 0.0];
+/// let mut b = [3.0, 0.0, 2.0, 1.0];
+/// assert_eq!(wasserstein_1d_samples(&mut a, &mut b).unwrap(), 1.0);
+/// ```
+pub fn wasserstein_1d_samples(a: &mut [f64], b: &mut [f64]) -> Result<f64> {
+    if !sort_samples(a, b)? {
+        return Ok(f64::NAN);
+    }
+    let (n_a, n_b) = (a.len() as f64, b.len() as f64);
+    let (mut i, mut j) = (0, 0);
+    let mut x = a[0].min(b[0]);
+    let mut distance = 0.0;
+    while i < a.len() || j < b.len() {
+        // advance past all samples equal to the next breakpoint
+        let next = match (a.get(i), b.get(j)) {
+            (Some(&u), Some(&v)) => u.min(v),
+            (Some(&u), None) => u,
+            (None, Some(&v)) => v,
+            (None, None) => unreachable!(),
+        };
+        distance += (next - x) * (i as f64 / n_a - j as f64 / n_b).abs();
+        while i < a.len() && a[i] == next {
+            i += 1;
+        }
+        while j < b.len() && b[j] == next {
+            j += 1;
+        }
+        x = next;
+    }
+    Ok(distance)
+}
+
+/// Computes the two-sample Cramér–von Mises statistic of the samples `a`
+/// and `b`, measuring the squared difference between their empirical cdfs
+/// over the pooled sample. The samples may differ in size and ties are
+/// assigned average ranks. This matches SciPy's `cramervonmises_2samp`.
+///
+/// Sorts `a` and `b` in place.
+///
+/// # Errors
+///
+/// Returns an error if `a` or `b` are empty
+///
+/// # Remarks
+///
+/// Returns `NaN` if `a` or `b` contain a `NaN` entry
+///
+/// # Formula
+///
+/// ```ignore
+/// U / (n * m * (n + m)) - (4 * n * m - 1) / (6 * (n + m))
+/// U = n * Σ (r_i - i)^2 + m * Σ (s_j - j)^2
+/// ```
+///
+/// where `n` and `m` are the sizes of `a` and `b` and `r_i` and `s_j` are
+/// the ranks of the `i`-th smallest element of `a` and the `j`-th smallest
+/// element of `b` in the pooled sample
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::cramer_von_mises_two_sample;
+/// use statrs::prec;
+///
+/// let mut a = [1.0, 2.0, 3.0];
+/// let mut b = [4.0, 5.0, 6.0];
+/// let t = cramer_von_mises_two_sample(&mut a, &mut b).unwrap();
+/// assert!(prec::almost_eq(t, 0.5277777777777777, 1e-15));
+/// ```
+pub fn cramer_von_mises_two_sample(a: &mut [f64], b: &mut [f64]) -> Result<f64> {
+    if !sort_samples(a, b)? {
+        return Ok(f64::NAN);
+    }
+    let (n, m) = (a.len() as f64, b.len() as f64);
+    let (mut i, mut j) = (0, 0);
+    let (mut sum_a, mut sum_b) = (0.0, 0.0);
+    while i < a.len() || j < b.len() {
+        let next = match (a.get(i), b.get(j)) {
+            (Some(&u), Some(&v)) => u.min(v),
+            (Some(&u), None) => u,
+            (None, Some(&v)) => v,
+            (None, None) => unreachable!(),
+        };
+        let ties_a = a[i..].iter().take_while(|&&x| x == next).count();
+        let ties_b = b[j..].iter().take_while(|&&x| x == next).count();
+        // average of the pooled ranks i + j + 1, ..., i + j + ties
+        let rank = (i + j) as f64 + (ties_a + ties_b + 1) as f64 / 2.0;
+        for k in 0..ties_a {
+            let d = rank - (i + k + 1) as f64;
+            sum_a += d * d;
+        }
+        for k in 0..ties_b {
+            let d = rank - (j + k + 1) as f64;
+            sum_b += d * d;
+        }
+        i += ties_a;
+        j += ties_b;
+    }
+    let u = n * sum_a + m * sum_b;
+    Ok(u / (n * m * (n + m)) - (4.0 * n * m - 1.0) / (6.0 * (n + m)))
+}
+
+/// Validates and sorts the samples, returning `false` without sorting if
+/// any of them is `NaN`
+fn sort_samples(a: &mut [f64], b: &mut [f64]) -> Result<bool> {
+    if a.is_empty() || b.is_empty() {
+        return Err(StatsError::BadParams);
+    }
+    if a.iter().chain(b.iter()).any(|x| x.is_nan()) {
+        return Ok(false);
+    }
+    a.sort_by(|x, y| x.partial_cmp(y).unwrap());
+    b.sort_by(|x, y| x.partial_cmp(y).unwrap());
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distribution::{Exp, Normal};
+
+    #[test]
+    fn test_wasserstein_1d_normal() {
+        let cases = [
+            ((0.0, 1.0), (0.0, 1.0)),
+            ((0.0, 1.0), (2.0, 1.0)),
+            ((0.0, 1.0), (0.0, 2.0)),
+            ((-1.0, 0.5), (1.5, 3.0)),
+        ];
+        for &((m1, s1), (m2, s2)) in cases.iter() {
+            let p = Normal::new(m1, s1).unwrap();
+            let q = Normal::new(m2, s2).unwrap();
+            let numeric = wasserstein_1d(&p, &q, -30.0, 30.0).unwrap();
+            assert_almost_eq!(numeric, p.wasserstein_1d(&q), 1e-7);
+        }
+    }
+
+    #[test]
+    fn test_wasserstein_1d_same() {
+        let p = Exp::new(2.0).unwrap();
+        assert_eq!(wasserstein_1d(&p, &p, 0.0, 50.0).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_wasserstein_1d_bad_bounds() {
+        let p = Normal::new(0.0, 1.0).unwrap();
+        assert!(wasserstein_1d(&p, &p, 1.0, -1.0).is_err());
+        assert!(wasserstein_1d(&p, &p, 1.0, 1.0).is_err());
+        assert!(wasserstein_1d(&p, &p, f64::NEG_INFINITY, 1.0).is_err());
+        assert!(wasserstein_1d(&p, &p, 0.0, f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_wasserstein_1d_samples() {
+        let mut a = [3.0, 0.0, 1.0];
+        let mut b = [8.0, 6.0, 5.0];
+        assert_eq!(wasserstein_1d_samples(&mut a, &mut b).unwrap(), 5.0);
+        assert_eq!(a, [0.0, 1.0, 3.0]);
+        assert_eq!(b, [5.0, 6.0, 8.0]);
+        // the empirical cdfs differ by 1/4, 1/2 and 1/4 on [0, 1), [1, 2)
+        // and [2, 3)
+        let mut a = [0.0, 1.0];
+        let mut b = [0.0, 1.0, 2.0, 3.0];
+        assert_eq!(wasserstein_1d_samples(&mut a, &mut b).unwrap(), 1.0);
+        assert_eq!(wasserstein_1d_samples(&mut b, &mut a).unwrap(), 1.0);
+        // ties within and across the samples
+        let mut a = [1.0, 1.0, 2.0];
+        let mut b = [1.0, 4.0];
+        let expected = (2.0 / 3.0 - 1.0 / 2.0) + (1.0 - 1.0 / 2.0) * 2.0;
+        assert_almost_eq!(
+            wasserstein_1d_samples(&mut a, &mut b).unwrap(),
+            expected,
+            1e-15
+        );
+    }
+
+    #[test]
+    fn test_wasserstein_1d_samples_identical() {
+        let mut a = [2.5, -1.0, 4.0, 2.5, 0.0];
+        let mut b = a;
+        assert_eq!(wasserstein_1d_samples(&mut a, &mut b).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_cramer_von_mises_two_sample() {
+        // scipy.stats.cramervonmises_2samp([1, 2, 3], [4, 5, 6]).statistic
+        let mut a = [3.0, 1.0, 2.0];
+        let mut b = [4.0, 6.0, 5.0];
+        let t = cramer_von_mises_two_sample(&mut a, &mut b).unwrap();
+        assert_almost_eq!(t, 0.5277777777777777, 1e-15);
+        // with ties the identical samples have a statistic of 0
+        let mut a = [1.0, 2.0, 3.0];
+        let mut b = [1.0, 2.0, 3.0];
+        let t = cramer_von_mises_two_sample(&mut a, &mut b).unwrap();
+        assert_almost_eq!(t, 0.0, 1e-15);
+        // unequal sizes: pooled ranks of a are 1, 3 and of b are 2, 4, 5
+        // U = 2 * (0 + 1) + 3 * (1 + 4 + 4) = 29
+        let mut a = [0.0, 2.0];
+        let mut b = [1.0, 3.0, 4.0];
+        let t = cramer_von_mises_two_sample(&mut a, &mut b).unwrap();
+        assert_almost_eq!(t, 29.0 / 30.0 - 23.0 / 30.0, 1e-15);
+    }
+
+    #[test]
+    fn test_sample_distances_bad_params() {
+        assert!(wasserstein_1d_samples(&mut [], &mut [1.0]).is_err());
+        assert!(cramer_von_mises_two_sample(&mut [1.0], &mut []).is_err());
+        let w = wasserstein_1d_samples(&mut [f64::NAN], &mut [1.0]).unwrap();
+        assert!(w.is_nan());
+        let t = cramer_von_mises_two_sample(&mut [1.0], &mut [f64::NAN]).unwrap();
+        assert!(t.is_nan());
+    }
+}
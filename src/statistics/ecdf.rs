@@ -0,0 +1,153 @@
+/// Computes a confidence band for the cumulative distribution function of
+/// the population that `data` was sampled from, using the
+/// Dvoretzky–Kiefer–Wolfowitz inequality. With probability at least
+/// `confidence` the true cdf lies within the band everywhere.
+///
+/// Returns the sorted samples together with the lower and upper bounds of
+/// the band at each of them. Between two consecutive samples the band is
+/// constant and equal to its value at the smaller one, as is the empirical
+/// cdf, and below the smallest sample the band is `[0, ε]`.
+///
+/// Sorts `data` in place.
+///
+/// # Panics
+///
+/// If `confidence` is not in `(0, 1)` or if `data` contains `NaN`
+///
+/// # Formula
+///
+/// ```ignore
+/// ε = sqrt(ln(2 / α) / (2n))
+/// lower = max(F_n(x) - ε, 0)
+/// upper = min(F_n(x) + ε, 1)
+/// ```
+///
+/// where `α = 1 - confidence`, `n` is the number of samples and `F_n` is
+/// the empirical cdf
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::dkw_band;
+/// use statrs::prec;
+///
+/// let mut data = [3.0, 1.0, 2.0, 4.0];
+/// let (xs, lower, upper) = dkw_band(&mut data, 0.95);
+/// let eps = (2f64 / 0.05).ln().sqrt() / 8f64.sqrt();
+/// assert_eq!(xs, vec![1.0, 2.0, 3.0, 4.0]);
+/// assert_eq!(lower[0], 0.0);
+/// assert!(prec::almost_eq(upper[0], 0.25 + eps, 1e-15));
+/// assert!(prec::almost_eq(lower[3], 1.0 - eps, 1e-15));
+/// assert_eq!(upper[3], 1.0);
+/// ```
+pub fn dkw_band(data: &mut [f64], confidence: f64) -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+    if confidence.is_nan() || confidence <= 0.0 || confidence >= 1.0 {
+        panic!("confidence must be in (0, 1)");
+    }
+    if data.iter().any(|x| x.is_nan()) {
+        panic!("data must not contain NaN");
+    }
+    data.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let n = data.len();
+    let eps = ((2.0 / (1.0 - confidence)).ln() / (2.0 * n as f64)).sqrt();
+    let mut lower = vec![0.0; n];
+    let mut upper = vec![0.0; n];
+    // walk backwards so that tied samples share the empirical cdf of the
+    // last of them
+    let mut ecdf = 1.0;
+    for i in (0..n).rev() {
+        if i + 1 < n && data[i] < data[i + 1] {
+            ecdf = (i + 1) as f64 / n as f64;
+        }
+        lower[i] = (ecdf - eps).max(0.0);
+        upper[i] = (ecdf + eps).min(1.0);
+    }
+    (data.to_vec(), lower, upper)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distribution::{ContinuousCDF, Normal};
+    use ::rand::distributions::Distribution;
+    use ::rand::rngs::StdRng;
+    use ::rand::SeedableRng;
+
+    #[test]
+    fn test_dkw_band() {
+        let mut data = [0.5, -1.0, 2.0, 0.0, 1.0];
+        let (xs, lower, upper) = dkw_band(&mut data, 0.9);
+        let eps = (20f64.ln() / 10.0).sqrt();
+        assert_eq!(xs, vec![-1.0, 0.0, 0.5, 1.0, 2.0]);
+        assert_eq!(data, [-1.0, 0.0, 0.5, 1.0, 2.0]);
+        for i in 0..5 {
+            let ecdf = (i + 1) as f64 / 5.0;
+            assert_almost_eq!(lower[i], (ecdf - eps).max(0.0), 1e-15);
+            assert_almost_eq!(upper[i], (ecdf + eps).min(1.0), 1e-15);
+        }
+    }
+
+    #[test]
+    fn test_dkw_band_ties() {
+        let mut data = [1.0, 2.0, 2.0, 2.0];
+        let (_, lower, upper) = dkw_band(&mut data, 0.5);
+        let eps = (4f64.ln() / 8.0).sqrt();
+        assert_almost_eq!(upper[0], 0.25 + eps, 1e-15);
+        for i in 1..4 {
+            assert_almost_eq!(lower[i], 1.0 - eps, 1e-15);
+            assert_eq!(upper[i], 1.0);
+        }
+    }
+
+    #[test]
+    fn test_dkw_band_narrows() {
+        let n = Normal::new(0.0, 1.0).unwrap();
+        let mut r: StdRng = SeedableRng::seed_from_u64(37);
+        let mut small: Vec<f64> = (0..100).map(|_| n.sample(&mut r)).collect();
+        let mut large: Vec<f64> = (0..10_000).map(|_| n.sample(&mut r)).collect();
+        let (_, lower, upper) = dkw_band(&mut small, 0.95);
+        let width_small = upper[50] - lower[50];
+        let (_, lower, upper) = dkw_band(&mut large, 0.95);
+        let width_large = upper[5000] - lower[5000];
+        assert_almost_eq!(width_small / width_large, 10.0, 1e-10);
+        assert!(dkw_band(&mut [], 0.95).0.is_empty());
+    }
+
+    #[test]
+    fn test_dkw_band_coverage() {
+        let n = Normal::new(2.0, 3.0).unwrap();
+        let mut r: StdRng = SeedableRng::seed_from_u64(41);
+        let trials = 1000;
+        let mut covered = 0;
+        for _ in 0..trials {
+            let mut data: Vec<f64> = (0..100).map(|_| n.sample(&mut r)).collect();
+            let (xs, lower, upper) = dkw_band(&mut data, 0.95);
+            // the band is a step function, so the true cdf must lie below
+            // the upper bound at each sample and above the lower bound of
+            // the preceding step just before it
+            let inside = (0..xs.len()).all(|i| {
+                let f = n.cdf(xs[i]);
+                let prev_lower = if i == 0 { 0.0 } else { lower[i - 1] };
+                f <= upper[i] && f >= prev_lower
+            });
+            if inside {
+                covered += 1;
+            }
+        }
+        // the band is conservative, so the coverage is at least nominal up
+        // to the simulation error
+        assert!(covered as f64 / trials as f64 >= 0.93);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_dkw_band_bad_confidence() {
+        dkw_band(&mut [1.0, 2.0], 1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_dkw_band_nan() {
+        dkw_band(&mut [1.0, f64::NAN], 0.95);
+    }
+}
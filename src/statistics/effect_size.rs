@@ -0,0 +1,110 @@
+use crate::statistics::Statistics;
+use crate::{Result, StatsError};
+
+/// Computes Cohen's d, the standardized difference between the means of
+/// the samples `a` and `b`, using the pooled standard deviation.
+///
+/// # Errors
+///
+/// Returns an error if `a` or `b` contain fewer than two elements
+///
+/// # Formula
+///
+/// ```ignore
+/// (mean(a) - mean(b)) / s
+/// s = sqrt(((n_a - 1) * var(a) + (n_b - 1) * var(b)) / (n_a + n_b - 2))
+/// ```
+///
+/// where `n_a` and `n_b` are the sample sizes and `var` is the unbiased
+/// sample variance
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::cohens_d;
+/// use statrs::prec;
+///
+/// let a = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+/// let b = [1.0, 2.0, 3.0, 4.0, 5.0];
+/// let d = cohens_d(&a, &b).unwrap();
+/// assert!(prec::almost_eq(d, 2.0 / (42.0f64 / 11.0).sqrt(), 1e-15));
+/// ```
+pub fn cohens_d(a: &[f64], b: &[f64]) -> Result<f64> {
+    if a.len() < 2 || b.len() < 2 {
+        return Err(StatsError::BadParams);
+    }
+    let n_a = a.len() as f64;
+    let n_b = b.len() as f64;
+    let pooled_var = ((n_a - 1.0) * a.variance() + (n_b - 1.0) * b.variance()) / (n_a + n_b - 2.0);
+    Ok((a.mean() - b.mean()) / pooled_var.sqrt())
+}
+
+/// Computes Hedges' g, Cohen's d corrected for its bias in small samples.
+///
+/// # Errors
+///
+/// Returns an error if `a` or `b` contain fewer than two elements
+///
+/// # Formula
+///
+/// ```ignore
+/// d * (1 - 3 / (4 * (n_a + n_b) - 9))
+/// ```
+///
+/// where `d` is Cohen's d and `n_a` and `n_b` are the sample sizes
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::{cohens_d, hedges_g};
+///
+/// let a = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+/// let b = [1.0, 2.0, 3.0, 4.0, 5.0];
+/// assert!(hedges_g(&a, &b).unwrap() < cohens_d(&a, &b).unwrap());
+/// ```
+pub fn hedges_g(a: &[f64], b: &[f64]) -> Result<f64> {
+    let d = cohens_d(a, b)?;
+    let n = (a.len() + b.len()) as f64;
+    Ok(d * (1.0 - 3.0 / (4.0 * n - 9.0)))
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::{cohens_d, hedges_g};
+
+    #[test]
+    fn test_cohens_d() {
+        let a = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        let b = [1.0, 2.0, 3.0, 4.0, 5.0];
+        assert_almost_eq!(cohens_d(&a, &b).unwrap(), 1.023532631438318, 1e-14);
+        assert_almost_eq!(cohens_d(&b, &a).unwrap(), -1.023532631438318, 1e-14);
+        assert_eq!(cohens_d(&a, &a).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_hedges_g() {
+        let a = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        let b = [1.0, 2.0, 3.0, 4.0, 5.0];
+        assert_almost_eq!(hedges_g(&a, &b).unwrap(), 0.9521233780821562, 1e-14);
+        assert_almost_eq!(hedges_g(&b, &a).unwrap(), -0.9521233780821562, 1e-14);
+    }
+
+    #[test]
+    fn test_hedges_g_smaller_than_cohens_d() {
+        let a = [1.1, 2.3, 2.9];
+        let b = [3.5, 4.0, 4.2, 5.1];
+        let d = cohens_d(&a, &b).unwrap();
+        let g = hedges_g(&a, &b).unwrap();
+        assert!(g.abs() < d.abs());
+        assert!(g.abs() > 0.8 * d.abs());
+    }
+
+    #[test]
+    fn test_too_few_points() {
+        assert!(cohens_d(&[1.0], &[1.0, 2.0]).is_err());
+        assert!(cohens_d(&[1.0, 2.0], &[]).is_err());
+        assert!(hedges_g(&[1.0], &[1.0, 2.0]).is_err());
+        assert!(hedges_g(&[1.0, 2.0], &[2.0]).is_err());
+    }
+}
@@ -0,0 +1,106 @@
+use crate::distribution::Continuous;
+
+/// Computes the logarithm of the density ratio `p(x) / q(x)` as the
+/// difference of the log densities, which does not underflow when the
+/// densities themselves do.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::Normal;
+/// use statrs::statistics::log_density_ratio;
+/// use statrs::prec;
+///
+/// let p = Normal::new(0.0, 1.0).unwrap();
+/// let q = Normal::new(1.0, 1.0).unwrap();
+/// // p(40) and q(40) both underflow to zero
+/// assert!(prec::almost_eq(log_density_ratio(&p, &q, 40.0), -39.5, 1e-12));
+/// ```
+pub fn log_density_ratio<P, Q>(p: &P, q: &Q, x: f64) -> f64
+where
+    P: Continuous<f64, f64>,
+    Q: Continuous<f64, f64>,
+{
+    p.ln_pdf(x) - q.ln_pdf(x)
+}
+
+/// Computes the self-normalized importance weights of the samples in `xs`,
+/// drawn from the proposal `q`, for the target `p`. The weights are
+/// proportional to `p(x) / q(x)` and sum to one; they are normalized in log
+/// space so that they remain accurate when the density ratios underflow or
+/// overflow.
+///
+/// # Remarks
+///
+/// Returns an empty vector if `xs` is empty and `NaN` weights if no sample
+/// has a finite positive density ratio
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::Normal;
+/// use statrs::statistics::importance_weights;
+///
+/// let p = Normal::new(0.0, 1.0).unwrap();
+/// let q = Normal::new(0.0, 2.0).unwrap();
+/// let weights = importance_weights(&p, &q, &[-1.0, 0.0, 1.0]);
+/// assert!(weights[1] > weights[0]);
+/// assert_eq!(weights[0], weights[2]);
+/// ```
+pub fn importance_weights<P, Q>(p: &P, q: &Q, xs: &[f64]) -> Vec<f64>
+where
+    P: Continuous<f64, f64>,
+    Q: Continuous<f64, f64>,
+{
+    let log_ratios: Vec<f64> = xs.iter().map(|&x| log_density_ratio(p, q, x)).collect();
+    let max = log_ratios.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let unnormalized: Vec<f64> = log_ratios.iter().map(|&r| (r - max).exp()).collect();
+    let sum: f64 = unnormalized.iter().sum();
+    unnormalized.into_iter().map(|w| w / sum).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distribution::{Exp, Normal};
+    use rand::distributions::Distribution;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_log_density_ratio() {
+        let p = Normal::new(0.0, 1.0).unwrap();
+        let q = Exp::new(2.0).unwrap();
+        for &x in &[0.1, 1.0, 5.0] {
+            let expected = (p.pdf(x) / q.pdf(x)).ln();
+            assert_almost_eq!(log_density_ratio(&p, &q, x), expected, 1e-12);
+        }
+        assert_eq!(log_density_ratio(&p, &p, 3.0), 0.0);
+    }
+
+    #[test]
+    fn test_importance_weights_normalized() {
+        let p = Normal::new(0.0, 1.0).unwrap();
+        let q = Normal::new(0.0, 0.01).unwrap();
+        // the proposal density underflows to zero at 1.0 and 2.0, so the raw
+        // density ratios there are infinite, while their logs are finite and
+        // the one at 2.0 dominates
+        let weights = importance_weights(&p, &q, &[0.0, 1.0, 2.0]);
+        assert_almost_eq!(weights.iter().sum::<f64>(), 1.0, 1e-15);
+        assert!(weights.iter().all(|w| w.is_finite()));
+        assert_almost_eq!(weights[2], 1.0, 1e-15);
+        assert!(importance_weights(&p, &q, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_importance_weighted_mean() {
+        // estimate the mean of the target from a shifted, wider proposal
+        let p = Normal::new(1.5, 1.0).unwrap();
+        let q = Normal::new(0.0, 2.0).unwrap();
+        let mut r: StdRng = SeedableRng::seed_from_u64(11);
+        let xs: Vec<f64> = (0..100_000).map(|_| q.sample(&mut r)).collect();
+        let weights = importance_weights(&p, &q, &xs);
+        let mean: f64 = xs.iter().zip(weights.iter()).map(|(x, w)| x * w).sum();
+        assert_almost_eq!(mean, 1.5, 0.02);
+    }
+}
@@ -0,0 +1,119 @@
+/// Computes the jackknife estimate of `statistic` on `data` and its
+/// standard error, by recomputing the statistic on each of the `n` samples
+/// obtained by leaving out one observation. The estimate is corrected for
+/// the first-order bias of the statistic.
+///
+/// Evaluates `statistic` `n + 1` times, on slices of length `n` and `n - 1`.
+///
+/// # Remarks
+///
+/// Returns `(NaN, NaN)` if `data` has fewer than two elements
+///
+/// # Formula
+///
+/// ```ignore
+/// θ_jack = n * θ - (n - 1) * θ_(.)
+/// se = sqrt((n - 1) / n * Σ (θ_(i) - θ_(.))^2)
+/// ```
+///
+/// where `θ` is the statistic of `data`, `θ_(i)` is the statistic of
+/// `data` without the `i`-th observation and `θ_(.)` is the mean of the
+/// `θ_(i)`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::{jackknife, Statistics};
+/// use statrs::prec;
+///
+/// let data = [1.0, 2.0, 4.0, 7.0];
+/// let (estimate, se) = jackknife(&data, |xs| xs.mean());
+/// assert!(prec::almost_eq(estimate, 3.5, 1e-14));
+/// // the standard error of the mean is s / sqrt(n)
+/// assert!(prec::almost_eq(se, data.std_dev() / 2.0, 1e-14));
+/// ```
+pub fn jackknife<F>(data: &[f64], statistic: F) -> (f64, f64)
+where
+    F: Fn(&[f64]) -> f64,
+{
+    let n = data.len();
+    if n < 2 {
+        return (f64::NAN, f64::NAN);
+    }
+    let full = statistic(data);
+    let mut subsample = data[1..].to_vec();
+    let mut leave_one_out = Vec::with_capacity(n);
+    for i in 0..n {
+        // subsample holds data without the i-th element, so swapping the
+        // i-th element back in for the (i + 1)-th yields the next subsample
+        if i > 0 {
+            subsample[i - 1] = data[i - 1];
+        }
+        leave_one_out.push(statistic(&subsample));
+    }
+    let nf = n as f64;
+    let mean = leave_one_out.iter().sum::<f64>() / nf;
+    let ss = leave_one_out
+        .iter()
+        .map(|t| (t - mean) * (t - mean))
+        .sum::<f64>();
+    (nf * full - (nf - 1.0) * mean, ((nf - 1.0) / nf * ss).sqrt())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::statistics::Statistics;
+
+    #[test]
+    fn test_jackknife_mean() {
+        let data = [2.0, 3.5, -1.0, 8.0, 4.25, 0.5, 6.0];
+        let (estimate, se) = jackknife(&data, |xs| xs.mean());
+        assert_almost_eq!(estimate, data.mean(), 1e-14);
+        assert_almost_eq!(se, data.std_dev() / (data.len() as f64).sqrt(), 1e-14);
+    }
+
+    #[test]
+    fn test_jackknife_by_hand() {
+        // leave-one-out means are 3, 2, 1, whose mean is 2 and whose squared
+        // deviations sum to 2, so se = sqrt(2 / 3 * 2)
+        let data = [0.0, 2.0, 4.0];
+        let (estimate, se) = jackknife(&data, |xs| xs.mean());
+        assert_almost_eq!(estimate, 2.0, 1e-15);
+        assert_almost_eq!(se, (4.0f64 / 3.0).sqrt(), 1e-15);
+    }
+
+    #[test]
+    fn test_jackknife_bias_correction() {
+        // the jackknife removes the bias of the plug-in variance exactly,
+        // yielding the unbiased sample variance
+        let data = [1.0, 4.0, 2.0, 8.0, 5.0];
+        let plug_in = |xs: &[f64]| xs.population_variance();
+        let (estimate, _) = jackknife(&data, plug_in);
+        assert_almost_eq!(estimate, data.variance(), 1e-13);
+    }
+
+    #[test]
+    fn test_jackknife_subsamples() {
+        let data = [1.0, 2.0, 3.0, 4.0];
+        let seen = std::cell::RefCell::new(Vec::new());
+        jackknife(&data, |xs| {
+            seen.borrow_mut().push(xs.to_vec());
+            0.0
+        });
+        let seen = seen.into_inner();
+        assert_eq!(seen[0], vec![1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(seen[1], vec![2.0, 3.0, 4.0]);
+        assert_eq!(seen[2], vec![1.0, 3.0, 4.0]);
+        assert_eq!(seen[3], vec![1.0, 2.0, 4.0]);
+        assert_eq!(seen[4], vec![1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn test_jackknife_too_short() {
+        let (estimate, se) = jackknife(&[1.0], |xs| xs.mean());
+        assert!(estimate.is_nan() && se.is_nan());
+        let (estimate, se) = jackknife(&[], |xs| xs.mean());
+        assert!(estimate.is_nan() && se.is_nan());
+    }
+}
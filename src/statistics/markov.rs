@@ -0,0 +1,142 @@
+use crate::{Result, StatsError};
+use nalgebra::DMatrix;
+
+/// Computes the stationary distribution `π` of the Markov chain with the
+/// row-stochastic transition matrix `transition`, i.e. the left eigenvector
+/// of the matrix with eigenvalue `1` normalized to sum to one, where
+/// `transition[(i, j)]` is the probability of moving from state `i` to
+/// state `j`.
+///
+/// # Remarks
+///
+/// The distribution is found by power iteration on the lazy chain
+/// `(P + I) / 2`, which has the same stationary distributions as `P` but
+/// also converges for periodic chains. For a reducible chain with several
+/// stationary distributions, one of them is returned.
+///
+/// # Errors
+///
+/// Returns an error if `transition` is empty or not square, if any entry
+/// is negative or not finite, if any row does not sum to `1` within
+/// `1e-10`, or if the iteration does not converge
+///
+/// # Formula
+///
+/// ```ignore
+/// π P = π, Σ π_i = 1
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use nalgebra::DMatrix;
+/// use statrs::statistics::stationary_distribution;
+/// use statrs::prec;
+///
+/// let p = DMatrix::from_row_slice(2, 2, &[0.9, 0.1, 0.5, 0.5]);
+/// let pi = stationary_distribution(&p).unwrap();
+/// assert!(prec::almost_eq(pi[0], 5.0 / 6.0, 1e-12));
+/// assert!(prec::almost_eq(pi[1], 1.0 / 6.0, 1e-12));
+/// ```
+pub fn stationary_distribution(transition: &DMatrix<f64>) -> Result<Vec<f64>> {
+    let n = transition.nrows();
+    if n == 0
+        || transition.ncols() != n
+        || transition.iter().any(|&p| !p.is_finite() || p < 0.0)
+        || transition
+            .row_iter()
+            .any(|row| (row.sum() - 1.0).abs() > 1e-10)
+    {
+        return Err(StatsError::BadParams);
+    }
+    let lazy_t = (transition.transpose() + DMatrix::identity(n, n)) * 0.5;
+    let mut pi = nalgebra::DVector::from_element(n, 1.0 / n as f64);
+    for _ in 0..100_000 {
+        let mut next = &lazy_t * &pi;
+        next /= next.sum();
+        let change = (&next - &pi).abs().sum();
+        pi = next;
+        if change <= 1e-14 {
+            return Ok(pi.iter().cloned().collect());
+        }
+    }
+    Err(StatsError::BadParams)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_two_state() {
+        // the stationary distribution of [[1 - a, a], [b, 1 - b]] is
+        // (b, a) / (a + b)
+        let (a, b) = (0.3, 0.05);
+        let p = DMatrix::from_row_slice(2, 2, &[1.0 - a, a, b, 1.0 - b]);
+        let pi = stationary_distribution(&p).unwrap();
+        assert_almost_eq!(pi[0], b / (a + b), 1e-12);
+        assert_almost_eq!(pi[1], a / (a + b), 1e-12);
+    }
+
+    #[test]
+    fn test_is_stationary() {
+        let p = DMatrix::from_row_slice(3, 3, &[0.5, 0.25, 0.25, 0.1, 0.8, 0.1, 0.3, 0.3, 0.4]);
+        let pi = stationary_distribution(&p).unwrap();
+        assert_almost_eq!(pi.iter().sum::<f64>(), 1.0, 1e-14);
+        let row = nalgebra::RowDVector::from_row_slice(&pi);
+        let next = &row * &p;
+        for j in 0..3 {
+            assert_almost_eq!(next[j], pi[j], 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_periodic() {
+        let p = DMatrix::from_row_slice(2, 2, &[0.0, 1.0, 1.0, 0.0]);
+        let pi = stationary_distribution(&p).unwrap();
+        assert_almost_eq!(pi[0], 0.5, 1e-15);
+        assert_almost_eq!(pi[1], 0.5, 1e-15);
+        let p = DMatrix::from_row_slice(3, 3, &[0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0]);
+        let pi = stationary_distribution(&p).unwrap();
+        for &x in &pi {
+            assert_almost_eq!(x, 1.0 / 3.0, 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_absorbing() {
+        let p = DMatrix::from_row_slice(2, 2, &[1.0, 0.0, 0.4, 0.6]);
+        let pi = stationary_distribution(&p).unwrap();
+        assert_almost_eq!(pi[0], 1.0, 1e-12);
+        assert_almost_eq!(pi[1], 0.0, 1e-12);
+    }
+
+    #[test]
+    fn test_larger_chain() {
+        // a random walk on 50 states with reflecting boundaries
+        let n = 50;
+        let mut p = DMatrix::zeros(n, n);
+        for i in 0..n {
+            p[(i, (i + 1).min(n - 1))] += 0.6;
+            p[(i, i.saturating_sub(1))] += 0.4;
+        }
+        let pi = stationary_distribution(&p).unwrap();
+        // detailed balance gives π_(i + 1) / π_i = 0.6 / 0.4
+        for i in 0..n - 1 {
+            assert_almost_eq!(pi[i + 1] / pi[i], 1.5, 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_bad_transition() {
+        let not_stochastic = DMatrix::from_row_slice(2, 2, &[0.5, 0.4, 0.5, 0.5]);
+        assert!(stationary_distribution(&not_stochastic).is_err());
+        let negative = DMatrix::from_row_slice(2, 2, &[1.5, -0.5, 0.5, 0.5]);
+        assert!(stationary_distribution(&negative).is_err());
+        let nan = DMatrix::from_row_slice(2, 2, &[f64::NAN, 1.0, 0.5, 0.5]);
+        assert!(stationary_distribution(&nan).is_err());
+        let not_square = DMatrix::from_row_slice(1, 2, &[0.5, 0.5]);
+        assert!(stationary_distribution(&not_square).is_err());
+        assert!(stationary_distribution(&DMatrix::zeros(0, 0)).is_err());
+    }
+}
@@ -0,0 +1,111 @@
+use crate::statistics::Statistics;
+use crate::{Result, StatsError};
+
+/// Computes the geometric mean of `data`, i.e. `exp(mean(ln x))`.
+///
+/// # Errors
+///
+/// Returns an error if `data` is empty or if any entry is not strictly
+/// positive, including `NaN` entries
+///
+/// # Remarks
+///
+/// Unlike [`Statistics::geometric_mean`](crate::statistics::Statistics::geometric_mean),
+/// which returns `NaN` or `0` for such data, invalid input is reported as
+/// an error.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::prec;
+/// use statrs::statistics::geometric_mean;
+///
+/// let data = [1.0, 2.0, 4.0, 8.0];
+/// assert!(prec::almost_eq(geometric_mean(&data).unwrap(), 8f64.sqrt(), 1e-15));
+/// assert!(geometric_mean(&[1.0, 0.0]).is_err());
+/// ```
+pub fn geometric_mean(data: &[f64]) -> Result<f64> {
+    check_positive(data)?;
+    Ok(data.geometric_mean())
+}
+
+/// Computes the harmonic mean of `data`, i.e. `n / Σ(1 / x)`.
+///
+/// # Errors
+///
+/// Returns an error if `data` is empty or if any entry is not strictly
+/// positive, including `NaN` entries
+///
+/// # Remarks
+///
+/// Unlike [`Statistics::harmonic_mean`](crate::statistics::Statistics::harmonic_mean),
+/// which returns `NaN` or `0` for such data, invalid input is reported as
+/// an error.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::harmonic_mean;
+///
+/// let data = [1.0, 2.0, 4.0];
+/// assert_eq!(harmonic_mean(&data).unwrap(), 12.0 / 7.0);
+/// assert!(harmonic_mean(&[1.0, -2.0]).is_err());
+/// ```
+pub fn harmonic_mean(data: &[f64]) -> Result<f64> {
+    check_positive(data)?;
+    Ok(data.harmonic_mean())
+}
+
+/// Checks that `data` is non-empty and all entries are strictly positive
+fn check_positive(data: &[f64]) -> Result<()> {
+    if data.is_empty() || !data.iter().all(|&x| x > 0.0) {
+        return Err(StatsError::BadParams);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_geometric_mean() {
+        assert_almost_eq!(geometric_mean(&[5.0]).unwrap(), 5.0, 1e-15);
+        // (2 * 8)^(1/2) == 4
+        assert_almost_eq!(geometric_mean(&[2.0, 8.0]).unwrap(), 4.0, 1e-15);
+        // (1 * 3 * 9)^(1/3) == 3
+        assert_almost_eq!(geometric_mean(&[1.0, 3.0, 9.0]).unwrap(), 3.0, 1e-15);
+        // 6^(1/3) == 1.8171205928321397
+        assert_almost_eq!(
+            geometric_mean(&[1.0, 2.0, 3.0]).unwrap(),
+            1.8171205928321397,
+            1e-15
+        );
+    }
+
+    #[test]
+    fn test_harmonic_mean() {
+        assert_eq!(harmonic_mean(&[5.0]).unwrap(), 5.0);
+        // 2 / (1/2 + 1/8) == 3.2
+        assert_almost_eq!(harmonic_mean(&[2.0, 8.0]).unwrap(), 3.2, 1e-15);
+        // 3 / (1 + 1/2 + 1/3) == 18 / 11
+        assert_almost_eq!(harmonic_mean(&[1.0, 2.0, 3.0]).unwrap(), 18.0 / 11.0, 1e-15);
+    }
+
+    #[test]
+    fn test_mean_inequality() {
+        let data = [0.5, 1.5, 2.0, 7.0, 11.0];
+        let arithmetic = data.iter().sum::<f64>() / data.len() as f64;
+        let geometric = geometric_mean(&data).unwrap();
+        let harmonic = harmonic_mean(&data).unwrap();
+        assert!(harmonic < geometric && geometric < arithmetic);
+    }
+
+    #[test]
+    fn test_bad_data() {
+        for data in [&[][..], &[1.0, 0.0], &[1.0, -2.0], &[1.0, f64::NAN]].iter() {
+            assert!(geometric_mean(data).is_err());
+            assert!(harmonic_mean(data).is_err());
+        }
+    }
+}
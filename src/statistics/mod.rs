@@ -1,14 +1,38 @@
 //! Provides traits for statistical computation
 
+pub use self::cornish_fisher::*;
+pub use self::distance::*;
+pub use self::ecdf::*;
+pub use self::effect_size::*;
+pub use self::importance::*;
 pub use self::iter_statistics::*;
+pub use self::jackknife::*;
+pub use self::markov::*;
+pub use self::means::*;
 pub use self::order_statistics::*;
+pub use self::robust::*;
 pub use self::slice_statistics::*;
 pub use self::statistics::*;
+pub use self::survival::*;
+pub use self::tail::*;
 pub use self::traits::*;
+pub use self::trimmed::*;
 
+mod cornish_fisher;
+mod distance;
+mod ecdf;
+mod effect_size;
+mod importance;
 mod iter_statistics;
+mod jackknife;
+mod markov;
+mod means;
 mod order_statistics;
+mod robust;
 // TODO: fix later
 mod slice_statistics;
 mod statistics;
+mod survival;
+mod tail;
 mod traits;
+mod trimmed;
@@ -0,0 +1,120 @@
+/// The consistency factor `1 / Φ^-1(3/4) ≈ 1.4826` that turns the median
+/// absolute deviation into an estimator of the standard deviation of
+/// normally distributed data
+const MAD_NORMAL_FACTOR: f64 = 1.482_602_218_505_602;
+
+/// Computes the median absolute deviation of `data`, the median of the
+/// absolute deviations of the samples from their median
+///
+/// Sorts `data` in place.
+///
+/// # Remarks
+///
+/// Returns `NaN` if `data` is empty or contains a `NaN` entry
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::mad;
+///
+/// // the median is 2 and the sorted deviations are [0, 0, 1, 1, 2, 4, 7]
+/// let mut data = [1.0, 2.0, 9.0, 4.0, 2.0, 6.0, 1.0];
+/// assert_eq!(mad(&mut data), 1.0);
+/// assert_eq!(data, [1.0, 1.0, 2.0, 2.0, 4.0, 6.0, 9.0]);
+/// ```
+pub fn mad(data: &mut [f64]) -> f64 {
+    if data.is_empty() || data.iter().any(|x| x.is_nan()) {
+        return f64::NAN;
+    }
+    data.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let center = sorted_median(data);
+    let mut deviations: Vec<f64> = data.iter().map(|x| (x - center).abs()).collect();
+    deviations.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    sorted_median(&deviations)
+}
+
+/// Computes the median absolute deviation of `data` scaled by the
+/// consistency factor `1.4826`, so that it estimates the standard deviation
+/// of normally distributed data while staying robust to outliers
+///
+/// Sorts `data` in place.
+///
+/// # Remarks
+///
+/// Returns `NaN` if `data` is empty or contains a `NaN` entry
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::mad_normal;
+/// use statrs::prec;
+///
+/// let mut data = [1.0, 2.0, 9.0, 4.0, 2.0, 6.0, 1.0];
+/// assert!(prec::almost_eq(mad_normal(&mut data), 1.4826, 1e-4));
+/// ```
+pub fn mad_normal(data: &mut [f64]) -> f64 {
+    MAD_NORMAL_FACTOR * mad(data)
+}
+
+/// Returns the median of non-empty sorted `data`
+fn sorted_median(data: &[f64]) -> f64 {
+    let n = data.len();
+    if n % 2 == 1 {
+        data[n / 2]
+    } else {
+        (data[n / 2 - 1] + data[n / 2]) / 2.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distribution::Normal;
+    use ::rand::distributions::Distribution;
+    use ::rand::rngs::StdRng;
+    use ::rand::SeedableRng;
+
+    #[test]
+    fn test_mad() {
+        let mut data = [1.0, 2.0, 9.0, 4.0, 2.0, 6.0, 1.0];
+        assert_eq!(mad(&mut data), 1.0);
+        // the median is 4.5 and the sorted deviations are [0.5, 1.5, 2.5, 3.5]
+        let mut data = [8.0, 1.0, 5.0, 4.0];
+        assert_eq!(mad(&mut data), 2.0);
+        assert_eq!(mad(&mut [3.0]), 0.0);
+        assert_eq!(mad(&mut [5.0, 5.0, 5.0, 1000.0]), 0.0);
+    }
+
+    #[test]
+    fn test_mad_sorts_in_place() {
+        let mut data = [3.0, -1.0, 2.0];
+        mad(&mut data);
+        assert_eq!(data, [-1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn test_mad_nan_or_empty() {
+        assert!(mad(&mut []).is_nan());
+        assert!(mad(&mut [1.0, f64::NAN, 2.0]).is_nan());
+        assert!(mad_normal(&mut []).is_nan());
+    }
+
+    #[test]
+    fn test_mad_normal() {
+        let n = Normal::new(3.0, 2.0).unwrap();
+        let mut r: StdRng = SeedableRng::seed_from_u64(5);
+        let mut data: Vec<f64> = (0..20_000).map(|_| n.sample(&mut r)).collect();
+        assert!((mad_normal(&mut data) - 2.0).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_mad_normal_robust_to_outliers() {
+        let n = Normal::new(0.0, 1.0).unwrap();
+        let mut r: StdRng = SeedableRng::seed_from_u64(6);
+        let mut data: Vec<f64> = (0..20_000).map(|_| n.sample(&mut r)).collect();
+        for x in data.iter_mut().take(200) {
+            *x = 1e6;
+        }
+        assert!((mad_normal(&mut data) - 1.0).abs() < 0.05);
+    }
+}
@@ -0,0 +1,112 @@
+use crate::distribution::{Continuous, ContinuousCDF};
+
+/// Computes the hazard (failure rate) of the distribution `d` at `x`, the
+/// density of failing at `x` given survival up to `x`.
+///
+/// # Formula
+///
+/// ```ignore
+/// f(x) / S(x)
+/// ```
+///
+/// where `f` is the pdf and `S` the survival function of `d`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::Exp;
+/// use statrs::statistics::hazard;
+/// use statrs::prec;
+///
+/// // the exponential distribution is memoryless
+/// let d = Exp::new(2.0).unwrap();
+/// assert!(prec::almost_eq(hazard(&d, 0.5), 2.0, 1e-14));
+/// assert!(prec::almost_eq(hazard(&d, 10.0), 2.0, 1e-12));
+/// ```
+pub fn hazard<D>(d: &D, x: f64) -> f64
+where
+    D: Continuous<f64, f64> + ContinuousCDF<f64, f64>,
+{
+    d.pdf(x) / d.sf(x)
+}
+
+/// Computes the ratio of the hazards of the distributions `a` and `b` at
+/// `x`. The ratio is computed from the log densities and survival functions
+/// so that it remains accurate where the densities underflow.
+///
+/// When the ratio does not depend on `x` the distributions have
+/// proportional hazards. This is the case for two exponential distributions
+/// with rates `λ_a` and `λ_b`, whose hazard ratio is `λ_a / λ_b`, and for two
+/// weibull distributions with a common shape `k` and scales `λ_a` and `λ_b`,
+/// whose hazard ratio is `(λ_b / λ_a)^k`. Weibull distributions with
+/// different shapes do not have proportional hazards.
+///
+/// # Formula
+///
+/// ```ignore
+/// (f_a(x) / S_a(x)) / (f_b(x) / S_b(x))
+/// ```
+///
+/// where `f_a`, `f_b` are the pdfs and `S_a`, `S_b` the survival functions
+/// of `a` and `b`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::Weibull;
+/// use statrs::statistics::hazard_ratio;
+/// use statrs::prec;
+///
+/// let a = Weibull::new(2.0, 1.0).unwrap();
+/// let b = Weibull::new(2.0, 3.0).unwrap();
+/// assert!(prec::almost_eq(hazard_ratio(&a, &b, 0.5), 9.0, 1e-12));
+/// assert!(prec::almost_eq(hazard_ratio(&a, &b, 2.0), 9.0, 1e-12));
+/// ```
+pub fn hazard_ratio<A, B>(a: &A, b: &B, x: f64) -> f64
+where
+    A: Continuous<f64, f64> + ContinuousCDF<f64, f64>,
+    B: Continuous<f64, f64> + ContinuousCDF<f64, f64>,
+{
+    let ln_hazard_a = a.ln_pdf(x) - a.sf(x).ln();
+    let ln_hazard_b = b.ln_pdf(x) - b.sf(x).ln();
+    (ln_hazard_a - ln_hazard_b).exp()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distribution::{Exp, Weibull};
+
+    #[test]
+    fn test_hazard() {
+        let d = Weibull::new(1.5, 2.0).unwrap();
+        for &x in &[0.1, 1.0, 3.0] {
+            // k / λ * (x / λ)^(k - 1)
+            let expected = 1.5 / 2.0 * (x / 2.0f64).powf(0.5);
+            assert_almost_eq!(hazard(&d, x), expected, 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_hazard_ratio_exponential() {
+        for &(rate_a, rate_b) in &[(1.0, 1.0), (2.0, 0.5), (0.1, 3.0)] {
+            let a = Exp::new(rate_a).unwrap();
+            let b = Exp::new(rate_b).unwrap();
+            for &x in &[0.0, 0.01, 1.0, 5.0, 20.0] {
+                assert_almost_eq!(hazard_ratio(&a, &b, x), rate_a / rate_b, 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_hazard_ratio_weibull() {
+        let a = Weibull::new(3.0, 2.0).unwrap();
+        let b = Weibull::new(3.0, 1.0).unwrap();
+        for &x in &[0.1, 0.5, 1.0, 2.0] {
+            assert_almost_eq!(hazard_ratio(&a, &b, x), 0.125, 1e-12);
+        }
+        // different shapes do not have proportional hazards
+        let c = Weibull::new(1.0, 1.0).unwrap();
+        assert!((hazard_ratio(&a, &c, 0.5) - hazard_ratio(&a, &c, 2.0)).abs() > 0.1);
+    }
+}
@@ -0,0 +1,112 @@
+/// Computes the Hill estimator of the extreme value index `1 / α` of a
+/// heavy, Pareto-like upper tail from the `k` largest samples in `data`.
+/// Larger estimates indicate heavier tails, an estimate near `1 / α`
+/// corresponds to a tail decaying like `x^(-α)`.
+///
+/// Sorts `data` in place.
+///
+/// # Panics
+///
+/// If `k == 0`, if `k >= data.len()` or if any of the `k + 1` largest
+/// samples is not positive or is `NaN`
+///
+/// # Formula
+///
+/// ```ignore
+/// (1 / k) * Σ ln(x_(n-i) / x_(n-k)), i = 0..k-1
+/// ```
+///
+/// where `x_(j)` is the `j`-th smallest of the `n` samples
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::hill_estimator;
+/// use statrs::prec;
+///
+/// let mut data = [1.0, 2.0, 4.0, 8.0];
+/// let expected = (2f64.ln() + 4f64.ln()) / 2.0;
+/// assert!(prec::almost_eq(hill_estimator(&mut data, 2), expected, 1e-15));
+/// ```
+pub fn hill_estimator(data: &mut [f64], k: usize) -> f64 {
+    let n = data.len();
+    if k == 0 || k >= n {
+        panic!("k must be in [1, n)");
+    }
+    if data.iter().any(|x| x.is_nan()) {
+        panic!("data must not contain NaN");
+    }
+    data.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let threshold = data[n - k - 1];
+    if threshold <= 0.0 {
+        panic!("the k + 1 largest samples must be positive");
+    }
+    let ln_threshold = threshold.ln();
+    data[n - k..]
+        .iter()
+        .map(|x| x.ln() - ln_threshold)
+        .sum::<f64>()
+        / k as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distribution::Pareto;
+    use ::rand::distributions::Distribution;
+    use ::rand::rngs::StdRng;
+    use ::rand::SeedableRng;
+
+    #[test]
+    fn test_hill_estimator() {
+        let mut data = [8.0, 1.0, 4.0, 2.0];
+        let expected = (2f64.ln() + 4f64.ln()) / 2.0;
+        assert!((hill_estimator(&mut data, 2) - expected).abs() < 1e-15);
+        assert_eq!(data, [1.0, 2.0, 4.0, 8.0]);
+        assert!((hill_estimator(&mut data, 3) - 2.0 * 2f64.ln()).abs() < 1e-14);
+    }
+
+    #[test]
+    fn test_hill_estimator_pareto() {
+        let mut r: StdRng = SeedableRng::seed_from_u64(11);
+        for &shape in &[1.0, 2.0, 4.0] {
+            let d = Pareto::new(1.0, shape).unwrap();
+            let mut data: Vec<f64> = (0..20_000).map(|_| d.sample(&mut r)).collect();
+            let estimate = hill_estimator(&mut data, 2000);
+            assert!(
+                (estimate * shape - 1.0).abs() < 0.05,
+                "{} {}",
+                shape,
+                estimate
+            );
+        }
+    }
+
+    #[test]
+    fn test_hill_estimator_light_tail() {
+        // the exponential tail is lighter than any power law, so the
+        // estimate decreases towards zero as the threshold moves outwards
+        let d = crate::distribution::Exp::new(1.0).unwrap();
+        let mut r: StdRng = SeedableRng::seed_from_u64(12);
+        let mut data: Vec<f64> = (0..20_000).map(|_| d.sample(&mut r)).collect();
+        assert!(hill_estimator(&mut data, 100) < 0.25);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_hill_estimator_k_too_large() {
+        hill_estimator(&mut [1.0, 2.0, 3.0], 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_hill_estimator_k_zero() {
+        hill_estimator(&mut [1.0, 2.0, 3.0], 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_hill_estimator_non_positive() {
+        hill_estimator(&mut [-1.0, 2.0, 3.0], 2);
+    }
+}
@@ -59,6 +59,25 @@ pub trait DiscreteDistribution<T: Float>: ::rand::distributions::Distribution<u6
     fn skewness(&self) -> Option<T> {
         None
     }
+    /// Returns the excess kurtosis, i.e. the kurtosis minus `3`, if it
+    /// exists.
+    fn excess_kurtosis(&self) -> Option<T> {
+        None
+    }
+    /// Returns the first four cumulants `[κ1, κ2, κ3, κ4]`, if they exist.
+    /// See `Distribution::cumulants`.
+    fn cumulants(&self) -> Option<[T; 4]> {
+        let skewness = self.skewness()?;
+        let excess_kurtosis = self.excess_kurtosis()?;
+        let mean = self.mean()?;
+        let variance = self.variance()?;
+        Some([
+            mean,
+            variance,
+            skewness * variance * variance.sqrt(),
+            excess_kurtosis * variance * variance,
+        ])
+    }
 }
 
 pub trait Distribution<T: Float>: ::rand::distributions::Distribution<T> {
@@ -164,6 +183,131 @@ pub trait Distribution<T: Float>: ::rand::distributions::Distribution<T> {
     fn skewness(&self) -> Option<T> {
         None
     }
+    /// Returns the excess kurtosis, i.e. the kurtosis minus `3`, if it
+    /// exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::Distribution;
+    /// use statrs::distribution::Uniform;
+    ///
+    /// let n = Uniform::new(0.0, 1.0).unwrap();
+    /// assert_eq!(-1.2, n.excess_kurtosis().unwrap());
+    /// ```
+    fn excess_kurtosis(&self) -> Option<T> {
+        None
+    }
+    /// Returns the center of symmetry, if the distribution is symmetric
+    /// about a point `c`, i.e. if `X - c` and `c - X` have the same
+    /// distribution.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::Distribution;
+    /// use statrs::distribution::{Exp, Uniform};
+    ///
+    /// let n = Uniform::new(0.0, 1.0).unwrap();
+    /// assert_eq!(Some(0.5), n.symmetry_center());
+    /// let n = Exp::new(1.0).unwrap();
+    /// assert_eq!(None, n.symmetry_center());
+    /// ```
+    fn symmetry_center(&self) -> Option<T> {
+        None
+    }
+    /// Returns whether the distribution is symmetric about some point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::Distribution;
+    /// use statrs::distribution::{Gamma, Normal};
+    ///
+    /// assert!(Normal::new(1.0, 2.0).unwrap().is_symmetric());
+    /// assert!(!Gamma::new(2.0, 1.0).unwrap().is_symmetric());
+    /// ```
+    fn is_symmetric(&self) -> bool {
+        self.symmetry_center().is_some()
+    }
+    /// Returns the first four cumulants `[κ1, κ2, κ3, κ4]`, if they exist,
+    /// e.g. for Edgeworth or Cornish-Fisher expansions. They are computed
+    /// from the moments as `κ1 = μ`, `κ2 = σ^2`, `κ3 = γ1 * σ^3` and
+    /// `κ4 = γ2 * σ^4`, where `γ1` is the skewness and `γ2` is the excess
+    /// kurtosis.
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if the skewness or the excess kurtosis is not known,
+    /// in which case the moments are not computed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::Distribution;
+    /// use statrs::distribution::Exp;
+    ///
+    /// let n = Exp::new(0.5).unwrap();
+    /// assert_eq!([2.0, 4.0, 16.0, 96.0], n.cumulants().unwrap());
+    /// ```
+    fn cumulants(&self) -> Option<[T; 4]> {
+        let skewness = self.skewness()?;
+        let excess_kurtosis = self.excess_kurtosis()?;
+        let mean = self.mean()?;
+        let variance = self.variance()?;
+        Some([
+            mean,
+            variance,
+            skewness * variance * variance.sqrt(),
+            excess_kurtosis * variance * variance,
+        ])
+    }
+    /// Fills `out` with independent samples from the distribution, for
+    /// drawing batches of samples into a preallocated buffer without
+    /// allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::Distribution;
+    /// use statrs::distribution::Uniform;
+    ///
+    /// let n = Uniform::new(0.0, 1.0).unwrap();
+    /// let mut out = [0.0; 16];
+    /// n.sample_into(&mut rand::thread_rng(), &mut out);
+    /// assert!(out.iter().all(|&x| (0.0..=1.0).contains(&x)));
+    /// ```
+    fn sample_into<R: ::rand::Rng + ?Sized>(&self, rng: &mut R, out: &mut [T]) {
+        for x in out.iter_mut() {
+            *x = Self::sample(self, rng);
+        }
+    }
+    /// Draws `n` samples from the distribution using a random number
+    /// generator seeded with `seed`, so that the same seed always yields
+    /// the same samples, e.g. for reproducible examples and tests.
+    ///
+    /// # Remarks
+    ///
+    /// The samples are reproducible for a given version of the `rand`
+    /// crate, whose seeded generators may change between releases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::Distribution;
+    /// use statrs::distribution::Normal;
+    ///
+    /// let n = Normal::new(0.0, 1.0).unwrap();
+    /// assert_eq!(n.sample_seeded(42, 5), n.sample_seeded(42, 5));
+    /// ```
+    fn sample_seeded(&self, seed: u64, n: usize) -> Vec<T> {
+        use ::rand::SeedableRng;
+
+        let mut rng = ::rand::rngs::StdRng::seed_from_u64(seed);
+        let mut out = vec![T::zero(); n];
+        self.sample_into(&mut rng, &mut out);
+        out
+    }
 }
 
 /// The `Mean` trait implements the calculation of a mean.
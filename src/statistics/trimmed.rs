@@ -0,0 +1,134 @@
+use crate::{Result, StatsError};
+
+/// Computes the mean of `data` after discarding the lowest and the highest
+/// `proportion` of the samples, rounded down to a whole number of samples
+/// on each side. This matches SciPy's `trim_mean`.
+///
+/// Sorts `data` in place.
+///
+/// # Errors
+///
+/// Returns an error if `data` is empty or if `proportion` is `NaN`,
+/// `proportion < 0.0` or `proportion >= 0.5`
+///
+/// # Remarks
+///
+/// Returns `NaN` if `data` contains a `NaN` entry
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::trimmed_mean;
+///
+/// let mut data = [3.0, 100.0, 1.0, 7.0, 0.0, 2.0];
+/// assert_eq!(trimmed_mean(&mut data, 0.2).unwrap(), 3.25);
+/// assert_eq!(data, [0.0, 1.0, 2.0, 3.0, 7.0, 100.0]);
+/// ```
+pub fn trimmed_mean(data: &mut [f64], proportion: f64) -> Result<f64> {
+    let cut = sort_and_cut(data, proportion)?;
+    if data.iter().any(|x| x.is_nan()) {
+        return Ok(f64::NAN);
+    }
+    let kept = &data[cut..data.len() - cut];
+    Ok(kept.iter().sum::<f64>() / kept.len() as f64)
+}
+
+/// Computes the mean of `data` after clamping the lowest and the highest
+/// `proportion` of the samples to the most extreme remaining values,
+/// rounded down to a whole number of samples on each side.
+///
+/// Sorts `data` in place, the clamped values are not written back.
+///
+/// # Errors
+///
+/// Returns an error if `data` is empty or if `proportion` is `NaN`,
+/// `proportion < 0.0` or `proportion >= 0.5`
+///
+/// # Remarks
+///
+/// Returns `NaN` if `data` contains a `NaN` entry
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::winsorized_mean;
+///
+/// // the data is winsorized to [1.0, 1.0, 2.0, 3.0, 7.0, 7.0]
+/// let mut data = [3.0, 100.0, 1.0, 7.0, 0.0, 2.0];
+/// assert_eq!(winsorized_mean(&mut data, 0.2).unwrap(), 3.5);
+/// ```
+pub fn winsorized_mean(data: &mut [f64], proportion: f64) -> Result<f64> {
+    let cut = sort_and_cut(data, proportion)?;
+    if data.iter().any(|x| x.is_nan()) {
+        return Ok(f64::NAN);
+    }
+    let n = data.len();
+    let inner = data[cut..n - cut].iter().sum::<f64>();
+    let clamped = cut as f64 * (data[cut] + data[n - cut - 1]);
+    Ok((inner + clamped) / n as f64)
+}
+
+/// Validates the arguments, sorts `data` and returns the number of samples
+/// to cut from each side
+fn sort_and_cut(data: &mut [f64], proportion: f64) -> Result<usize> {
+    if data.is_empty() || !(0.0..0.5).contains(&proportion) {
+        return Err(StatsError::BadParams);
+    }
+    if data.iter().all(|x| !x.is_nan()) {
+        data.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    }
+    Ok((proportion * data.len() as f64).floor() as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::statistics::Statistics;
+
+    #[test]
+    fn test_trimmed_mean() {
+        // scipy.stats.trim_mean(np.arange(20), 0.1) == 9.5
+        let mut data: Vec<f64> = (0..20).map(|x| ((x * 7) % 20) as f64).collect();
+        assert_eq!(trimmed_mean(&mut data, 0.1).unwrap(), 9.5);
+        // scipy.stats.trim_mean([1, 2, 3, 4, 5, 6, 7, 80, 90, 100], 0.25) == 17.5
+        let mut data = [90.0, 1.0, 6.0, 2.0, 100.0, 3.0, 80.0, 4.0, 5.0, 7.0];
+        assert_eq!(trimmed_mean(&mut data, 0.25).unwrap(), 17.5);
+        // scipy.stats.trim_mean([1, 2, 3, 4, 5, 6, 7, 80, 90, 100], 0.3) == 5.5
+        assert_eq!(trimmed_mean(&mut data, 0.3).unwrap(), 5.5);
+    }
+
+    #[test]
+    fn test_winsorized_mean() {
+        // winsorized to [2, 2, 3, 4, 5, 6, 7, 80, 90, 90]
+        let mut data = [90.0, 1.0, 6.0, 2.0, 100.0, 3.0, 80.0, 4.0, 5.0, 7.0];
+        assert_eq!(winsorized_mean(&mut data, 0.1).unwrap(), 28.9);
+        // winsorized to [4, 4, 4, 4, 5, 6, 7, 7, 7, 7]
+        assert_eq!(winsorized_mean(&mut data, 0.3).unwrap(), 5.5);
+    }
+
+    #[test]
+    fn test_zero_proportion_is_mean() {
+        let data = [90.0, 1.0, 6.0, 2.0, 100.0, 3.0, 80.0, 4.0, 5.0, 7.0];
+        let mean = data.mean();
+        assert_eq!(trimmed_mean(&mut data.clone(), 0.0).unwrap(), mean);
+        assert_eq!(winsorized_mean(&mut data.clone(), 0.0).unwrap(), mean);
+    }
+
+    #[test]
+    fn test_nan() {
+        let mut data = [1.0, f64::NAN, 3.0];
+        assert!(trimmed_mean(&mut data, 0.1).unwrap().is_nan());
+        assert!(winsorized_mean(&mut data, 0.1).unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_bad_params() {
+        let mut data = [1.0, 2.0, 3.0];
+        assert!(trimmed_mean(&mut data, 0.5).is_err());
+        assert!(trimmed_mean(&mut data, -0.1).is_err());
+        assert!(trimmed_mean(&mut data, f64::NAN).is_err());
+        assert!(trimmed_mean(&mut [], 0.1).is_err());
+        assert!(winsorized_mean(&mut data, 0.5).is_err());
+        assert!(winsorized_mean(&mut [], 0.1).is_err());
+    }
+}
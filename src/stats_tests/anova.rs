@@ -0,0 +1,107 @@
+use crate::distribution::{ContinuousCDF, FisherSnedecor};
+use crate::statistics::Statistics;
+use crate::stats_tests::TestResult;
+use crate::{Result, StatsError};
+
+/// Performs a one-way analysis of variance, testing the null hypothesis
+/// that all `groups` are drawn from populations with the same mean.
+///
+/// # Errors
+///
+/// Returns an error if there are fewer than two groups, if any group is
+/// empty, or if there are no more observations than groups
+///
+/// # Formula
+///
+/// ```ignore
+/// F = (SSB / (k - 1)) / (SSW / (N - k))
+/// ```
+///
+/// where `SSB` is the sum of squares between the groups, `SSW` the sum of
+/// squares within the groups, `k` the number of groups and `N` the total
+/// number of observations. The p-value is the survival function of the
+/// fisher-snedecor distribution with `k - 1` and `N - k` degrees of freedom
+/// at `F`.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::one_way_anova;
+///
+/// let a = [6.0, 8.0, 4.0, 5.0, 3.0, 4.0];
+/// let b = [8.0, 12.0, 9.0, 11.0, 6.0, 8.0];
+/// let c = [13.0, 9.0, 11.0, 8.0, 7.0, 12.0];
+/// let result = one_way_anova(&[&a, &b, &c]).unwrap();
+/// assert_eq!(result.freedom(), &[2.0, 15.0]);
+/// assert!(result.p_value() < 0.01);
+/// ```
+pub fn one_way_anova(groups: &[&[f64]]) -> Result<TestResult> {
+    if groups.len() < 2 || groups.iter().any(|g| g.is_empty()) {
+        return Err(StatsError::BadParams);
+    }
+    let k = groups.len() as f64;
+    let n = groups.iter().map(|g| g.len()).sum::<usize>() as f64;
+    if n <= k {
+        return Err(StatsError::BadParams);
+    }
+
+    let grand_mean = groups.iter().flat_map(|g| g.iter()).mean();
+    let (ss_between, ss_within) = groups.iter().fold((0.0, 0.0), |(ssb, ssw), g| {
+        let mean = g.mean();
+        let ssw_g = g.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>();
+        let d = mean - grand_mean;
+        (ssb + g.len() as f64 * d * d, ssw + ssw_g)
+    });
+
+    let freedom_1 = k - 1.0;
+    let freedom_2 = n - k;
+    let statistic = (ss_between / freedom_1) / (ss_within / freedom_2);
+    let p_value = FisherSnedecor::new(freedom_1, freedom_2)?.sf(statistic);
+    Ok(TestResult::new(
+        statistic,
+        vec![freedom_1, freedom_2],
+        p_value,
+    ))
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::one_way_anova;
+
+    #[test]
+    fn test_one_way_anova() {
+        // mussel shell measurements used in the scipy.stats.f_oneway docs
+        let tillamook = [0.0571, 0.0813, 0.0831, 0.0976, 0.0817, 0.0859, 0.0735, 0.0659, 0.0923, 0.0836];
+        let newport = [0.0873, 0.0662, 0.0672, 0.0819, 0.0749, 0.0649, 0.0835, 0.0725];
+        let petersburg = [0.0974, 0.1352, 0.0817, 0.1016, 0.0968, 0.1064, 0.105];
+        let magadan = [0.1033, 0.0915, 0.0781, 0.0685, 0.0677, 0.0697, 0.0764, 0.0689];
+        let tvarminne = [0.0703, 0.1026, 0.0956, 0.0973, 0.1039, 0.1045];
+        let result = one_way_anova(&[&tillamook, &newport, &petersburg, &magadan, &tvarminne]).unwrap();
+        assert_almost_eq!(result.statistic(), 7.121019471642447, 1e-12);
+        assert_eq!(result.freedom(), &[4.0, 34.0]);
+        assert_almost_eq!(result.p_value(), 0.0002812242314534544, 1e-15);
+    }
+
+    #[test]
+    fn test_one_way_anova_equal_means() {
+        let result = one_way_anova(&[&[1.0, 2.0, 3.0], &[3.0, 2.0, 1.0]]).unwrap();
+        assert_eq!(result.statistic(), 0.0);
+        assert_eq!(result.p_value(), 1.0);
+    }
+
+    #[test]
+    fn test_one_way_anova_no_within_variance() {
+        let result = one_way_anova(&[&[1.0, 1.0], &[2.0, 2.0]]).unwrap();
+        assert_eq!(result.statistic(), f64::INFINITY);
+        assert_eq!(result.p_value(), 0.0);
+    }
+
+    #[test]
+    fn test_one_way_anova_bad_groups() {
+        assert!(one_way_anova(&[]).is_err());
+        assert!(one_way_anova(&[&[1.0, 2.0]]).is_err());
+        assert!(one_way_anova(&[&[1.0, 2.0], &[]]).is_err());
+        assert!(one_way_anova(&[&[1.0], &[2.0]]).is_err());
+    }
+}
@@ -0,0 +1,129 @@
+use rand::distributions::Distribution;
+use rand::Rng;
+
+/// Performs a goodness-of-fit test by parametric bootstrap, for statistics
+/// whose null distribution is unknown, e.g. the Kolmogorov-Smirnov
+/// statistic of a model with estimated parameters.
+///
+/// The model is fitted to `data` with `fit` and the statistic `stat` of
+/// the data against the fitted model is computed. Then `n_boot` datasets of
+/// the same size are simulated from the fitted model, the model is refitted
+/// to each and their statistics are recomputed. Larger statistics are taken
+/// to indicate a worse fit. Returns the bootstrap p-value of the observed
+/// statistic.
+///
+/// # Panics
+///
+/// If `data` is empty or `n_boot == 0`
+///
+/// # Formula
+///
+/// ```ignore
+/// p = (1 + #{b : T_b >= T}) / (n_boot + 1)
+/// ```
+///
+/// where `T` is the observed statistic and `T_b` are the bootstrapped
+/// statistics
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{ContinuousCDF, Exp};
+/// use statrs::statistics::Statistics;
+/// use statrs::stats_tests::parametric_bootstrap_gof;
+///
+/// let data = [0.3, 1.9, 0.2, 0.8, 3.1, 0.4, 1.1, 0.6];
+/// let fit = |xs: &[f64]| Exp::new(1.0 / xs.mean()).unwrap();
+/// // largest distance between the fitted and the empirical cdf
+/// let stat = |xs: &[f64], d: &Exp| {
+///     let n = xs.len() as f64;
+///     xs.iter()
+///         .map(|&x| (d.cdf(x) - xs.iter().filter(|&&y| y <= x).count() as f64 / n).abs())
+///         .fold(0.0, f64::max)
+/// };
+/// let mut r = rand::thread_rng();
+/// let p = parametric_bootstrap_gof(&data, fit, stat, 99, &mut r);
+/// assert!(0.0 < p && p <= 1.0);
+/// ```
+pub fn parametric_bootstrap_gof<D, F, S, R>(
+    data: &[f64],
+    fit: F,
+    stat: S,
+    n_boot: usize,
+    rng: &mut R,
+) -> f64
+where
+    D: Distribution<f64>,
+    F: Fn(&[f64]) -> D,
+    S: Fn(&[f64], &D) -> f64,
+    R: Rng + ?Sized,
+{
+    if data.is_empty() || n_boot == 0 {
+        panic!("data must be non-empty and n_boot must be positive");
+    }
+    let model = fit(data);
+    let observed = stat(data, &model);
+    let mut simulated = vec![0.0; data.len()];
+    let mut extreme = 0;
+    for _ in 0..n_boot {
+        for x in simulated.iter_mut() {
+            *x = model.sample(rng);
+        }
+        let refitted = fit(&simulated);
+        if stat(&simulated, &refitted) >= observed {
+            extreme += 1;
+        }
+    }
+    (1 + extreme) as f64 / (n_boot + 1) as f64
+}
+
+#[cfg(all(test, feature = "nightly"))]
+mod tests {
+    use super::*;
+    use crate::distribution::internal::test::ks_statistic;
+    use crate::distribution::{ContinuousCDF, Exp, Normal};
+    use crate::statistics::Statistics;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn ks<D: ContinuousCDF<f64, f64>>(data: &[f64], d: &D) -> f64 {
+        ks_statistic(data, |x| d.cdf(x))
+    }
+
+    fn exponential_data(r: &mut StdRng) -> Vec<f64> {
+        let d = Exp::new(0.5).unwrap();
+        (0..200).map(|_| d.sample(r)).collect()
+    }
+
+    #[test]
+    fn test_correct_family_not_rejected() {
+        let mut r: StdRng = SeedableRng::seed_from_u64(29);
+        let data = exponential_data(&mut r);
+        let fit = |xs: &[f64]| Exp::new(1.0 / xs.mean()).unwrap();
+        assert!(parametric_bootstrap_gof(&data, fit, ks, 200, &mut r) > 0.05);
+    }
+
+    #[test]
+    fn test_wrong_family_rejected() {
+        let mut r: StdRng = SeedableRng::seed_from_u64(29);
+        let data = exponential_data(&mut r);
+        let fit = |xs: &[f64]| Normal::new(xs.mean(), xs.std_dev()).unwrap();
+        assert!(parametric_bootstrap_gof(&data, fit, ks, 200, &mut r) < 0.01);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_empty_data() {
+        let mut r: StdRng = SeedableRng::seed_from_u64(29);
+        let fit = |xs: &[f64]| Exp::new(1.0 / xs.mean()).unwrap();
+        parametric_bootstrap_gof(&[], fit, ks, 10, &mut r);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_zero_n_boot() {
+        let mut r: StdRng = SeedableRng::seed_from_u64(29);
+        let fit = |xs: &[f64]| Exp::new(1.0 / xs.mean()).unwrap();
+        parametric_bootstrap_gof(&[1.0], fit, ks, 0, &mut r);
+    }
+}
@@ -0,0 +1,70 @@
+//! Provides statistical hypothesis tests
+
+pub use self::anova::*;
+pub use self::bootstrap::*;
+
+mod anova;
+mod bootstrap;
+
+/// The outcome of a statistical hypothesis test
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestResult {
+    statistic: f64,
+    freedom: Vec<f64>,
+    p_value: f64,
+}
+
+impl TestResult {
+    pub(crate) fn new(statistic: f64, freedom: Vec<f64>, p_value: f64) -> TestResult {
+        TestResult {
+            statistic,
+            freedom,
+            p_value,
+        }
+    }
+
+    /// Returns the value of the test statistic
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::stats_tests::one_way_anova;
+    ///
+    /// let result = one_way_anova(&[&[1.0, 2.0, 3.0], &[3.0, 4.0, 5.0]]).unwrap();
+    /// assert_eq!(result.statistic(), 6.0);
+    /// ```
+    pub fn statistic(&self) -> f64 {
+        self.statistic
+    }
+
+    /// Returns the degrees of freedom of the null distribution of the
+    /// test statistic
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::stats_tests::one_way_anova;
+    ///
+    /// let result = one_way_anova(&[&[1.0, 2.0, 3.0], &[3.0, 4.0, 5.0]]).unwrap();
+    /// assert_eq!(result.freedom(), &[1.0, 4.0]);
+    /// ```
+    pub fn freedom(&self) -> &[f64] {
+        &self.freedom
+    }
+
+    /// Returns the p-value of the test, the probability under the null
+    /// hypothesis of a test statistic at least as extreme as the one
+    /// observed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::stats_tests::one_way_anova;
+    ///
+    /// let result = one_way_anova(&[&[1.0, 2.0, 3.0], &[3.0, 4.0, 5.0]]).unwrap();
+    /// assert!(result.p_value() > 0.05);
+    /// ```
+    pub fn p_value(&self) -> f64 {
+        self.p_value
+    }
+}